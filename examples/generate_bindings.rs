@@ -0,0 +1,43 @@
+//! Generate typed bindings from the Confluence WSDL.
+//!
+//! Usage: `cargo run --example generate_bindings -- <wsdl file or url> [output file]`
+//!
+//! The WSDL can be downloaded from `<confluence>/rpc/soap-axis/confluenceservice-v2?wsdl`.
+
+extern crate confluence;
+
+use confluence::wsdl;
+use std::env;
+use std::fs;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args.len() > 2 {
+        eprintln!("usage: generate_bindings <wsdl file or url> [output file]");
+        process::exit(2);
+    }
+
+    let source = &args[0];
+    let wsdl = if source.starts_with("http://") || source.starts_with("https://") {
+        wsdl::fetch(source).unwrap_or_else(|e| {
+            eprintln!("failed to fetch {}: {}", source, e);
+            process::exit(1)
+        })
+    } else {
+        wsdl::load(source).unwrap_or_else(|e| {
+            eprintln!("failed to read {}: {}", source, e);
+            process::exit(1)
+        })
+    };
+
+    let code = wsdl::codegen::generate(&wsdl);
+
+    match args.get(1) {
+        Some(output) => fs::write(output, code).unwrap_or_else(|e| {
+            eprintln!("failed to write {}: {}", output, e);
+            process::exit(1)
+        }),
+        None => print!("{}", code),
+    }
+}
@@ -0,0 +1,673 @@
+//! Non-blocking client session.
+
+use futures::future::{self, Either, Loop, Shared};
+use futures::Future;
+use reqwest::async::Client as AsyncClient;
+use std::io;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tokio_timer::Delay;
+use xmltree::Element;
+
+use limit::Limiter;
+use relogin::{Relogin, ReloginEvent};
+use rpser::xml::BuildElement;
+use rpser::{self, Method};
+use transforms::{self, FromElement};
+use {
+    http, login_token, logout_result, parse_soap_response, throttled_for, wsdl, wsdl_url, Error,
+    FaultKind, Page, PageSummary, PageUpdateOptions, PreparedCall, RetryPolicy, Space, UpdatePage,
+};
+
+/// Boxed future returned by `AsyncSession` methods.
+pub type BoxFuture<T> = Box<dyn Future<Item = T, Error = Error> + Send>;
+
+/// How often to check for a free slot when `max_in_flight` calls are being sent.
+const IN_FLIGHT_POLL: Duration = Duration::from_millis(10);
+
+/// Login in progress after a token has expired, resolves to `true` if it succeeded.
+type PendingLogin = Shared<Box<dyn Future<Item = bool, Error = ()> + Send>>;
+
+/**
+Non-blocking client's session.
+
+Exposes the same operations as `Session`, but every method returns a future
+instead of blocking the current thread. The futures must be driven by a
+`tokio` runtime.
+
+The session is cheap to clone: all clones share the same HTTP client
+(and its connection pool), WSDL, auth token and limits. Sessions created
+with `SessionBuilder::login_async` retry, rate limit and log in again like
+`Session` does, waiting on timers instead of blocking the thread.
+
+Unlike `Session`, there is no automatic logout when the last clone goes out
+of scope, because logging out requires a network round trip.
+Call `logout` explicitly when done.
+
+## Example
+
+```no_run
+extern crate confluence;
+extern crate futures;
+
+use futures::{future, Future};
+
+# fn main() {
+let pages = confluence::AsyncSession::login("https://confluence", "user", "pass")
+    .and_then(|session| {
+        future::join_all(
+            vec![123456, 123457, 123458]
+                .into_iter()
+                .map(move |id| session.get_page_by_id(id)),
+        )
+    });
+# }
+```
+*/
+#[derive(Clone)]
+pub struct AsyncSession {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    client: AsyncClient,
+    wsdl: wsdl::Wsdl,
+    token: RwLock<String>,
+    relogin: Option<Relogin>,
+    /// Login shared by the calls which found the token expired at the same time.
+    pending_login: Mutex<Option<PendingLogin>>,
+    retry: RetryPolicy,
+    limiter: Limiter,
+}
+
+/// Settings of a session created with `SessionBuilder::login_async`.
+pub(crate) struct AsyncSettings {
+    /// WSDL to use instead of fetching it.
+    pub wsdl: Option<wsdl::Wsdl>,
+    pub relogin: Option<Relogin>,
+    pub retry: RetryPolicy,
+    pub limiter: Limiter,
+}
+
+/// In-flight slot of the limiter, given back when dropped.
+struct Slot(Arc<Inner>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.limiter.leave();
+    }
+}
+
+impl AsyncSession {
+    /// Create new confluence session.
+    pub fn login(url: &str, user: &str, pass: &str) -> BoxFuture<AsyncSession> {
+        AsyncSession::login_with_client(AsyncClient::new(), url, user, pass)
+    }
+
+    /// Create new confluence session using the provided HTTP client.
+    ///
+    /// Use `SessionBuilder::login_async` to retry calls, limit their rate or log in again.
+    pub fn login_with_client(
+        client: AsyncClient,
+        url: &str,
+        user: &str,
+        pass: &str,
+    ) -> BoxFuture<AsyncSession> {
+        let settings = AsyncSettings {
+            wsdl: None,
+            relogin: None,
+            retry: RetryPolicy::never(),
+            limiter: Limiter::default(),
+        };
+
+        AsyncSession::login_with_settings(client, url, user, pass, settings)
+    }
+
+    pub(crate) fn login_with_settings(
+        client: AsyncClient,
+        url: &str,
+        user: &str,
+        pass: &str,
+        settings: AsyncSettings,
+    ) -> BoxFuture<AsyncSession> {
+        debug!("logging in at url {:?} with user {:?}", url, user);
+
+        let wsdl: BoxFuture<wsdl::Wsdl> = match settings.wsdl {
+            Some(wsdl) => Box::new(future::ok(wsdl)),
+            None => {
+                let wsdl_url = wsdl_url(url);
+
+                debug!("getting wsdl from url {:?}", wsdl_url);
+
+                Box::new(wsdl::fetch_async(&client, &wsdl_url).map_err(Error::from))
+            }
+        };
+
+        let (user, pass) = (user.to_string(), pass.to_string());
+        let (relogin, retry, limiter) = (settings.relogin, settings.retry, settings.limiter);
+
+        Box::new(wsdl.and_then(move |wsdl| {
+            let session = AsyncSession {
+                inner: Arc::new(Inner {
+                    client,
+                    wsdl,
+                    token: RwLock::new(String::new()),
+                    relogin,
+                    pending_login: Mutex::new(None),
+                    retry,
+                    limiter,
+                }),
+            };
+
+            session.login_call(&user, &pass).map(move |token| {
+                session.set_token(token);
+                session
+            })
+        }))
+    }
+
+    /// Current auth token.
+    pub fn token(&self) -> String {
+        self.inner
+            .token
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn set_token(&self, token: String) {
+        *self.inner.token.write().unwrap_or_else(|e| e.into_inner()) = token;
+    }
+
+    /// Call `login` and return the received token.
+    fn login_call(&self, user: &str, pass: &str) -> BoxFuture<String> {
+        let method = Method::new("login")
+            .with(Element::node("username").with_text(user))
+            .with(Element::node("password").with_text(pass));
+
+        Box::new(self.send(&method).and_then(login_token))
+    }
+
+    /// Explicitly log out out of confluence.
+    pub fn logout(&self) -> BoxFuture<bool> {
+        Box::new(
+            self.call(Method::new("logout").with(self.token_element()))
+                .and_then(logout_result),
+        )
+    }
+
+    /// Returns a single Space.
+    ///
+    /// See `Session::get_space`.
+    pub fn get_space(&self, space_key: &str) -> BoxFuture<Space> {
+        self.call_for(
+            Method::new("getSpace")
+                .with(self.token_element())
+                .with(Element::node("spaceKey").with_text(space_key)),
+            "getSpaceReturn",
+        )
+    }
+
+    /// Returns a single Page by space and title.
+    ///
+    /// See `Session::get_page_by_title`.
+    pub fn get_page_by_title(&self, space_key: &str, page_title: &str) -> BoxFuture<Page> {
+        self.call_for(
+            Method::new("getPage")
+                .with(self.token_element())
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("pageTitle").with_text(page_title)),
+            "getPageReturn",
+        )
+    }
+
+    /// Returns a single Page by id.
+    ///
+    /// See `Session::get_page_by_id`.
+    pub fn get_page_by_id(&self, page_id: i64) -> BoxFuture<Page> {
+        self.call_for(
+            Method::new("getPage")
+                .with(self.token_element())
+                .with(Element::node("pageId").with_text(page_id.to_string())),
+            "getPageReturn",
+        )
+    }
+
+    /// Adds or updates a page.
+    ///
+    /// See `Session::store_page`.
+    pub fn store_page(&self, page: UpdatePage) -> BoxFuture<Page> {
+        self.call_for(
+            Method::new("storePage")
+                .with(self.token_element())
+                .with(transforms::update_page_element(page)),
+            "storePageReturn",
+        )
+    }
+
+    /// Updates the page.
+    ///
+    /// See `Session::update_page`.
+    pub fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> BoxFuture<Page> {
+        self.call_for(
+            Method::new("updatePage")
+                .with(self.token_element())
+                .with(transforms::update_page_element(page))
+                .with(transforms::page_update_options_element(options)),
+            "updatePageReturn",
+        )
+    }
+
+    /// Returns all the direct children of this page.
+    ///
+    /// See `Session::get_children`.
+    pub fn get_children(&self, page_id: i64) -> BoxFuture<Vec<PageSummary>> {
+        Box::new(
+            self.call(
+                Method::new("getChildren")
+                    .with(self.token_element())
+                    .with(Element::node("pageId").with_text(page_id.to_string())),
+            )
+            .and_then(|response| {
+                let element = response.body.descend(&["getChildrenReturn"])?;
+
+                let mut summaries = vec![];
+
+                for element in element.children {
+                    summaries.push(PageSummary::from_element(element)?);
+                }
+
+                Ok(summaries)
+            }),
+        )
+    }
+
+    /// Call a custom method on this session.
+    ///
+    /// See `Session::call`, expired tokens are handled the same way. Calls which
+    /// find the token expired at the same time wait for a single login. If it
+    /// fails, they fail with the expired token fault, and the login error is
+    /// passed to the `on_relogin` hook.
+    pub fn call(&self, mut method: rpser::Method) -> BoxFuture<rpser::Response> {
+        let session = self.clone();
+        let token = self.token();
+
+        Box::new(self.send(&method).then(move |result| {
+            match result {
+                Err(e)
+                    if session.inner.relogin.is_some()
+                        && e.fault_kind() == Some(FaultKind::InvalidSession)
+                        && method.name != "logout" =>
+                {
+                    Either::A(
+                        session.relogin(&method.name, &token).then(
+                            move |logged_in| match logged_in.map(|done| *done) {
+                                Ok(true) => {
+                                    let token = session.token();
+                                    for arg in
+                                        method.args.iter_mut().filter(|arg| arg.name == "token")
+                                    {
+                                        arg.text = Some(token.clone());
+                                    }
+
+                                    session.send(&method)
+                                }
+                                _ => Box::new(future::err(e)),
+                            },
+                        ),
+                    )
+                }
+                other => Either::B(future::result(other)),
+            }
+        }))
+    }
+
+    /// Log in again, unless another call has already done so since `expired_token` was used.
+    ///
+    /// Joins the login in progress, if any. Resolves to `false` if the login failed.
+    fn relogin(&self, method: &str, expired_token: &str) -> PendingLogin {
+        let mut pending_login = self
+            .inner
+            .pending_login
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        if self.token() != expired_token {
+            let logged_in: Box<dyn Future<Item = bool, Error = ()> + Send> =
+                Box::new(future::ok(true));
+            return logged_in.shared();
+        }
+
+        if let Some(ref login) = *pending_login {
+            return login.clone();
+        }
+
+        let login = self.start_relogin(method).shared();
+        *pending_login = Some(login.clone());
+
+        login
+    }
+
+    /// Log in with the credentials of the relogin settings, and report it to the hook.
+    fn start_relogin(&self, method: &str) -> Box<dyn Future<Item = bool, Error = ()> + Send> {
+        let relogin = match self.inner.relogin {
+            Some(ref relogin) => relogin,
+            None => return Box::new(future::ok(false)),
+        };
+
+        let (user, pass) = relogin.credentials.credentials();
+
+        debug!(
+            "token expired during {:?}, logging in again as {:?}",
+            method, user
+        );
+
+        let session = self.clone();
+        let method = method.to_string();
+
+        Box::new(self.login_call(&user, &pass).then(move |result| {
+            let logged_in = result.is_ok();
+            if let Ok(ref token) = result {
+                session.set_token(token.clone());
+            }
+            *session
+                .inner
+                .pending_login
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()) = None;
+
+            if let Some(hook) = session
+                .inner
+                .relogin
+                .as_ref()
+                .and_then(|relogin| relogin.hook.as_ref())
+            {
+                hook(&ReloginEvent {
+                    method: &method,
+                    user: &user,
+                    error: result.as_ref().err(),
+                });
+            }
+
+            Ok(logged_in)
+        }))
+    }
+
+    /// Send the method, retrying according to the retry policy.
+    fn send(&self, method: &Method) -> BoxFuture<rpser::Response> {
+        let call = match PreparedCall::new(&self.inner.wsdl, method) {
+            Ok(call) => Arc::new(call),
+            Err(e) => return Box::new(future::err(e)),
+        };
+
+        let session = self.clone();
+
+        Box::new(future::loop_fn(1, move |attempt| {
+            let session = session.clone();
+            let call = call.clone();
+
+            session.send_once(&call).then(move |result| match result {
+                Err(ref e)
+                    if session.inner.retry.should_retry_call(
+                        &call.action,
+                        call.idempotent,
+                        e,
+                        attempt,
+                    ) =>
+                {
+                    let delay = session.inner.retry.retry_delay(e, attempt);
+                    warn!(
+                        "[call] {} failed (attempt {}), retrying in {:?}: {:?}",
+                        call.action, attempt, delay, e
+                    );
+                    Either::A(sleep(delay).map(move |_| Loop::Continue(attempt + 1)))
+                }
+                other => Either::B(future::result(other.map(Loop::Break))),
+            })
+        }))
+    }
+
+    fn send_once(&self, call: &Arc<PreparedCall>) -> BoxFuture<rpser::Response> {
+        let inner = self.inner.clone();
+        let call = call.clone();
+
+        Box::new(self.acquire().and_then(move |slot| {
+            http::soap_action_async(&inner.client, &call.url, &call.action, &call.body)
+                .map_err(Error::from)
+                .and_then(move |http_response| {
+                    drop(slot);
+
+                    trace!("[response xml] {}", http_response.body);
+
+                    let retry_after = throttled_for(&http_response);
+                    if let Some(retry_after) = retry_after {
+                        inner.limiter.pause_for(retry_after);
+                    }
+
+                    parse_soap_response(http_response, retry_after)
+                })
+        }))
+    }
+
+    /// Wait until the limiter allows the call to be sent.
+    fn acquire(&self) -> BoxFuture<Slot> {
+        let inner = self.inner.clone();
+
+        Box::new(future::loop_fn((), move |()| {
+            let delay = if !inner.limiter.try_enter() {
+                Some(IN_FLIGHT_POLL)
+            } else {
+                let delay = inner.limiter.delay();
+                if delay.is_some() {
+                    inner.limiter.leave();
+                }
+                delay
+            };
+
+            match delay {
+                Some(delay) => Either::A(sleep(delay).map(|_| Loop::Continue(()))),
+                None => Either::B(future::ok(Loop::Break(Slot(inner.clone())))),
+            }
+        }))
+    }
+
+    /// Call a method and parse the element at `return_name` in the response body.
+    fn call_for<T>(&self, method: Method, return_name: &'static str) -> BoxFuture<T>
+    where
+        T: FromElement + Send + 'static,
+    {
+        Box::new(self.call(method).and_then(move |response| {
+            let element = response.body.descend(&[return_name])?;
+
+            T::from_element(element)
+        }))
+    }
+
+    fn token_element(&self) -> Element {
+        Element::node("token").with_text(self.token())
+    }
+}
+
+/// Resolve after the delay, on the timer of the runtime.
+fn sleep(delay: Duration) -> impl Future<Item = (), Error = Error> {
+    Delay::new(Instant::now() + delay).map_err(|e| Error::Io(io::Error::other(e)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "testing")]
+    use futures::future;
+    #[cfg(feature = "testing")]
+    use testing::{FakeConfluence, StubServer, FAKE_USER, STUB_PASSWORD};
+    use tokio::runtime::Runtime;
+    use Session;
+
+    #[cfg(feature = "testing")]
+    fn started() -> (StubServer, Space, Runtime) {
+        let confluence = FakeConfluence::new();
+        let space = confluence.seed_space("DOC", "Documentation");
+
+        (
+            StubServer::start(confluence).unwrap(),
+            space,
+            Runtime::new().unwrap(),
+        )
+    }
+
+    #[cfg(feature = "testing")]
+    fn logins(server: &StubServer) -> usize {
+        server
+            .operations()
+            .iter()
+            .filter(|operation| *operation == "login")
+            .count()
+    }
+
+    #[test]
+    #[cfg(feature = "testing")]
+    fn serves_session_end_to_end() {
+        let (server, space, mut runtime) = started();
+
+        let session = runtime
+            .block_on(AsyncSession::login(&server.url(), FAKE_USER, STUB_PASSWORD))
+            .unwrap();
+        let guide = runtime
+            .block_on(session.store_page(UpdatePage::with_create_fields(
+                Some(space.home_page),
+                "DOC",
+                "Guide",
+                "<p>v1</p>",
+            )))
+            .unwrap();
+        let children = runtime
+            .block_on(session.get_children(space.home_page))
+            .unwrap();
+        assert_eq!(children[0].id, guide.id);
+
+        match runtime.block_on(session.get_page_by_id(404)) {
+            Err(ref e) if e.fault_kind() == Some(FaultKind::PageNotFound) => {}
+            other => panic!("expected page not found, received {:?}", other),
+        }
+        assert!(runtime.block_on(session.logout()).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "testing")]
+    fn logs_in_again_when_token_expires() {
+        let (server, _, mut runtime) = started();
+
+        let session = runtime
+            .block_on(
+                Session::builder(server.url())
+                    .auto_relogin()
+                    .login_async(FAKE_USER, STUB_PASSWORD),
+            )
+            .unwrap();
+        server.expire_tokens();
+
+        assert_eq!(
+            runtime.block_on(session.get_space("DOC")).unwrap().key,
+            "DOC"
+        );
+        assert_eq!(logins(&server), 2);
+
+        let session = runtime
+            .block_on(AsyncSession::login(&server.url(), FAKE_USER, STUB_PASSWORD))
+            .unwrap();
+        server.expire_tokens();
+        assert_eq!(
+            runtime
+                .block_on(session.get_space("DOC"))
+                .unwrap_err()
+                .fault_kind(),
+            Some(FaultKind::InvalidSession)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "testing")]
+    fn logs_in_once_for_concurrent_expired_calls() {
+        let (server, _, mut runtime) = started();
+        let relogins = Arc::new(Mutex::new(0));
+
+        let session = {
+            let relogins = relogins.clone();
+            runtime
+                .block_on(
+                    Session::builder(server.url())
+                        .auto_relogin()
+                        .on_relogin(move |_| *relogins.lock().unwrap() += 1)
+                        .login_async(FAKE_USER, STUB_PASSWORD),
+                )
+                .unwrap()
+        };
+        server.expire_tokens();
+
+        let spaces = runtime
+            .block_on(future::join_all(
+                (0..8).map(|_| session.get_space("DOC")).collect::<Vec<_>>(),
+            ))
+            .unwrap();
+        assert_eq!(spaces.len(), 8);
+        assert_eq!(logins(&server), 2);
+        assert_eq!(*relogins.lock().unwrap(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "testing")]
+    fn retries_throttled_calls_within_limits() {
+        let (server, _, mut runtime) = started();
+
+        let session = runtime
+            .block_on(
+                Session::builder(server.url())
+                    .retry_policy(
+                        RetryPolicy::new()
+                            .backoff(Duration::from_millis(1), Duration::from_millis(1)),
+                    )
+                    .max_in_flight(1)
+                    .login_async(FAKE_USER, STUB_PASSWORD),
+            )
+            .unwrap();
+        server.throttle(2);
+
+        let spaces = runtime
+            .block_on(future::join_all(
+                (0..3).map(|_| session.get_space("DOC")).collect::<Vec<_>>(),
+            ))
+            .unwrap();
+        assert_eq!(spaces.len(), 3);
+
+        let session = runtime
+            .block_on(AsyncSession::login(&server.url(), FAKE_USER, STUB_PASSWORD))
+            .unwrap();
+        server.throttle(1);
+        match runtime.block_on(session.get_space("DOC")) {
+            Err(Error::UnexpectedResponse {
+                status,
+                retry_after,
+                ..
+            }) => {
+                assert_eq!(status.as_u16(), 429);
+                assert_eq!(retry_after, Some(Duration::from_secs(0)));
+            }
+            other => panic!("expected unexpected response, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_other_backends() {
+        let result = Runtime::new().unwrap().block_on(
+            Session::builder("https://confluence")
+                .backend(::Backend::XmlRpc)
+                .login_async("user", "pass"),
+        );
+
+        match result {
+            Err(Error::Unsupported { ref method, .. }) if method == "login" => {}
+            Err(e) => panic!("expected unsupported login, received {:?}", e),
+            Ok(_) => panic!("expected unsupported login"),
+        }
+    }
+}
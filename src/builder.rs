@@ -0,0 +1,653 @@
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use futures::future;
+
+use async_session::AsyncSettings;
+use http::{Auth, Certificate, Proxy, ReqwestTransport, ReqwestTransportBuilder, Transport};
+use limit::Limiter;
+use relogin::{CredentialProvider, Relogin, ReloginEvent, ReloginHook, RetainedCredentials};
+use wsdl::Wsdl;
+use {
+    wsdl, wsdl_url, AsyncSession, Backend, BoxFuture, DropBehavior, Error, Result, RetryPolicy,
+    Session, SessionState,
+};
+
+/**
+Configures and creates a `Session`.
+
+Created with `Session::builder`. The HTTP settings are used for every
+request the session makes, including the WSDL download.
+
+## Example
+
+```no_run
+use confluence::http::{Certificate, Proxy};
+use confluence::Session;
+use std::fs;
+use std::time::Duration;
+
+let ca = Certificate::from_pem(&fs::read("internal-ca.pem").unwrap()).unwrap();
+
+let session = Session::builder("https://confluence")
+    .timeout(Duration::from_secs(60))
+    .connect_timeout(Duration::from_secs(5))
+    .proxy(Proxy::all("http://proxy.corp:3128").unwrap())
+    .add_root_certificate(ca)
+    .user_agent("confluence-sync/1.0")
+    .login("user", "pass")
+    .unwrap();
+```
+*/
+pub struct SessionBuilder {
+    url: String,
+    http: ReqwestTransportBuilder,
+    transport: Option<Box<dyn Transport>>,
+    relogin: ReloginCredentials,
+    relogin_hook: Option<ReloginHook>,
+    retry: RetryPolicy,
+    limiter: Limiter,
+    drop_behavior: DropBehavior,
+    wsdl: WsdlSource,
+    validate_arguments: bool,
+    backend: Backend,
+}
+
+/// Where to get the WSDL from.
+enum WsdlSource {
+    Fetch,
+    Cached { path: PathBuf, ttl: Duration },
+    Provided(Wsdl),
+    Bundled,
+}
+
+/// Where to get credentials for re-login.
+enum ReloginCredentials {
+    Disabled,
+    Retained,
+    Provider(Box<dyn CredentialProvider>),
+}
+
+impl SessionBuilder {
+    /// Create builder for confluence at the given base url.
+    pub fn new<S: Into<String>>(url: S) -> SessionBuilder {
+        SessionBuilder {
+            url: url.into(),
+            http: ReqwestTransport::builder(),
+            transport: None,
+            relogin: ReloginCredentials::Disabled,
+            relogin_hook: None,
+            retry: RetryPolicy::never(),
+            limiter: Limiter::default(),
+            drop_behavior: DropBehavior::default(),
+            wsdl: WsdlSource::Fetch,
+            validate_arguments: false,
+            backend: Backend::default(),
+        }
+    }
+
+    /// Timeout for the whole request, from connecting until the response body is read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http = self.http.timeout(timeout);
+        self
+    }
+
+    /// Timeout for the connect phase only.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http = self.http.connect_timeout(timeout);
+        self
+    }
+
+    /// Route requests through a proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.http = self.http.proxy(proxy);
+        self
+    }
+
+    /// Trust an additional root certificate, such as an internal CA.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.http = self.http.add_root_certificate(certificate);
+        self
+    }
+
+    /// Present a client certificate, given as a DER-encoded PKCS#12 archive.
+    pub fn pkcs12_identity<S: Into<String>>(mut self, der: Vec<u8>, password: S) -> Self {
+        self.http = self.http.pkcs12_identity(der, password);
+        self
+    }
+
+    /// Value of the `User-Agent` header sent with every request.
+    ///
+    /// A value with characters not allowed in a header, such as a newline, makes
+    /// `login` fail with `HttpError::InvalidHeader`.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.http = self.http.user_agent(user_agent);
+        self
+    }
+
+    /// Maximum number of idle connections kept open per host.
+    pub fn max_idle_per_host(mut self, max: usize) -> Self {
+        self.http = self.http.max_idle_per_host(max);
+        self
+    }
+
+    /// Close all pooled connections once the session was not used for this long.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.http = self.http.pool_idle_timeout(timeout);
+        self
+    }
+
+    /// Send the credentials in the `Authorization` header of every request.
+    ///
+    /// Use `connect` to create the session without a SOAP login.
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.http = self.http.auth(auth);
+        self
+    }
+
+    /// Authenticate every request with a personal access token, sent as `Authorization: Bearer`.
+    ///
+    /// Use `connect` to create the session without a SOAP login.
+    pub fn personal_access_token<S: Into<String>>(self, token: S) -> Self {
+        self.auth(Auth::Bearer(token.into()))
+    }
+
+    /// Send requests over a custom transport.
+    ///
+    /// The HTTP settings above are ignored when a custom transport is used.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Log in again with the same credentials when the session token expires.
+    ///
+    /// The method which failed because of the expired token is retried once.
+    /// The credentials are kept in memory for the lifetime of the session.
+    pub fn auto_relogin(mut self) -> Self {
+        self.relogin = ReloginCredentials::Retained;
+        self
+    }
+
+    /// Log in again with credentials from the provider when the session token expires.
+    ///
+    /// The method which failed because of the expired token is retried once.
+    pub fn credential_provider<P: CredentialProvider + 'static>(mut self, provider: P) -> Self {
+        self.relogin = ReloginCredentials::Provider(Box::new(provider));
+        self
+    }
+
+    /// Observe re-authentication events.
+    ///
+    /// Only used together with `auto_relogin` or `credential_provider`.
+    pub fn on_relogin<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&ReloginEvent) + Send + Sync + 'static,
+    {
+        self.relogin_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Retry calls which failed because of transient errors.
+    ///
+    /// By default calls are not retried.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Send at most `requests` calls per `per` period, allowing bursts of up to `requests` calls.
+    ///
+    /// Calls over the limit block until they are allowed.
+    pub fn rate_limit(mut self, requests: u32, per: Duration) -> Self {
+        self.limiter.rate_limit(requests, per);
+        self
+    }
+
+    /// Send at most `max` calls at the same time, when the session is shared between threads.
+    pub fn max_in_flight(mut self, max: usize) -> Self {
+        self.limiter.max_in_flight(max);
+        self
+    }
+
+    /// What happens to the auth token when the session is dropped.
+    ///
+    /// By default the session logs out and reports errors to the log.
+    pub fn on_drop(mut self, behavior: DropBehavior) -> Self {
+        self.drop_behavior = behavior;
+        self
+    }
+
+    /// Use the given WSDL instead of fetching it, for example one loaded with `wsdl::load`.
+    pub fn wsdl(mut self, wsdl: Wsdl) -> Self {
+        self.wsdl = WsdlSource::Provided(wsdl);
+        self
+    }
+
+    /// Use the operations of the v2 service bundled with this library instead of fetching the WSDL.
+    ///
+    /// See `Wsdl::bundled_v2`.
+    pub fn bundled_wsdl(mut self) -> Self {
+        self.wsdl = WsdlSource::Bundled;
+        self
+    }
+
+    /// Keep the fetched WSDL in a file, and use it instead of fetching while it is younger than `ttl`.
+    pub fn wsdl_cache<P: Into<PathBuf>>(mut self, path: P, ttl: Duration) -> Self {
+        self.wsdl = WsdlSource::Cached {
+            path: path.into(),
+            ttl,
+        };
+        self
+    }
+
+    /// Check method arguments against the operation signatures in the WSDL before sending.
+    ///
+    /// Calls which do not match fail with `Error::InvalidArguments`. Nothing is checked
+    /// when the WSDL has no signatures, such as the bundled or saved one.
+    pub fn validate_arguments(mut self) -> Self {
+        self.validate_arguments = true;
+        self
+    }
+
+    /**
+    Remote API to call the methods over, SOAP by default.
+
+    With `Backend::XmlRpc` and `Backend::Rest` no WSDL is fetched. A WSDL set with
+    `wsdl` is still used by `Backend::XmlRpc`, to send the method arguments with the
+    types of the operation signatures. With `Backend::Rest`, `login` sends the credentials with basic
+    auth instead of calling the `login` method; a custom transport has to
+    authenticate the requests itself.
+
+    ## Example
+
+    ```no_run
+    use confluence::{Backend, Session};
+
+    let session = Session::builder("https://confluence")
+        .backend(Backend::XmlRpc)
+        .login("user", "pass")
+        .unwrap();
+
+    println!("Page: {:#?}", session.get_page_by_id(123456));
+    ```
+    */
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Create the session by logging in with the given credentials.
+    pub fn login(mut self, user: &str, pass: &str) -> Result<Session> {
+        debug!("logging in at url {:?} with user {:?}", self.url, user);
+
+        if self.backend == Backend::Rest {
+            self.http = self.http.auth(Auth::Basic {
+                user: user.into(),
+                pass: pass.into(),
+            });
+            return self.connect();
+        }
+
+        let (mut session, drop_behavior) = self.build(None, String::new(), Some((user, pass)))?;
+
+        *session.token.get_mut().unwrap() = session.login_call(user, pass)?;
+        session.drop_behavior = drop_behavior;
+
+        Ok(session)
+    }
+
+    /**
+    Create a non-blocking session by logging in with the given credentials.
+
+    The HTTP settings, retry policy, rate limits and re-login settings apply
+    to the `AsyncSession` as they do to `Session`. A custom `transport`, `auth`
+    and `pool_idle_timeout` are not used, nor is the WSDL cache: the WSDL is
+    fetched unless it is given with `wsdl` or `bundled_wsdl`. Only `Backend::Soap`
+    is supported, other backends fail with `Error::Unsupported`.
+
+    ## Example
+
+    ```no_run
+    extern crate confluence;
+    extern crate futures;
+
+    use confluence::{RetryPolicy, Session};
+    use futures::Future;
+
+    # fn main() {
+    let page = Session::builder("https://confluence")
+        .retry_policy(RetryPolicy::new())
+        .auto_relogin()
+        .login_async("user", "pass")
+        .and_then(|session| session.get_page_by_id(123456));
+    # }
+    ```
+    */
+    pub fn login_async(self, user: &str, pass: &str) -> BoxFuture<AsyncSession> {
+        debug!("logging in at url {:?} with user {:?}", self.url, user);
+
+        if self.backend != Backend::Soap {
+            return Box::new(future::err(Error::Unsupported {
+                method: "login".into(),
+                backend: self.backend,
+            }));
+        }
+
+        let client = match self.http.build_async() {
+            Ok(client) => client,
+            Err(e) => return Box::new(future::err(e.into())),
+        };
+
+        let wsdl = match self.wsdl {
+            WsdlSource::Provided(wsdl) => Some(wsdl),
+            WsdlSource::Bundled => Some(Wsdl::bundled_v2(&self.url)),
+            WsdlSource::Fetch | WsdlSource::Cached { .. } => None,
+        };
+
+        let settings = AsyncSettings {
+            wsdl,
+            relogin: relogin(self.relogin, self.relogin_hook, Some((user, pass))),
+            retry: self.retry,
+            limiter: self.limiter,
+        };
+
+        AsyncSession::login_with_settings(client, &self.url, user, pass, settings)
+    }
+
+    /**
+    Create the session without logging in, for requests authenticated at the HTTP layer.
+
+    The SOAP `token` argument is left empty, which Confluence accepts when the request
+    carries credentials in the `Authorization` header (see `auth` and
+    `personal_access_token`). There is nothing to log out from, so the session
+    never logs out when dropped.
+
+    ## Example
+
+    ```no_run
+    let session = confluence::Session::builder("https://confluence")
+        .personal_access_token("NjU0OTk1ODk1NjQ5On5sTI1YjL0GQbF")
+        .connect()
+        .unwrap();
+
+    println!("Space: {:#?}", session.get_space("SomeSpaceKey"));
+    ```
+    */
+    pub fn connect(self) -> Result<Session> {
+        debug!("connecting to url {:?} without login", self.url);
+
+        let (session, _) = self.build(None, String::new(), None)?;
+
+        Ok(session)
+    }
+
+    /// Create the session using a token received from an earlier login.
+    ///
+    /// The WSDL is fetched, but the token is not validated. `auto_relogin`
+    /// has no effect, as there are no credentials to log in again with.
+    pub fn resume_token(self, token: &str) -> Result<Session> {
+        debug!("resuming session at url {:?}", self.url);
+
+        let (mut session, drop_behavior) = self.build(None, token.into(), None)?;
+        session.drop_behavior = drop_behavior;
+
+        Ok(session)
+    }
+
+    /// Create the session from a saved state, without any requests to the server.
+    ///
+    /// The url of the state is used instead of the one given to the builder.
+    /// The token is not validated. `auto_relogin` has no effect, as there are
+    /// no credentials to log in again with.
+    pub fn resume(mut self, state: SessionState) -> Result<Session> {
+        debug!("resuming session at url {:?}", state.url);
+
+        self.url = state.url;
+        let (mut session, drop_behavior) = self.build(Some(state.wsdl), state.token, None)?;
+        session.drop_behavior = drop_behavior;
+
+        Ok(session)
+    }
+
+    /// Resume the session from a saved state if its token is still valid, or log in.
+    ///
+    /// The token is validated with a `getServerInfo` call. The saved state is ignored
+    /// if it is for a different url. When the token has expired, the session logs in
+    /// with the given credentials. If the saved WSDL lacks `getServerInfo`, it is
+    /// fetched again and the session logs in.
+    pub fn resume_or_login(
+        self,
+        state: Option<SessionState>,
+        user: &str,
+        pass: &str,
+    ) -> Result<Session> {
+        if self.backend == Backend::Rest {
+            return self.login(user, pass);
+        }
+
+        let state = match state {
+            Some(ref state) if wsdl_url(&state.url) == wsdl_url(&self.url) => state.clone(),
+            _ => return self.login(user, pass),
+        };
+
+        debug!("resuming session at url {:?}", self.url);
+
+        let (mut session, drop_behavior) =
+            self.build(Some(state.wsdl), state.token, Some((user, pass)))?;
+
+        let valid = match session.check_token() {
+            Err(Error::MethodNotFoundInWsdl(_)) => {
+                debug!("saved wsdl is incomplete, fetching it again");
+                session.wsdl = wsdl::fetch_with(&session.transport, &wsdl_url(&session.url))?;
+                false
+            }
+            other => other?,
+        };
+
+        if !valid {
+            debug!(
+                "saved token is no longer valid, logging in again as {:?}",
+                user
+            );
+
+            *session.token.get_mut().unwrap() = session.login_call(user, pass)?;
+        }
+
+        session.drop_behavior = drop_behavior;
+
+        Ok(session)
+    }
+
+    /// Create the session with the given token, fetching the WSDL if none is given.
+    ///
+    /// The session keeps the token on drop, the configured drop behavior is returned
+    /// to be set once the session is ready.
+    fn build(
+        self,
+        wsdl: Option<wsdl::Wsdl>,
+        token: String,
+        credentials: Option<(&str, &str)>,
+    ) -> Result<(Session, DropBehavior)> {
+        // a custom transport is trusted to authenticate a session created with `connect`
+        let http_auth = self.http.has_auth()
+            || (self.transport.is_some() && token.is_empty() && credentials.is_none());
+
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => Box::new(self.http.build()?),
+        };
+
+        let wsdl = match (wsdl, self.wsdl) {
+            (Some(wsdl), _) | (None, WsdlSource::Provided(wsdl)) => wsdl,
+            (None, _) if self.backend != Backend::Soap => Wsdl::default(),
+            (None, WsdlSource::Bundled) => Wsdl::bundled_v2(&self.url),
+            (None, WsdlSource::Cached { path, ttl }) => {
+                wsdl::fetch_cached(&transport, &wsdl_url(&self.url), path, ttl)?
+            }
+            (None, WsdlSource::Fetch) => {
+                let wsdl_url = wsdl_url(&self.url);
+
+                debug!("getting wsdl from url {:?}", wsdl_url);
+
+                wsdl::fetch_with(&transport, &wsdl_url)?
+            }
+        };
+
+        let session = Session {
+            url: self.url,
+            transport,
+            wsdl,
+            token: RwLock::new(token),
+            relogin: relogin(self.relogin, self.relogin_hook, credentials),
+            retry: self.retry,
+            limiter: self.limiter,
+            drop_behavior: DropBehavior::KeepToken,
+            validate_arguments: self.validate_arguments,
+            backend: self.backend,
+            http_auth,
+        };
+
+        Ok((session, self.drop_behavior))
+    }
+}
+
+/// Re-login settings for the credentials the session logs in with, if any.
+fn relogin(
+    relogin: ReloginCredentials,
+    hook: Option<ReloginHook>,
+    credentials: Option<(&str, &str)>,
+) -> Option<Relogin> {
+    let provider: Option<Box<dyn CredentialProvider>> = match (relogin, credentials) {
+        (ReloginCredentials::Disabled, _) | (ReloginCredentials::Retained, None) => None,
+        (ReloginCredentials::Retained, Some((user, pass))) => Some(Box::new(RetainedCredentials {
+            user: user.into(),
+            pass: pass.into(),
+        })),
+        (ReloginCredentials::Provider(provider), _) => Some(provider),
+    };
+
+    provider.map(|credentials| Relogin { credentials, hook })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fixtures::{CannedTransport, GET_SPACE};
+    use std::sync::Arc;
+
+    /// Transport which knows the space `DOC` and answers the token check of `resume_or_login`.
+    fn transport() -> Arc<CannedTransport> {
+        Arc::new(CannedTransport::new(&[
+            ("getSpace", GET_SPACE),
+            (
+                "getServerInfo",
+                r#"<ns1:getServerInfoResponse><getServerInfoReturn>
+                    <majorVersion xsi:type="xsd:int">5</majorVersion>
+                </getServerInfoReturn></ns1:getServerInfoResponse>"#,
+            ),
+        ]))
+    }
+
+    fn saved_state(transport: &Arc<CannedTransport>) -> SessionState {
+        Session::builder("https://confluence")
+            .transport(transport.clone())
+            .on_drop(DropBehavior::KeepToken)
+            .login("user", "pass")
+            .unwrap()
+            .state()
+    }
+
+    #[test]
+    fn resumes_saved_token_when_still_valid() {
+        let transport = transport();
+        let state = saved_state(&transport);
+
+        let session = Session::builder("https://confluence/")
+            .transport(transport.clone())
+            .on_drop(DropBehavior::KeepToken)
+            .resume_or_login(Some(state), "user", "pass")
+            .unwrap();
+
+        assert_eq!(session.token(), "a3a8ecc6d5");
+        assert_eq!(transport.actions(), vec!["login", "getServerInfo"]);
+    }
+
+    #[test]
+    fn logs_in_when_saved_token_has_expired() {
+        let transport = transport();
+        let mut state = saved_state(&transport);
+        state.token = "expired".into();
+
+        transport.expire_token();
+
+        let session = Session::builder("https://confluence")
+            .transport(transport.clone())
+            .on_drop(DropBehavior::KeepToken)
+            .resume_or_login(Some(state), "user", "pass")
+            .unwrap();
+
+        assert_eq!(session.token(), "a3a8ecc6d5");
+        assert_eq!(transport.actions(), vec!["login", "getServerInfo", "login"]);
+    }
+
+    #[test]
+    fn resumes_from_token_without_login() {
+        let transport = transport();
+
+        {
+            let session = Session::builder("https://confluence")
+                .transport(transport.clone())
+                .resume_token("a3a8ecc6d5")
+                .unwrap();
+
+            assert_eq!(session.get_space("DOC").unwrap().key, "DOC");
+        }
+
+        assert_eq!(transport.actions(), vec!["getSpace", "logout"]);
+    }
+
+    #[test]
+    fn connects_without_login_or_logout() {
+        let transport = transport();
+
+        {
+            let session = Session::builder("https://confluence")
+                .transport(transport.clone())
+                .connect()
+                .unwrap();
+
+            assert_eq!(session.token(), "");
+            assert_eq!(session.get_space("DOC").unwrap().key, "DOC");
+        }
+
+        assert_eq!(transport.actions(), vec!["getSpace"]);
+    }
+
+    #[test]
+    fn starts_with_bundled_wsdl_without_fetching() {
+        let transport = transport();
+        let session = Session::builder("https://confluence")
+            .transport(transport.clone())
+            .bundled_wsdl()
+            .login("user", "pass")
+            .unwrap();
+
+        assert_eq!(session.get_space("DOC").unwrap().key, "DOC");
+        assert_eq!(transport.wsdl_requests(), 0);
+    }
+
+    #[test]
+    fn fetches_wsdl_once_per_session() {
+        let transport = transport();
+        let session = Session::builder("https://confluence")
+            .transport(transport.clone())
+            .login("user", "pass")
+            .unwrap();
+
+        session.get_space("DOC").unwrap();
+        session.get_space("DOC").unwrap();
+        assert_eq!(transport.wsdl_requests(), 1);
+    }
+}
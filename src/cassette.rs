@@ -0,0 +1,686 @@
+//! Recording SOAP traffic to cassette files, and replaying it without a server.
+//!
+//! A cassette is a JSON file with the requests a `Session` made and the responses
+//! it received. Record one against a staging Confluence with `RecordingTransport`,
+//! commit it, and replay it in CI with `ReplayTransport`.
+
+use reqwest::header::HeaderMap;
+use reqwest::Url;
+use serde_json::Value as JsonValue;
+use std::error::Error as StdError;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use xmltree::Element;
+
+use http::{HttpError, Method, Response, Result, StatusCode, Transport};
+use rpser;
+use rpser::xml::BuildElement;
+
+/// Replaces the login password and session tokens in cassettes.
+pub const REDACTED: &str = "REDACTED";
+
+/// Request and response recorded in a cassette.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interaction {
+    /// `GET` for the WSDL, `POST` for SOAP calls.
+    pub method: String,
+    /// Path and query of the url, without the server.
+    pub path: String,
+    /// Name of the SOAP operation, empty for `GET`.
+    pub operation: String,
+    /// Arguments of the operation as JSON, which replayed requests are matched by.
+    ///
+    /// Arguments with text are strings, and arguments with child elements are
+    /// objects, or arrays when all children are `item`s.
+    pub arguments: JsonValue,
+    /// The SOAP envelope sent, empty for `GET`.
+    pub request: String,
+    /// Status of the response, `0` if the request failed.
+    pub status: u16,
+    pub response: String,
+    /// Error of the inner transport, if no response was received.
+    pub error: Option<String>,
+}
+
+/// Recorded interactions, in the order they were made.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Read a cassette file written by `Cassette::save`.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Cassette> {
+        let json: JsonValue = serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let interactions = match json.get("interactions").and_then(JsonValue::as_array) {
+            Some(interactions) => interactions,
+            None => return Err(invalid_cassette("interactions")),
+        };
+
+        interactions
+            .iter()
+            .map(|interaction| {
+                let text = |name: &str| {
+                    interaction
+                        .get(name)
+                        .and_then(JsonValue::as_str)
+                        .map(String::from)
+                        .ok_or_else(|| invalid_cassette(name))
+                };
+
+                Ok(Interaction {
+                    method: text("method")?,
+                    path: text("path")?,
+                    operation: text("operation")?,
+                    arguments: interaction
+                        .get("arguments")
+                        .cloned()
+                        .unwrap_or(JsonValue::Null),
+                    request: text("request")?,
+                    status: interaction
+                        .get("status")
+                        .and_then(JsonValue::as_u64)
+                        .map(|status| status as u16)
+                        .ok_or_else(|| invalid_cassette("status"))?,
+                    response: text("response")?,
+                    error: interaction
+                        .get("error")
+                        .and_then(JsonValue::as_str)
+                        .map(String::from),
+                })
+            })
+            .collect::<io::Result<_>>()
+            .map(|interactions| Cassette { interactions })
+    }
+
+    /// Write the cassette as pretty-printed JSON, which reads well in diffs.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = json!({
+            "interactions": self
+                .interactions
+                .iter()
+                .map(|interaction| json!({
+                    "method": interaction.method,
+                    "path": interaction.path,
+                    "operation": interaction.operation,
+                    "arguments": interaction.arguments,
+                    "request": interaction.request,
+                    "status": interaction.status,
+                    "response": interaction.response,
+                    "error": interaction.error,
+                }))
+                .collect::<Vec<_>>(),
+        });
+
+        fs::write(path, serde_json::to_string_pretty(&json)?)
+    }
+}
+
+fn invalid_cassette(field: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("cassette interaction has no valid {:?}", field),
+    )
+}
+
+/**
+Transport which records every request and response of the inner transport.
+
+The cassette is written to the file after each interaction. The login password
+and session tokens are replaced with `REDACTED`, both in the matched arguments
+and in the text of the argument and return elements of the recorded envelopes.
+Requests which fail in the inner transport are recorded with the error, and
+replayed as `RecordedFailure`. XML-RPC and REST requests are passed through
+without recording.
+
+## Example
+
+```no_run
+use confluence::cassette::RecordingTransport;
+use confluence::http::ReqwestTransport;
+
+let transport = RecordingTransport::new(ReqwestTransport::new(), "tests/cassettes/publish.json");
+let session = confluence::Session::login_with_transport(
+    transport,
+    "https://staging.confluence",
+    "user",
+    "pass"
+).unwrap();
+
+session.get_space("DOC").unwrap();
+```
+*/
+#[derive(Debug)]
+pub struct RecordingTransport<T> {
+    inner: T,
+    path: PathBuf,
+    recording: Mutex<Recording>,
+}
+
+#[derive(Debug, Default)]
+struct Recording {
+    cassette: Cassette,
+    /// Passwords and tokens seen so far, replaced in the argument and return elements.
+    secrets: Vec<String>,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Record the requests of `inner` to the cassette file at `path`, replacing it.
+    pub fn new<P: Into<PathBuf>>(inner: T, path: P) -> RecordingTransport<T> {
+        RecordingTransport {
+            inner,
+            path: path.into(),
+            recording: Mutex::new(Recording::default()),
+        }
+    }
+
+    /// Interactions recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.recording().cassette.clone()
+    }
+
+    fn record(&self, mut interaction: Interaction) {
+        let mut recording = self.recording();
+
+        interaction.request = redact(&interaction.request, &recording.secrets);
+        interaction.response = redact(&interaction.response, &recording.secrets);
+
+        recording.cassette.interactions.push(interaction);
+        if let Err(e) = recording.cassette.save(&self.path) {
+            error!("[cassette] failed to write {:?}: {}", self.path, e);
+        }
+    }
+
+    fn recording(&self) -> MutexGuard<'_, Recording> {
+        self.recording.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn get(&self, url: &str) -> Result<Response> {
+        let result = self.inner.get(url);
+
+        self.record(Interaction {
+            method: "GET".into(),
+            path: path(url),
+            operation: String::new(),
+            arguments: JsonValue::Null,
+            request: String::new(),
+            ..outcome(&result)
+        });
+
+        result
+    }
+
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<Response> {
+        let (operation, arguments) = match parse_call(xml) {
+            Some((operation, args)) => {
+                self.recording().secrets.extend(secrets(&operation, &args));
+                let arguments = arguments(&operation, &args);
+                (operation, arguments)
+            }
+            None => (action.to_string(), JsonValue::Null),
+        };
+
+        let result = self.inner.soap_action(url, action, xml);
+
+        if let (Ok(response), "login") = (&result, operation.as_str()) {
+            let token = rpser::Response::from_xml(&response.body)
+                .ok()
+                .and_then(|response| response.body.children.first().cloned())
+                .and_then(|token| token.text);
+            if let Some(token) = token {
+                self.recording().secrets.push(token);
+            }
+        }
+
+        self.record(Interaction {
+            method: "POST".into(),
+            path: path(url),
+            operation,
+            arguments,
+            request: xml.into(),
+            ..outcome(&result)
+        });
+
+        result
+    }
+
+    fn post_xml(&self, url: &str, xml: &str) -> Result<Response> {
+        self.inner.post_xml(url, xml)
+    }
+
+    fn send_json(&self, method: Method, url: &str, json: Option<&str>) -> Result<Response> {
+        self.inner.send_json(method, url, json)
+    }
+}
+
+/**
+Transport which answers from a cassette, without a server.
+
+SOAP calls are matched by the operation name and the arguments, and `GET`
+requests by the path. Each interaction is replayed once, in the recorded order,
+and the last match is repeated when the same request is made more often than
+recorded. A request without any match fails with `NotRecorded`, wrapped in
+`HttpError::Transport`, which is never retried.
+
+## Example
+
+```no_run
+use confluence::cassette::ReplayTransport;
+
+let transport = ReplayTransport::load("tests/cassettes/publish.json").unwrap();
+// any password matches, it was redacted in the cassette
+let session = confluence::Session::login_with_transport(
+    transport,
+    "https://staging.confluence",
+    "user",
+    "pass"
+).unwrap();
+
+assert_eq!(session.get_space("DOC").unwrap().key, "DOC");
+```
+*/
+#[derive(Debug)]
+pub struct ReplayTransport {
+    interactions: Vec<Interaction>,
+    played: Mutex<Vec<bool>>,
+}
+
+impl ReplayTransport {
+    /// Replay the interactions of the cassette.
+    pub fn new(cassette: Cassette) -> ReplayTransport {
+        ReplayTransport {
+            played: Mutex::new(vec![false; cassette.interactions.len()]),
+            interactions: cassette.interactions,
+        }
+    }
+
+    /// Replay the cassette file.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<ReplayTransport> {
+        Cassette::load(path).map(ReplayTransport::new)
+    }
+
+    /// Interactions which have not been replayed yet.
+    pub fn unplayed(&self) -> Vec<&Interaction> {
+        let played = self.played.lock().unwrap_or_else(|e| e.into_inner());
+
+        self.interactions
+            .iter()
+            .zip(played.iter())
+            .filter(|&(_, &played)| !played)
+            .map(|(interaction, _)| interaction)
+            .collect()
+    }
+
+    fn replay<F>(&self, request: String, matches: F) -> Result<Response>
+    where
+        F: Fn(&Interaction) -> bool,
+    {
+        let mut played = self.played.lock().unwrap_or_else(|e| e.into_inner());
+
+        let matching: Vec<usize> = (0..self.interactions.len())
+            .filter(|&i| matches(&self.interactions[i]))
+            .collect();
+        let index = match matching.iter().find(|&&i| !played[i]).or(matching.last()) {
+            Some(&index) => index,
+            None => {
+                error!("[cassette] no recorded response matches {}", request);
+                return Err(NotRecorded { request }.into());
+            }
+        };
+        played[index] = true;
+
+        let interaction = &self.interactions[index];
+        if let Some(ref message) = interaction.error {
+            return Err(RecordedFailure {
+                message: message.clone(),
+            }
+            .into());
+        }
+
+        Ok(Response {
+            status: StatusCode::from_u16(interaction.status)
+                .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            headers: HeaderMap::new(),
+            body: interaction.response.clone(),
+        })
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn get(&self, url: &str) -> Result<Response> {
+        let path = path(url);
+
+        self.replay(format!("GET {}", path), |interaction| {
+            interaction.method == "GET" && interaction.path == path
+        })
+    }
+
+    fn soap_action(&self, _url: &str, action: &str, xml: &str) -> Result<Response> {
+        let (operation, arguments) = match parse_call(xml) {
+            Some((operation, args)) => {
+                let arguments = arguments(&operation, &args);
+                (operation, arguments)
+            }
+            None => (action.to_string(), JsonValue::Null),
+        };
+
+        self.replay(format!("{} {}", operation, arguments), |interaction| {
+            interaction.method == "POST"
+                && interaction.operation == operation
+                && interaction.arguments == arguments
+        })
+    }
+}
+
+/// Error of `ReplayTransport` when no recorded interaction matches the request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotRecorded {
+    /// Operation and arguments of the SOAP call, or the path of the `GET` request.
+    pub request: String,
+}
+
+impl fmt::Display for NotRecorded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no recorded response matches {}", self.request)
+    }
+}
+
+impl StdError for NotRecorded {}
+
+impl From<NotRecorded> for HttpError {
+    fn from(other: NotRecorded) -> HttpError {
+        HttpError::Transport(Box::new(other))
+    }
+}
+
+/// Error of `ReplayTransport` for an interaction which failed when it was recorded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedFailure {
+    /// The error of the recording transport.
+    pub message: String,
+}
+
+impl fmt::Display for RecordedFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "recorded request failed: {}", self.message)
+    }
+}
+
+impl StdError for RecordedFailure {}
+
+impl From<RecordedFailure> for HttpError {
+    fn from(other: RecordedFailure) -> HttpError {
+        HttpError::Transport(Box::new(other))
+    }
+}
+
+/// Path and query of the url.
+fn path(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+/// Operation name and argument elements of the SOAP envelope.
+fn parse_call(xml: &str) -> Option<(String, Vec<Element>)> {
+    let envelope = Element::parse(xml.as_bytes()).ok()?;
+    let operation = envelope.get_child("Body")?.children.first()?;
+
+    Some((operation.name.clone(), operation.children.clone()))
+}
+
+/// Status, body and error of the result of the inner transport, for the recorded interaction.
+fn outcome(result: &Result<Response>) -> Interaction {
+    let (status, response, error) = match *result {
+        Ok(ref response) => (response.status.as_u16(), response.body.clone(), None),
+        Err(ref e) => (0, String::new(), Some(e.to_string())),
+    };
+
+    Interaction {
+        method: String::new(),
+        path: String::new(),
+        operation: String::new(),
+        arguments: JsonValue::Null,
+        request: String::new(),
+        status,
+        response,
+        error,
+    }
+}
+
+/// Replace the secrets in the argument or return elements of the SOAP envelope.
+///
+/// The envelope is left as it is when it has no secrets in these elements, or is not XML.
+fn redact(xml: &str, secrets: &[String]) -> String {
+    let mut envelope = match Element::parse(xml.as_bytes()) {
+        Ok(envelope) => envelope,
+        Err(_) => return xml.to_string(),
+    };
+
+    let mut redacted = false;
+    if let Some(operation) = envelope
+        .get_mut_child("Body")
+        .and_then(|body| body.children.first_mut())
+    {
+        for element in &mut operation.children {
+            redacted |= redact_text(element, secrets);
+        }
+    }
+
+    if redacted {
+        envelope.to_string()
+    } else {
+        xml.to_string()
+    }
+}
+
+/// Replace the secrets in the text of the element and its descendants, returning whether any was found.
+fn redact_text(element: &mut Element, secrets: &[String]) -> bool {
+    let mut redacted = false;
+
+    if let Some(ref mut text) = element.text {
+        for secret in secrets {
+            if text.contains(secret.as_str()) {
+                *text = text.replace(secret.as_str(), REDACTED);
+                redacted = true;
+            }
+        }
+    }
+
+    for child in &mut element.children {
+        redacted |= redact_text(child, secrets);
+    }
+
+    redacted
+}
+
+/// Login password and session token passed to the operation.
+fn secrets(operation: &str, args: &[Element]) -> Vec<String> {
+    args.iter()
+        .enumerate()
+        .filter(|&(position, arg)| is_secret(operation, position, arg))
+        .filter_map(|(_, arg)| arg.text.clone())
+        .filter(|secret| !secret.is_empty())
+        .collect()
+}
+
+fn is_secret(operation: &str, position: usize, arg: &Element) -> bool {
+    arg.name == "token" || (operation == "login" && position == 1)
+}
+
+/// Arguments as JSON, with the secrets redacted.
+fn arguments(operation: &str, args: &[Element]) -> JsonValue {
+    JsonValue::Array(
+        args.iter()
+            .enumerate()
+            .map(|(position, arg)| {
+                if is_secret(operation, position, arg) {
+                    JsonValue::String(REDACTED.into())
+                } else {
+                    argument(arg)
+                }
+            })
+            .collect(),
+    )
+}
+
+fn argument(element: &Element) -> JsonValue {
+    if element.children.is_empty() {
+        return JsonValue::String(element.text.clone().unwrap_or_default());
+    }
+
+    if element.children.iter().all(|child| child.name == "item") {
+        return JsonValue::Array(element.children.iter().map(argument).collect());
+    }
+
+    JsonValue::Object(
+        element
+            .children
+            .iter()
+            .map(|child| (child.name.clone(), argument(child)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fixtures::{CannedTransport, GET_SPACE};
+    use std::env;
+    use std::sync::Arc;
+    use {Error, Session};
+
+    /// Server which issues the token `a3a8ecc6d5`, knows the space `DOC` and fails `getPage`.
+    fn server() -> CannedTransport {
+        CannedTransport::new(&[("getSpace", GET_SPACE)])
+    }
+
+    fn cassette_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("confluence-{}-{}.json", name, ::std::process::id()))
+    }
+
+    #[test]
+    fn records_redacted_cassette_and_replays_it() {
+        let path = cassette_path("record");
+
+        {
+            let session = Session::builder("https://staging")
+                .transport(RecordingTransport::new(server(), &path))
+                .bundled_wsdl()
+                .login("user", "s3cret")
+                .unwrap();
+            assert_eq!(session.get_space("DOC").unwrap().home_page, 42);
+        }
+
+        let recorded = fs::read_to_string(&path).unwrap();
+        assert!(!recorded.contains("s3cret"));
+        assert!(!recorded.contains("a3a8ecc6d5"));
+
+        let cassette = Cassette::load(&path).unwrap();
+        let operations: Vec<&str> = cassette
+            .interactions
+            .iter()
+            .map(|interaction| interaction.operation.as_str())
+            .collect();
+        assert_eq!(operations, vec!["login", "getSpace", "logout"]);
+        assert_eq!(cassette.interactions[1].arguments, json!([REDACTED, "DOC"]));
+
+        let replay = Arc::new(ReplayTransport::load(&path).unwrap());
+        {
+            let session = Session::builder("http://localhost")
+                .transport(replay.clone())
+                .bundled_wsdl()
+                .login("user", "another password")
+                .unwrap();
+            assert_eq!(session.get_space("DOC").unwrap().key, "DOC");
+
+            match session.get_space("NONE") {
+                Err(Error::Http(HttpError::Transport(e))) => {
+                    let not_recorded = e.downcast_ref::<NotRecorded>().unwrap();
+                    assert!(not_recorded.request.starts_with("getSpace"))
+                }
+                other => panic!("expected unmatched request, received {:?}", other),
+            }
+        }
+        assert!(replay.unplayed().is_empty());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn redacts_only_argument_and_return_text() {
+        let path = cassette_path("markup");
+
+        {
+            // the password also appears in the markup of every envelope
+            Session::builder("https://staging")
+                .transport(RecordingTransport::new(server(), &path))
+                .bundled_wsdl()
+                .login("user", "Envelope")
+                .unwrap();
+        }
+
+        let cassette = Cassette::load(&path).unwrap();
+        let login = &cassette.interactions[0];
+        assert_eq!(login.arguments, json!(["user", REDACTED]));
+        assert!(!login.request.contains(">Envelope<"));
+        assert_eq!(
+            parse_call(&login.request).map(|(operation, args)| arguments(&operation, &args)),
+            Some(json!(["user", REDACTED]))
+        );
+        assert!(login.response.contains("Envelope"));
+        assert!(!login.response.contains("a3a8ecc6d5"));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn records_and_replays_failed_requests() {
+        let path = cassette_path("failure");
+
+        {
+            let session = Session::builder("https://staging")
+                .transport(RecordingTransport::new(server(), &path))
+                .bundled_wsdl()
+                .login("user", "s3cret")
+                .unwrap();
+            assert!(session.get_page_by_id(42).is_err());
+        }
+
+        let cassette = Cassette::load(&path).unwrap();
+        let failed = &cassette.interactions[1];
+        assert_eq!(failed.operation, "getPage");
+        assert_eq!(failed.status, 0);
+        assert_eq!(
+            failed.error.as_deref(),
+            Some("transport does not support getPage")
+        );
+
+        let session = Session::builder("http://localhost")
+            .transport(ReplayTransport::load(&path).unwrap())
+            .bundled_wsdl()
+            .login("user", "s3cret")
+            .unwrap();
+        match session.get_page_by_id(42) {
+            Err(Error::Http(HttpError::Transport(e))) => assert_eq!(
+                e.downcast_ref::<RecordedFailure>()
+                    .map(|e| e.message.as_str()),
+                Some("transport does not support getPage")
+            ),
+            other => panic!("expected recorded failure, received {:?}", other),
+        }
+
+        fs::remove_file(&path).unwrap();
+    }
+}
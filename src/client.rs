@@ -0,0 +1,779 @@
+//! Backend-agnostic interface to the page and space operations.
+
+use std::sync::Arc;
+
+use wsdl::ValidationError;
+use {
+    Error, MovePosition, Page, PageSummary, PageUpdateOptions, Pages, Result, Session, Space,
+    SpaceSummary, TrashContents, UpdatePage, UpdateSpace,
+};
+
+/**
+Page and space operations of a Confluence client.
+
+Implemented by `Session` for every backend, although `Backend::Rest` fails
+most space operations with `Error::Unsupported`. Depend on this trait instead
+of `Session` to swap in a fake, or to wrap the session in decorators which add
+caching, logging or a dry run.
+
+## Example
+
+```no_run
+use confluence::{ConfluenceClient, Page, PageSummary, PageUpdateOptions, Result, Space, UpdatePage};
+# use confluence::{MovePosition, SpaceSummary, TrashContents, UpdateSpace};
+
+/// Logs every stored page, and stores nothing.
+struct DryRun<C>(C);
+
+impl<C: ConfluenceClient> ConfluenceClient for DryRun<C> {
+    fn get_space(&self, space_key: &str) -> Result<Space> {
+        self.0.get_space(space_key)
+    }
+#
+#   fn get_spaces(&self) -> Result<Vec<SpaceSummary>> { self.0.get_spaces() }
+#   fn add_space(&self, space: UpdateSpace) -> Result<Space> { self.0.add_space(space) }
+#   fn add_personal_space(&self, space: UpdateSpace, user: &str) -> Result<Space> { self.0.add_personal_space(space, user) }
+#   fn remove_space(&self, key: &str) -> Result<bool> { self.0.remove_space(key) }
+#   fn store_space(&self, space: UpdateSpace) -> Result<Space> { self.0.store_space(space) }
+#   fn archive_space(&self, key: &str) -> Result<bool> { self.0.archive_space(key) }
+#   fn unarchive_space(&self, key: &str) -> Result<bool> { self.0.unarchive_space(key) }
+
+    fn get_page_by_title(&self, space_key: &str, page_title: &str) -> Result<Page> {
+        self.0.get_page_by_title(space_key, page_title)
+    }
+
+    fn get_page_by_id(&self, page_id: i64) -> Result<Page> {
+        self.0.get_page_by_id(page_id)
+    }
+
+    fn store_page(&self, page: UpdatePage) -> Result<Page> {
+        println!("would store {:?}", page);
+        self.0.get_page_by_title(&page.space, &page.title)
+    }
+
+    fn update_page(&self, page: UpdatePage, _options: PageUpdateOptions) -> Result<Page> {
+        self.store_page(page)
+    }
+
+    fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        self.0.get_children(page_id)
+    }
+#
+#   fn get_descendants(&self, id: i64) -> Result<Vec<PageSummary>> { self.0.get_descendants(id) }
+#   fn get_pages(&self, key: &str) -> Result<Vec<PageSummary>> { self.0.get_pages(key) }
+#   fn move_page(&self, id: i64, target: i64, position: MovePosition) -> Result<bool> { self.0.move_page(id, target, position) }
+#   fn move_page_to_top_level(&self, id: i64, key: &str) -> Result<bool> { self.0.move_page_to_top_level(id, key) }
+#   fn remove_page(&self, id: i64) -> Result<bool> { self.0.remove_page(id) }
+#   fn remove_page_version(&self, id: i64, version: i32) -> Result<bool> { self.0.remove_page_version(id, version) }
+#   fn get_trash_contents(&self, key: &str, offset: i32, count: i32) -> Result<TrashContents> { self.0.get_trash_contents(key, offset, count) }
+#   fn purge_from_trash(&self, key: &str, id: i64) -> Result<bool> { self.0.purge_from_trash(key, id) }
+#   fn empty_trash(&self, key: &str) -> Result<bool> { self.0.empty_trash(key) }
+#   fn restore_page(&self, id: i64) -> Result<Page> { self.0.restore_page(id) }
+}
+
+fn publish<C: ConfluenceClient>(client: &C) -> Result<Page> {
+    client.store_page(UpdatePage::with_create_fields(None, "DOC", "Release notes", "<p>1.0</p>"))
+}
+
+let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+publish(&DryRun(session)).unwrap();
+```
+*/
+pub trait ConfluenceClient {
+    /// Returns a single Space, see `Session::get_space`.
+    fn get_space(&self, space_key: &str) -> Result<Space>;
+
+    /// Returns all spaces visible to the user, see `Session::get_spaces`.
+    fn get_spaces(&self) -> Result<Vec<SpaceSummary>>;
+
+    /// Creates a global space, see `Session::add_space`.
+    fn add_space(&self, space: UpdateSpace) -> Result<Space>;
+
+    /// Creates a personal space for the user, see `Session::add_personal_space`.
+    fn add_personal_space(&self, space: UpdateSpace, user_name: &str) -> Result<Space>;
+
+    /// Removes the space with all its pages, see `Session::remove_space`.
+    fn remove_space(&self, space_key: &str) -> Result<bool>;
+
+    /// Updates the name, description or home page of the space, see `Session::store_space`.
+    fn store_space(&self, space: UpdateSpace) -> Result<Space>;
+
+    /// Archives the space, see `Session::archive_space`.
+    fn archive_space(&self, space_key: &str) -> Result<bool>;
+
+    /// Restores the archived space, see `Session::unarchive_space`.
+    fn unarchive_space(&self, space_key: &str) -> Result<bool>;
+
+    /// Returns a single Page by space and title, see `Session::get_page_by_title`.
+    fn get_page_by_title(&self, space_key: &str, page_title: &str) -> Result<Page>;
+
+    /// Returns a single Page by id, see `Session::get_page_by_id`.
+    fn get_page_by_id(&self, page_id: i64) -> Result<Page>;
+
+    /// Adds or updates a page, see `Session::store_page`.
+    fn store_page(&self, page: UpdatePage) -> Result<Page>;
+
+    /// Updates the page with update options, see `Session::update_page`.
+    fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> Result<Page>;
+
+    /// Returns all the direct children of the page, see `Session::get_children`.
+    fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>>;
+
+    /// Returns all the descendants of the page, see `Session::get_descendants`.
+    fn get_descendants(&self, page_id: i64) -> Result<Vec<PageSummary>>;
+
+    /// Returns the summaries of all pages in the space, see `Session::get_pages`.
+    fn get_pages(&self, space_key: &str) -> Result<Vec<PageSummary>>;
+
+    /// Returns an iterator over the full pages of the space, see `Session::iter_pages`.
+    fn iter_pages(&self, space_key: &str) -> Result<Pages<'_>>
+    where
+        Self: Sized,
+    {
+        Ok(Pages::new(self, self.get_pages(space_key)?))
+    }
+
+    /// Moves the page next to, or under the target page, see `Session::move_page`.
+    fn move_page(
+        &self,
+        source_page_id: i64,
+        target_page_id: i64,
+        position: MovePosition,
+    ) -> Result<bool>;
+
+    /// Moves the page to the top level of the space, see `Session::move_page_to_top_level`.
+    fn move_page_to_top_level(&self, page_id: i64, space_key: &str) -> Result<bool>;
+
+    /// Moves the page with all its descendants into the space, see `Session::move_page_tree`.
+    fn move_page_tree(
+        &self,
+        page_id: i64,
+        space_key: &str,
+        parent_id: Option<i64>,
+    ) -> Result<usize> {
+        if let Some(parent_id) = parent_id {
+            let parent = self.get_page_by_id(parent_id)?;
+            if parent.space != space_key {
+                return Err(Error::InvalidArguments(ValidationError::InvalidArgument {
+                    operation: "movePage".into(),
+                    position: 2,
+                    reason: format!(
+                        "parent page {} is in space {}, not in {}",
+                        parent_id, parent.space, space_key
+                    ),
+                }));
+            }
+        }
+
+        let descendants = self.get_descendants(page_id)?.len();
+
+        match parent_id {
+            Some(parent_id) => self.move_page(page_id, parent_id, MovePosition::Append)?,
+            None => self.move_page_to_top_level(page_id, space_key)?,
+        };
+
+        Ok(1 + descendants)
+    }
+
+    /// Moves the page to the trash of its space, see `Session::remove_page`.
+    fn remove_page(&self, page_id: i64) -> Result<bool>;
+
+    /// Removes one historical version of the page, see `Session::remove_page_version`.
+    fn remove_page_version(&self, page_id: i64, version: i32) -> Result<bool>;
+
+    /// Returns a batch of the trash of the space, see `Session::get_trash_contents`.
+    fn get_trash_contents(&self, space_key: &str, offset: i32, count: i32)
+        -> Result<TrashContents>;
+
+    /// Permanently removes the content from the trash, see `Session::purge_from_trash`.
+    fn purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool>;
+
+    /// Permanently removes everything in the trash of the space, see `Session::empty_trash`.
+    fn empty_trash(&self, space_key: &str) -> Result<bool>;
+
+    /// Restores the page from the trash, see `Session::restore_page`.
+    fn restore_page(&self, page_id: i64) -> Result<Page>;
+}
+
+impl ConfluenceClient for Session {
+    fn get_space(&self, space_key: &str) -> Result<Space> {
+        Session::get_space(self, space_key)
+    }
+
+    fn get_spaces(&self) -> Result<Vec<SpaceSummary>> {
+        Session::get_spaces(self)
+    }
+
+    fn add_space(&self, space: UpdateSpace) -> Result<Space> {
+        Session::add_space(self, space)
+    }
+
+    fn add_personal_space(&self, space: UpdateSpace, user_name: &str) -> Result<Space> {
+        Session::add_personal_space(self, space, user_name)
+    }
+
+    fn remove_space(&self, space_key: &str) -> Result<bool> {
+        Session::remove_space(self, space_key)
+    }
+
+    fn store_space(&self, space: UpdateSpace) -> Result<Space> {
+        Session::store_space(self, space)
+    }
+
+    fn archive_space(&self, space_key: &str) -> Result<bool> {
+        Session::archive_space(self, space_key)
+    }
+
+    fn unarchive_space(&self, space_key: &str) -> Result<bool> {
+        Session::unarchive_space(self, space_key)
+    }
+
+    fn get_page_by_title(&self, space_key: &str, page_title: &str) -> Result<Page> {
+        Session::get_page_by_title(self, space_key, page_title)
+    }
+
+    fn get_page_by_id(&self, page_id: i64) -> Result<Page> {
+        Session::get_page_by_id(self, page_id)
+    }
+
+    fn store_page(&self, page: UpdatePage) -> Result<Page> {
+        Session::store_page(self, page)
+    }
+
+    fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> Result<Page> {
+        Session::update_page(self, page, options)
+    }
+
+    fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        Session::get_children(self, page_id)
+    }
+
+    fn get_descendants(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        Session::get_descendants(self, page_id)
+    }
+
+    fn get_pages(&self, space_key: &str) -> Result<Vec<PageSummary>> {
+        Session::get_pages(self, space_key)
+    }
+
+    fn move_page(
+        &self,
+        source_page_id: i64,
+        target_page_id: i64,
+        position: MovePosition,
+    ) -> Result<bool> {
+        Session::move_page(self, source_page_id, target_page_id, position)
+    }
+
+    fn move_page_to_top_level(&self, page_id: i64, space_key: &str) -> Result<bool> {
+        Session::move_page_to_top_level(self, page_id, space_key)
+    }
+
+    fn remove_page(&self, page_id: i64) -> Result<bool> {
+        Session::remove_page(self, page_id)
+    }
+
+    fn remove_page_version(&self, page_id: i64, version: i32) -> Result<bool> {
+        Session::remove_page_version(self, page_id, version)
+    }
+
+    fn get_trash_contents(
+        &self,
+        space_key: &str,
+        offset: i32,
+        count: i32,
+    ) -> Result<TrashContents> {
+        Session::get_trash_contents(self, space_key, offset, count)
+    }
+
+    fn purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool> {
+        Session::purge_from_trash(self, space_key, content_id)
+    }
+
+    fn empty_trash(&self, space_key: &str) -> Result<bool> {
+        Session::empty_trash(self, space_key)
+    }
+
+    fn restore_page(&self, page_id: i64) -> Result<Page> {
+        Session::restore_page(self, page_id)
+    }
+}
+
+macro_rules! forward_client {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a, T: ConfluenceClient + ?Sized> ConfluenceClient for $ty {
+                fn get_space(&self, space_key: &str) -> Result<Space> {
+                    (**self).get_space(space_key)
+                }
+
+                fn get_spaces(&self) -> Result<Vec<SpaceSummary>> {
+                    (**self).get_spaces()
+                }
+
+                fn add_space(&self, space: UpdateSpace) -> Result<Space> {
+                    (**self).add_space(space)
+                }
+
+                fn add_personal_space(&self, space: UpdateSpace, user_name: &str) -> Result<Space> {
+                    (**self).add_personal_space(space, user_name)
+                }
+
+                fn remove_space(&self, space_key: &str) -> Result<bool> {
+                    (**self).remove_space(space_key)
+                }
+
+                fn store_space(&self, space: UpdateSpace) -> Result<Space> {
+                    (**self).store_space(space)
+                }
+
+                fn archive_space(&self, space_key: &str) -> Result<bool> {
+                    (**self).archive_space(space_key)
+                }
+
+                fn unarchive_space(&self, space_key: &str) -> Result<bool> {
+                    (**self).unarchive_space(space_key)
+                }
+
+                fn get_page_by_title(&self, space_key: &str, page_title: &str) -> Result<Page> {
+                    (**self).get_page_by_title(space_key, page_title)
+                }
+
+                fn get_page_by_id(&self, page_id: i64) -> Result<Page> {
+                    (**self).get_page_by_id(page_id)
+                }
+
+                fn store_page(&self, page: UpdatePage) -> Result<Page> {
+                    (**self).store_page(page)
+                }
+
+                fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> Result<Page> {
+                    (**self).update_page(page, options)
+                }
+
+                fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+                    (**self).get_children(page_id)
+                }
+
+                fn get_descendants(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+                    (**self).get_descendants(page_id)
+                }
+
+                fn get_pages(&self, space_key: &str) -> Result<Vec<PageSummary>> {
+                    (**self).get_pages(space_key)
+                }
+
+                fn move_page(
+                    &self,
+                    source_page_id: i64,
+                    target_page_id: i64,
+                    position: MovePosition,
+                ) -> Result<bool> {
+                    (**self).move_page(source_page_id, target_page_id, position)
+                }
+
+                fn move_page_to_top_level(&self, page_id: i64, space_key: &str) -> Result<bool> {
+                    (**self).move_page_to_top_level(page_id, space_key)
+                }
+
+                fn move_page_tree(
+                    &self,
+                    page_id: i64,
+                    space_key: &str,
+                    parent_id: Option<i64>,
+                ) -> Result<usize> {
+                    (**self).move_page_tree(page_id, space_key, parent_id)
+                }
+
+                fn remove_page(&self, page_id: i64) -> Result<bool> {
+                    (**self).remove_page(page_id)
+                }
+
+                fn remove_page_version(&self, page_id: i64, version: i32) -> Result<bool> {
+                    (**self).remove_page_version(page_id, version)
+                }
+
+                fn get_trash_contents(
+                    &self,
+                    space_key: &str,
+                    offset: i32,
+                    count: i32,
+                ) -> Result<TrashContents> {
+                    (**self).get_trash_contents(space_key, offset, count)
+                }
+
+                fn purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool> {
+                    (**self).purge_from_trash(space_key, content_id)
+                }
+
+                fn empty_trash(&self, space_key: &str) -> Result<bool> {
+                    (**self).empty_trash(space_key)
+                }
+
+                fn restore_page(&self, page_id: i64) -> Result<Page> {
+                    (**self).restore_page(page_id)
+                }
+            }
+        )*
+    };
+}
+
+forward_client!(&'a T, Box<T>, Arc<T>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fixtures::CannedTransport;
+    use rpser::xml::BuildElement;
+    use rpser::RpcError;
+    use std::cell::Cell;
+    use xmltree::Element;
+    use {DropBehavior, Error, FaultKind};
+
+    /// Client which only knows a child of every page, and fails everything else.
+    struct Stub;
+
+    fn not_found() -> Error {
+        RpcError::Fault {
+            fault_code: "soapenv:Server.userException".into(),
+            fault_string: "com.atlassian.confluence.rpc.RemoteException: You're not allowed to view that page, or it does not exist.".into(),
+            fault_detail: Box::new(Element::node("detail")),
+            kind: FaultKind::PageNotFound,
+        }
+        .into()
+    }
+
+    impl ConfluenceClient for Stub {
+        fn get_space(&self, _space_key: &str) -> Result<Space> {
+            Err(not_found())
+        }
+
+        fn get_spaces(&self) -> Result<Vec<SpaceSummary>> {
+            Ok(vec![])
+        }
+
+        fn add_space(&self, _space: UpdateSpace) -> Result<Space> {
+            Err(not_found())
+        }
+
+        fn add_personal_space(&self, _space: UpdateSpace, _user_name: &str) -> Result<Space> {
+            Err(not_found())
+        }
+
+        fn remove_space(&self, _space_key: &str) -> Result<bool> {
+            Err(not_found())
+        }
+
+        fn store_space(&self, _space: UpdateSpace) -> Result<Space> {
+            Err(not_found())
+        }
+
+        fn archive_space(&self, _space_key: &str) -> Result<bool> {
+            Err(not_found())
+        }
+
+        fn unarchive_space(&self, _space_key: &str) -> Result<bool> {
+            Err(not_found())
+        }
+
+        fn get_page_by_title(&self, _space_key: &str, _page_title: &str) -> Result<Page> {
+            Err(not_found())
+        }
+
+        fn get_page_by_id(&self, _page_id: i64) -> Result<Page> {
+            Err(not_found())
+        }
+
+        fn store_page(&self, _page: UpdatePage) -> Result<Page> {
+            Err(not_found())
+        }
+
+        fn update_page(&self, _page: UpdatePage, _options: PageUpdateOptions) -> Result<Page> {
+            Err(not_found())
+        }
+
+        fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+            Ok(vec![PageSummary {
+                id: 2,
+                space: "DOC".into(),
+                parent_id: page_id,
+                title: "Child".into(),
+                url: "https://confluence/display/DOC/Child".into(),
+            }])
+        }
+
+        fn get_descendants(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+            self.get_children(page_id)
+        }
+
+        fn get_pages(&self, _space_key: &str) -> Result<Vec<PageSummary>> {
+            self.get_children(1)
+        }
+
+        fn move_page(
+            &self,
+            _source_page_id: i64,
+            _target_page_id: i64,
+            _position: MovePosition,
+        ) -> Result<bool> {
+            Err(not_found())
+        }
+
+        fn move_page_to_top_level(&self, _page_id: i64, _space_key: &str) -> Result<bool> {
+            Ok(true)
+        }
+
+        fn remove_page(&self, _page_id: i64) -> Result<bool> {
+            Err(not_found())
+        }
+
+        fn remove_page_version(&self, _page_id: i64, _version: i32) -> Result<bool> {
+            Err(not_found())
+        }
+
+        fn get_trash_contents(
+            &self,
+            _space_key: &str,
+            _offset: i32,
+            _count: i32,
+        ) -> Result<TrashContents> {
+            Err(not_found())
+        }
+
+        fn purge_from_trash(&self, _space_key: &str, _content_id: i64) -> Result<bool> {
+            Err(not_found())
+        }
+
+        fn empty_trash(&self, _space_key: &str) -> Result<bool> {
+            Err(not_found())
+        }
+
+        fn restore_page(&self, _page_id: i64) -> Result<Page> {
+            Err(not_found())
+        }
+    }
+
+    /// Decorator counting the `get_children` calls.
+    struct Counting<C> {
+        inner: C,
+        calls: Cell<usize>,
+    }
+
+    impl<C: ConfluenceClient> ConfluenceClient for Counting<C> {
+        fn get_space(&self, space_key: &str) -> Result<Space> {
+            self.inner.get_space(space_key)
+        }
+
+        fn get_spaces(&self) -> Result<Vec<SpaceSummary>> {
+            self.inner.get_spaces()
+        }
+
+        fn add_space(&self, space: UpdateSpace) -> Result<Space> {
+            self.inner.add_space(space)
+        }
+
+        fn add_personal_space(&self, space: UpdateSpace, user_name: &str) -> Result<Space> {
+            self.inner.add_personal_space(space, user_name)
+        }
+
+        fn remove_space(&self, space_key: &str) -> Result<bool> {
+            self.inner.remove_space(space_key)
+        }
+
+        fn store_space(&self, space: UpdateSpace) -> Result<Space> {
+            self.inner.store_space(space)
+        }
+
+        fn archive_space(&self, space_key: &str) -> Result<bool> {
+            self.inner.archive_space(space_key)
+        }
+
+        fn unarchive_space(&self, space_key: &str) -> Result<bool> {
+            self.inner.unarchive_space(space_key)
+        }
+
+        fn get_page_by_title(&self, space_key: &str, page_title: &str) -> Result<Page> {
+            self.inner.get_page_by_title(space_key, page_title)
+        }
+
+        fn get_page_by_id(&self, page_id: i64) -> Result<Page> {
+            self.inner.get_page_by_id(page_id)
+        }
+
+        fn store_page(&self, page: UpdatePage) -> Result<Page> {
+            self.inner.store_page(page)
+        }
+
+        fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> Result<Page> {
+            self.inner.update_page(page, options)
+        }
+
+        fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+            self.calls.set(self.calls.get() + 1);
+            self.inner.get_children(page_id)
+        }
+
+        fn get_descendants(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+            self.inner.get_descendants(page_id)
+        }
+
+        fn get_pages(&self, space_key: &str) -> Result<Vec<PageSummary>> {
+            self.inner.get_pages(space_key)
+        }
+
+        fn move_page(
+            &self,
+            source_page_id: i64,
+            target_page_id: i64,
+            position: MovePosition,
+        ) -> Result<bool> {
+            self.inner
+                .move_page(source_page_id, target_page_id, position)
+        }
+
+        fn move_page_to_top_level(&self, page_id: i64, space_key: &str) -> Result<bool> {
+            self.inner.move_page_to_top_level(page_id, space_key)
+        }
+
+        fn remove_page(&self, page_id: i64) -> Result<bool> {
+            self.inner.remove_page(page_id)
+        }
+
+        fn remove_page_version(&self, page_id: i64, version: i32) -> Result<bool> {
+            self.inner.remove_page_version(page_id, version)
+        }
+
+        fn get_trash_contents(
+            &self,
+            space_key: &str,
+            offset: i32,
+            count: i32,
+        ) -> Result<TrashContents> {
+            self.inner.get_trash_contents(space_key, offset, count)
+        }
+
+        fn purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool> {
+            self.inner.purge_from_trash(space_key, content_id)
+        }
+
+        fn empty_trash(&self, space_key: &str) -> Result<bool> {
+            self.inner.empty_trash(space_key)
+        }
+
+        fn restore_page(&self, page_id: i64) -> Result<Page> {
+            self.inner.restore_page(page_id)
+        }
+    }
+
+    fn child_titles(client: &dyn ConfluenceClient, page_id: i64) -> Vec<String> {
+        client
+            .get_children(page_id)
+            .unwrap()
+            .into_iter()
+            .map(|child| child.title)
+            .collect()
+    }
+
+    #[test]
+    fn decorates_clients_behind_pointers() {
+        let client = Counting {
+            inner: Box::new(Stub) as Box<dyn ConfluenceClient>,
+            calls: Cell::new(0),
+        };
+
+        assert_eq!(child_titles(&client, 1), vec!["Child"]);
+        assert_eq!(child_titles(&&client, 1), vec!["Child"]);
+        assert_eq!(client.calls.get(), 2);
+        assert_eq!(Arc::new(Stub).get_children(7).unwrap()[0].parent_id, 7);
+        assert_eq!(
+            client.get_page_by_id(7).unwrap_err().fault_kind(),
+            Some(FaultKind::PageNotFound)
+        );
+        assert_eq!(client.move_page_tree(1, "DOC", None).unwrap(), 2);
+        let pages: Vec<_> = client.iter_pages("DOC").unwrap().collect();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(
+            pages[0].as_ref().unwrap_err().fault_kind(),
+            Some(FaultKind::PageNotFound)
+        );
+    }
+
+    fn summary(id: i64, parent_id: i64) -> String {
+        format!(
+            r#"<item><id xsi:type="xsd:long">{0}</id><space xsi:type="xsd:string">DOC</space><parentId xsi:type="xsd:long">{1}</parentId><title xsi:type="xsd:string">Page {0}</title><url xsi:type="xsd:string">https://confluence/pages/{0}</url></item>"#,
+            id, parent_id
+        )
+    }
+
+    /// Transport of the page tree 1 > (2 > 4), 3 in the space DOC, which only has
+    /// the page 10 in the space ARCHIVE.
+    fn tree_transport() -> Arc<CannedTransport> {
+        let descendants = format!(
+            "<ns1:getDescendentsResponse><getDescendentsReturn>{}{}{}</getDescendentsReturn></ns1:getDescendentsResponse>",
+            summary(2, 1),
+            summary(4, 2),
+            summary(3, 1)
+        );
+
+        Arc::new(CannedTransport::new(&[
+            ("getDescendents", &descendants),
+            (
+                "getPage",
+                r#"<ns1:getPageResponse><getPageReturn>
+                    <id xsi:type="xsd:long">10</id>
+                    <space xsi:type="xsd:string">ARCHIVE</space>
+                    <parentId xsi:type="xsd:long">0</parentId>
+                    <title xsi:type="xsd:string">Archive Home</title>
+                    <url xsi:type="xsd:string">https://confluence/display/ARCHIVE</url>
+                    <version xsi:type="xsd:int">1</version>
+                    <content xsi:type="xsd:string"></content>
+                    <created xsi:type="xsd:dateTime">2019-04-17T13:45:02.000Z</created>
+                    <creator xsi:type="xsd:string">admin</creator>
+                    <modified xsi:type="xsd:dateTime">2019-04-17T13:45:02.000Z</modified>
+                    <modifier xsi:type="xsd:string">admin</modifier>
+                    <homePage xsi:type="xsd:boolean">true</homePage>
+                    <contentStatus xsi:type="xsd:string">current</contentStatus>
+                    <current xsi:type="xsd:boolean">true</current>
+                </getPageReturn></ns1:getPageResponse>"#,
+            ),
+            (
+                "movePage",
+                r#"<ns1:movePageResponse><movePageReturn xsi:type="xsd:boolean">true</movePageReturn></ns1:movePageResponse>"#,
+            ),
+            (
+                "movePageToTopLevel",
+                r#"<ns1:movePageToTopLevelResponse><movePageToTopLevelReturn xsi:type="xsd:boolean">true</movePageToTopLevelReturn></ns1:movePageToTopLevelResponse>"#,
+            ),
+        ]))
+    }
+
+    #[test]
+    fn moves_only_the_root_of_page_tree() {
+        let transport = tree_transport();
+        let session = Session::builder("https://confluence")
+            .transport(transport.clone())
+            .bundled_wsdl()
+            .on_drop(DropBehavior::KeepToken)
+            .login("user", "pass")
+            .unwrap();
+
+        assert_eq!(session.move_page_tree(1, "ARCHIVE", None).unwrap(), 4);
+        assert_eq!(session.move_page_tree(1, "ARCHIVE", Some(10)).unwrap(), 4);
+        match session.move_page_tree(1, "DOC", Some(10)) {
+            Err(Error::InvalidArguments(_)) => {}
+            other => panic!("expected invalid arguments, received {:?}", other),
+        }
+
+        assert_eq!(
+            transport.calls()[1..],
+            [
+                "getDescendents 1",
+                "movePageToTopLevel 1 ARCHIVE",
+                "getPage 10",
+                "getDescendents 1",
+                "movePage 1 10 append",
+                "getPage 10",
+            ]
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! SOAP responses and a transport double shared by the unit tests.
+
+use http::{self, HttpError, Method, Response, StatusCode, Transport};
+use reqwest::header::HeaderMap;
+use std::sync::Mutex;
+use xmltree::Element;
+
+/// Body of the `login` response, with the token `a3a8ecc6d5`.
+pub const LOGIN: &str = r#"<ns1:loginResponse><loginReturn xsi:type="xsd:string">a3a8ecc6d5</loginReturn></ns1:loginResponse>"#;
+
+/// Body of the `logout` response.
+pub const LOGOUT: &str = r#"<ns1:logoutResponse><logoutReturn xsi:type="xsd:boolean">true</logoutReturn></ns1:logoutResponse>"#;
+
+/// Body of the `getSpace` response, for the space `DOC` with home page 42.
+pub const GET_SPACE: &str = r#"<ns1:getSpaceResponse><getSpaceReturn>
+        <description xsi:type="xsd:string">Docs</description>
+        <homePage xsi:type="xsd:long">42</homePage>
+        <key xsi:type="xsd:string">DOC</key>
+        <name xsi:type="xsd:string">Documentation</name>
+        <type xsi:type="xsd:string">global</type>
+        <url xsi:type="xsd:string">https://confluence/display/DOC</url>
+    </getSpaceReturn></ns1:getSpaceResponse>"#;
+
+/// Fault of a call with an expired token.
+pub const INVALID_SESSION_FAULT: &str = r#"<soapenv:Fault>
+        <faultcode>soapenv:Server.userException</faultcode>
+        <faultstring>com.atlassian.confluence.rpc.InvalidSessionException: User not authenticated or session expired. Call login() to open a new session</faultstring>
+        <detail>
+            <com.atlassian.confluence.rpc.InvalidSessionException xsi:type="ns1:InvalidSessionException"/>
+        </detail>
+    </soapenv:Fault>"#;
+
+/// Wrap the body in a SOAP envelope, with the namespaces declared by Axis.
+pub fn envelope(body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+        <soapenv:Envelope xmlns:soapenv="http://schemas.xmlsoap.org/soap/envelope/" xmlns:xsd="http://www.w3.org/2001/XMLSchema" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:ns1="https://confluence/rpc/soap-axis/confluenceservice-v2">
+            <soapenv:Body>{}</soapenv:Body>
+        </soapenv:Envelope>"#,
+        body
+    )
+}
+
+/// Response with the status and the body wrapped in a SOAP envelope.
+pub fn soap_response(status: StatusCode, body: &str) -> Response {
+    Response {
+        status,
+        headers: HeaderMap::new(),
+        body: envelope(body),
+    }
+}
+
+/// Response with the status and the body as it is.
+fn response(status: StatusCode, body: &str) -> Response {
+    Response {
+        status,
+        headers: HeaderMap::new(),
+        body: body.into(),
+    }
+}
+
+/// Path of the url, without the scheme, host and query.
+fn path(url: &str) -> &str {
+    let url = url.split('?').next().unwrap_or(url);
+    let start = url
+        .find("://")
+        .and_then(|scheme| url[scheme + 3..].find('/').map(|host| scheme + 3 + host))
+        .unwrap_or(url.len());
+
+    &url[start..]
+}
+
+/**
+Transport which answers requests with fixed bodies, and records the requests it was sent.
+
+SOAP actions are answered with the bodies given to `new`, in addition to `login` and
+`logout`. XML-RPC calls to `/rpc/xmlrpc`, REST API requests and plain `GET` requests are
+answered when their responses are added with `with_xml_rpc`, `with_json` and `with_get`.
+Other `GET` requests are answered with a WSDL which has the SOAP actions. Other requests
+fail with `HttpError::Unsupported`.
+*/
+pub struct CannedTransport {
+    soap: Vec<(&'static str, String)>,
+    xml_rpc: Vec<(&'static str, String)>,
+    json: Vec<(&'static str, StatusCode, String)>,
+    gets: Vec<(&'static str, String)>,
+    calls: Mutex<Vec<(String, Vec<String>)>>,
+    wsdl_requests: Mutex<usize>,
+    expire_token: Mutex<bool>,
+}
+
+impl CannedTransport {
+    /// Answer the SOAP actions with the bodies, in addition to `login` and `logout`.
+    pub fn new(bodies: &[(&'static str, &str)]) -> CannedTransport {
+        let mut soap = vec![("login", LOGIN.to_string()), ("logout", LOGOUT.to_string())];
+        soap.extend(
+            bodies
+                .iter()
+                .map(|&(action, body)| (action, body.to_string())),
+        );
+
+        CannedTransport {
+            soap,
+            xml_rpc: vec![],
+            json: vec![],
+            gets: vec![],
+            calls: Mutex::new(vec![]),
+            wsdl_requests: Mutex::new(0),
+            expire_token: Mutex::new(false),
+        }
+    }
+
+    /// Answer the XML-RPC methods, such as `confluence2.getPage`, with the `<value>`s.
+    pub fn with_xml_rpc(mut self, values: &[(&'static str, &str)]) -> CannedTransport {
+        self.xml_rpc.extend(
+            values
+                .iter()
+                .map(|&(name, value)| (name, value.to_string())),
+        );
+        self
+    }
+
+    /// Answer the REST API request, such as `GET /rest/api/content/42`, with the JSON.
+    pub fn with_json(
+        mut self,
+        request: &'static str,
+        status: StatusCode,
+        json: &str,
+    ) -> CannedTransport {
+        self.json.push((request, status, json.into()));
+        self
+    }
+
+    /// Answer `GET` requests of the path with the body.
+    pub fn with_get(mut self, path: &'static str, body: &str) -> CannedTransport {
+        self.gets.push((path, body.into()));
+        self
+    }
+
+    /// Actions, XML-RPC methods and REST API requests sent so far.
+    pub fn actions(&self) -> Vec<String> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(action, _)| action.clone())
+            .collect()
+    }
+
+    /// SOAP actions sent so far with the text of their arguments, other than the token.
+    pub fn calls(&self) -> Vec<String> {
+        self.calls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(action, args)| {
+                let mut call = vec![action.clone()];
+                call.extend(args.iter().cloned());
+                call.join(" ")
+            })
+            .collect()
+    }
+
+    /// Number of `GET` requests, such as the ones for the WSDL.
+    pub fn wsdl_requests(&self) -> usize {
+        *self.wsdl_requests.lock().unwrap()
+    }
+
+    /// Fail the next action other than `login` with `InvalidSessionException`.
+    pub fn expire_token(&self) {
+        *self.expire_token.lock().unwrap() = true;
+    }
+
+    fn record(&self, action: &str, args: Vec<String>) {
+        self.calls.lock().unwrap().push((action.into(), args));
+    }
+}
+
+impl Transport for CannedTransport {
+    fn get(&self, url: &str) -> http::Result<Response> {
+        *self.wsdl_requests.lock().unwrap() += 1;
+
+        if let Some((_, body)) = self.gets.iter().find(|&&(known, _)| known == path(url)) {
+            return Ok(response(StatusCode::OK, body));
+        }
+
+        let operations: String = self
+            .soap
+            .iter()
+            .map(|&(action, _)| format!(r#"<wsdl:operation name="{}"/>"#, action))
+            .collect();
+        Ok(response(
+            StatusCode::OK,
+            &format!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <wsdl:definitions targetNamespace="https://confluence/rpc/soap-axis/confluenceservice-v2" xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/">
+                    <wsdl:portType name="ConfluenceSoapService">{}</wsdl:portType>
+                </wsdl:definitions>"#,
+                operations
+            ),
+        ))
+    }
+
+    fn soap_action(&self, _url: &str, action: &str, xml: &str) -> http::Result<Response> {
+        let envelope = Element::parse(xml.as_bytes()).unwrap();
+        let args = envelope
+            .get_child("Body")
+            .and_then(|body| body.children.first())
+            .map(|method| {
+                method
+                    .children
+                    .iter()
+                    .filter(|arg| arg.name != "token")
+                    .map(|arg| arg.text.clone().unwrap_or_default())
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.record(action, args);
+
+        let mut expire_token = self.expire_token.lock().unwrap();
+        if *expire_token && action != "login" {
+            *expire_token = false;
+            return Ok(soap_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                INVALID_SESSION_FAULT,
+            ));
+        }
+
+        match self.soap.iter().find(|&&(known, _)| known == action) {
+            Some((_, body)) => Ok(soap_response(StatusCode::OK, body)),
+            None => Err(HttpError::Unsupported {
+                request: action.into(),
+            }),
+        }
+    }
+
+    fn post_xml(&self, url: &str, xml: &str) -> http::Result<Response> {
+        if path(url) != "/rpc/xmlrpc" {
+            return Err(HttpError::Unsupported {
+                request: format!("POST {}", url),
+            });
+        }
+
+        let call = Element::parse(xml.as_bytes()).unwrap();
+        let name = call
+            .get_child("methodName")
+            .and_then(|name| name.text.clone())
+            .unwrap_or_default();
+        self.record(&name, vec![]);
+
+        match self.xml_rpc.iter().find(|&&(known, _)| known == name) {
+            Some((_, value)) => Ok(response(
+                StatusCode::OK,
+                &format!(
+                    "<methodResponse><params><param>{}</param></params></methodResponse>",
+                    value
+                ),
+            )),
+            None => Err(HttpError::Unsupported { request: name }),
+        }
+    }
+
+    fn send_json(&self, method: Method, url: &str, _json: Option<&str>) -> http::Result<Response> {
+        let request = format!("{} {}", method, path(url));
+        self.record(&request, vec![]);
+
+        match self.json.iter().find(|&&(known, _, _)| known == request) {
+            Some(&(_, status, ref json)) => Ok(response(status, json)),
+            None => Err(HttpError::Unsupported { request }),
+        }
+    }
+}
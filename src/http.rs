@@ -1,48 +1,795 @@
 //! HTTP helpers.
 
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
-pub use reqwest::Error as HttpError;
-pub use reqwest::StatusCode;
-use std::io::Read;
+use chrono::{DateTime, Utc};
+use futures::{future, Future};
+use reqwest::async::Client as AsyncClient;
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE, RETRY_AFTER, USER_AGENT,
+};
+pub use reqwest::{Certificate, Proxy};
+pub use reqwest::{Method, StatusCode};
+use std::error::Error as StdError;
+use std::fmt;
+use std::io::{self, Read};
 use std::result;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// HTTP request error.
+#[derive(Debug)]
+pub enum HttpError {
+    /// The request could not be sent, or no response was received.
+    Request(reqwest::Error),
+    /// The response was received, but reading or parsing its body failed.
+    Body {
+        status: StatusCode,
+        error: io::Error,
+    },
+    /// The server responded with an unexpected status.
+    Status {
+        status: StatusCode,
+        body_excerpt: String,
+    },
+    /// The transport can not send this kind of request.
+    Unsupported { request: String },
+    /// A header value, such as the `User-Agent` given to the builder or the `SOAPAction`
+    /// of a method name, contains characters not allowed in a header.
+    InvalidHeader { name: String, value: String },
+    /// Error of a custom transport, such as `cassette::NotRecorded`.
+    ///
+    /// Never retried, as the retry policy can not tell what failed.
+    Transport(Box<dyn StdError + Send + Sync>),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HttpError::Request(ref e) => write!(f, "{}", e),
+            HttpError::Body { status, ref error } => {
+                write!(f, "failed to read {} response body: {}", status, error)
+            }
+            HttpError::Status {
+                status,
+                ref body_excerpt,
+            } => write!(f, "unexpected response status {}: {}", status, body_excerpt),
+            HttpError::Unsupported { ref request } => {
+                write!(f, "transport does not support {}", request)
+            }
+            HttpError::InvalidHeader {
+                ref name,
+                ref value,
+            } => write!(f, "invalid {} header value {:?}", name, value),
+            HttpError::Transport(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<reqwest::Error> for HttpError {
+    fn from(other: reqwest::Error) -> HttpError {
+        HttpError::Request(other)
+    }
+}
+
+/// Credentials sent with every request in the `Authorization` header.
+///
+/// Used instead of (or in addition to) the SOAP `login`, for example with
+/// personal access tokens on Confluence Data Center.
+#[derive(Clone)]
+pub enum Auth {
+    /// `Authorization: Bearer <token>`, such as a personal access token.
+    Bearer(String),
+    /// `Authorization: Basic <user:pass>`.
+    Basic { user: String, pass: String },
+}
+
+impl Auth {
+    fn apply(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match *self {
+            Auth::Bearer(ref token) => request.bearer_auth(token),
+            Auth::Basic { ref user, ref pass } => request.basic_auth(user, Some(pass)),
+        }
+    }
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Auth::Bearer(_) => write!(f, "Bearer(******)"),
+            Auth::Basic { ref user, .. } => write!(f, "Basic {{ user: {:?}, pass: ****** }}", user),
+        }
+    }
+}
 
 /// Simplified HTTP response representation.
 #[derive(Debug)]
 pub struct Response {
     pub status: StatusCode,
+    pub headers: HeaderMap,
     pub body: String,
 }
 
+impl Response {
+    /// How long the server asked to wait with the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        let value = self.headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+        if let Ok(seconds) = value.parse() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        (date.with_timezone(&Utc) - Utc::now()).to_std().ok()
+    }
+
+    /// Beginning of the body, short enough to be included in errors and logs.
+    pub fn body_excerpt(&self) -> String {
+        excerpt(&self.body)
+    }
+}
+
+/// HTTP transport used by `Session` to reach the server.
+///
+/// The default implementation is `ReqwestTransport`. Implement this trait to
+/// route requests through a custom client, add a recording layer or replace
+/// the server with a test double.
+pub trait Transport: Send + Sync {
+    /// Perform a GET request to specified URL.
+    fn get(&self, url: &str) -> Result<Response>;
+
+    /// Post the SOAP `xml` envelope to specified URL with the `SOAPAction` header.
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<Response>;
+
+    /// Post the XML-RPC `xml` method call to specified URL.
+    ///
+    /// The default implementation posts it with an empty `SOAPAction` header,
+    /// which XML-RPC servers ignore.
+    fn post_xml(&self, url: &str, xml: &str) -> Result<Response> {
+        self.soap_action(url, "", xml)
+    }
+
+    /// Send a REST API request with an optional JSON body.
+    ///
+    /// The default implementation can only send `GET` requests, with `get`.
+    fn send_json(&self, method: Method, url: &str, json: Option<&str>) -> Result<Response> {
+        match (method, json) {
+            (Method::GET, None) => self.get(url),
+            (method, _) => Err(HttpError::Unsupported {
+                request: format!("{} {}", method, url),
+            }),
+        }
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn get(&self, url: &str) -> Result<Response> {
+        (**self).get(url)
+    }
+
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<Response> {
+        (**self).soap_action(url, action, xml)
+    }
+
+    fn post_xml(&self, url: &str, xml: &str) -> Result<Response> {
+        (**self).post_xml(url, xml)
+    }
+
+    fn send_json(&self, method: Method, url: &str, json: Option<&str>) -> Result<Response> {
+        (**self).send_json(method, url, json)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn get(&self, url: &str) -> Result<Response> {
+        (**self).get(url)
+    }
+
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<Response> {
+        (**self).soap_action(url, action, xml)
+    }
+
+    fn post_xml(&self, url: &str, xml: &str) -> Result<Response> {
+        (**self).post_xml(url, xml)
+    }
+
+    fn send_json(&self, method: Method, url: &str, json: Option<&str>) -> Result<Response> {
+        (**self).send_json(method, url, json)
+    }
+}
+
+/**
+Default transport, performs requests with a long-lived `reqwest` client.
+
+All requests made through the same transport share the client's connection
+pool, so keep-alive connections are reused between calls.
+
+## Example
+
+```no_run
+use confluence::http::ReqwestTransport;
+use std::time::Duration;
+
+let transport = ReqwestTransport::builder()
+    .max_idle_per_host(8)
+    .pool_idle_timeout(Duration::from_secs(30))
+    .build()
+    .unwrap();
+
+let session = confluence::Session::login_with_transport(
+    transport,
+    "https://confluence",
+    "user",
+    "pass"
+).unwrap();
+```
+*/
+#[derive(Debug)]
+pub struct ReqwestTransport {
+    config: ClientConfig,
+    pool: Mutex<PooledClient>,
+}
+
+#[derive(Debug)]
+struct PooledClient {
+    client: reqwest::Client,
+    last_used: Instant,
+}
+
+impl ReqwestTransport {
+    /// Create transport with default client settings.
+    pub fn new() -> ReqwestTransport {
+        ReqwestTransport::with_client(ClientConfig::default(), reqwest::Client::new())
+    }
+
+    /// Create a builder to configure the underlying client.
+    pub fn builder() -> ReqwestTransportBuilder {
+        ReqwestTransportBuilder::default()
+    }
+
+    fn with_client(config: ClientConfig, client: reqwest::Client) -> ReqwestTransport {
+        ReqwestTransport {
+            config,
+            pool: Mutex::new(PooledClient {
+                client,
+                last_used: Instant::now(),
+            }),
+        }
+    }
+
+    /// Get the shared client, dropping the pooled connections if they were idle for too long.
+    fn client(&self) -> Result<reqwest::Client> {
+        let mut pool = self.pool.lock().unwrap_or_else(|e| e.into_inner());
+        pool.refresh(&self.config, Instant::now())?;
+
+        Ok(pool.client.clone())
+    }
+}
+
+impl PooledClient {
+    /// Mark the client used at `now`, rebuilding it first if it was idle for longer than
+    /// the configured timeout. Returns whether the client was rebuilt.
+    fn refresh(&mut self, config: &ClientConfig, now: Instant) -> Result<bool> {
+        let idle_for = now.saturating_duration_since(self.last_used);
+        let rebuild = match config.pool_idle_timeout {
+            Some(idle_timeout) => idle_for > idle_timeout,
+            None => false,
+        };
+
+        if rebuild {
+            debug!("connection pool was idle for {:?}, reconnecting", idle_for);
+            self.client = config.build()?;
+        }
+        self.last_used = now;
+
+        Ok(rebuild)
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> ReqwestTransport {
+        ReqwestTransport::new()
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn get(&self, url: &str) -> Result<Response> {
+        let mut request = self.client()?.get(url);
+        if let Some(ref auth) = self.config.auth {
+            request = auth.apply(request);
+        }
+
+        read_response(request.send()?)
+    }
+
+    fn soap_action(&self, url: &str, action: &str, xml: &str) -> Result<Response> {
+        send_soap_action(&self.client()?, url, action, xml, self.config.auth.as_ref())
+    }
+
+    fn post_xml(&self, url: &str, xml: &str) -> Result<Response> {
+        let mut request = self
+            .client()?
+            .post(url)
+            .header(CONTENT_TYPE, "text/xml; charset=utf-8")
+            .body(xml.to_string());
+        if let Some(ref auth) = self.config.auth {
+            request = auth.apply(request);
+        }
+
+        read_response(request.send()?)
+    }
+
+    fn send_json(&self, method: Method, url: &str, json: Option<&str>) -> Result<Response> {
+        let mut request = self
+            .client()?
+            .request(method, url)
+            .header(ACCEPT, "application/json");
+        if let Some(json) = json {
+            request = request
+                .header(CONTENT_TYPE, "application/json")
+                .body(json.to_string());
+        }
+        if let Some(ref auth) = self.config.auth {
+            request = auth.apply(request);
+        }
+
+        read_response(request.send()?)
+    }
+}
+
+/// Apply the `ClientConfig` to a blocking or non-blocking `reqwest` client builder.
+///
+/// Both builders have the same methods, but no common trait.
+macro_rules! configure {
+    ($config:expr, $builder:expr) => {{
+        let config = &$config;
+        let mut builder = $builder;
+
+        if let Some(max) = config.max_idle_per_host {
+            builder = builder.max_idle_per_host(max);
+        }
+
+        if let Some(timeout) = config.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        for proxy in &config.proxies {
+            builder = builder.proxy(proxy.clone());
+        }
+
+        for certificate in &config.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+
+        if let Some(ref identity) = config.identity {
+            builder = builder.identity(reqwest::Identity::from_pkcs12_der(
+                &identity.der,
+                &identity.password,
+            )?);
+        }
+
+        if let Some(ref user_agent) = config.user_agent {
+            let mut headers = HeaderMap::new();
+            headers.insert(USER_AGENT, user_agent.clone());
+            builder = builder.default_headers(headers);
+        }
+
+        builder
+    }};
+}
+
+/// Builder for `ReqwestTransport`.
+#[derive(Debug, Default)]
+pub struct ReqwestTransportBuilder {
+    config: ClientConfig,
+    /// First invalid setting, reported by `build`.
+    error: Option<HttpError>,
+}
+
+impl ReqwestTransportBuilder {
+    /// Maximum number of idle connections kept open per host.
+    pub fn max_idle_per_host(mut self, max: usize) -> Self {
+        self.config.max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Close all pooled connections once the transport was not used for this long.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for the whole request, from connecting until the response body is read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for the connect phase only.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.config.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Route requests through a proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.config.proxies.push(proxy);
+        self
+    }
+
+    /// Trust an additional root certificate, such as an internal CA.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.config.root_certificates.push(certificate);
+        self
+    }
+
+    /// Present a client certificate, given as a DER-encoded PKCS#12 archive.
+    pub fn pkcs12_identity<S: Into<String>>(mut self, der: Vec<u8>, password: S) -> Self {
+        self.config.identity = Some(Pkcs12Identity {
+            der,
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Value of the `User-Agent` header sent with every request.
+    ///
+    /// A value with characters not allowed in a header, such as a newline, makes
+    /// `build` fail with `HttpError::InvalidHeader`.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        match HeaderValue::from_str(user_agent) {
+            Ok(value) => self.config.user_agent = Some(value),
+            Err(_) => {
+                self.error.get_or_insert(HttpError::InvalidHeader {
+                    name: USER_AGENT.to_string(),
+                    value: user_agent.into(),
+                });
+            }
+        }
+        self
+    }
+
+    /// Send the credentials in the `Authorization` header of every request.
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.config.auth = Some(auth);
+        self
+    }
+
+    /// Whether the credentials are sent in the `Authorization` header.
+    pub(crate) fn has_auth(&self) -> bool {
+        self.config.auth.is_some()
+    }
+
+    /// Build the transport.
+    pub fn build(self) -> Result<ReqwestTransport> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        let client = self.config.build()?;
+
+        Ok(ReqwestTransport::with_client(self.config, client))
+    }
+
+    /// Build a non-blocking client with the same settings, for `AsyncSession`.
+    ///
+    /// The `auth` and `pool_idle_timeout` settings are not used by the non-blocking client.
+    pub fn build_async(self) -> Result<AsyncClient> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        Ok(configure!(self.config, AsyncClient::builder()).build()?)
+    }
+}
+
+/// Settings used to (re)build the `reqwest` client.
+#[derive(Debug, Default)]
+struct ClientConfig {
+    max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    identity: Option<Pkcs12Identity>,
+    user_agent: Option<HeaderValue>,
+    auth: Option<Auth>,
+}
+
+impl ClientConfig {
+    fn build(&self) -> Result<reqwest::Client> {
+        Ok(configure!(self, reqwest::Client::builder()).build()?)
+    }
+}
+
+/// Client certificate, kept in encoded form so the client can be rebuilt.
+struct Pkcs12Identity {
+    der: Vec<u8>,
+    password: String,
+}
+
+impl fmt::Debug for Pkcs12Identity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Pkcs12Identity {{ {} bytes }}", self.der.len())
+    }
+}
+
+/// Boxed future returned by the non-blocking helpers.
+pub type ResponseFuture = Box<dyn Future<Item = Response, Error = HttpError> + Send>;
+
 /// Perform a GET request to specified URL.
+///
+/// Creates a new client for every call, use `ReqwestTransport` to reuse connections.
 pub fn get(url: &str) -> Result<Response> {
-    let mut res = reqwest::get(url)?;
-    let mut body = String::new();
-    res.read_to_string(&mut body).unwrap();
-    let status = res.status();
+    let response = reqwest::get(url)?;
 
-    Ok(Response { status, body })
+    read_response(response)
 }
 
 /// Perform a SOAP action to specified URL.
+///
+/// Creates a new client for every call, use `ReqwestTransport` to reuse connections.
 pub fn soap_action(url: &str, action: &str, xml: &str) -> Result<Response> {
-    let soap_action = HeaderName::from_bytes(b"SOAPAction").unwrap();
-    let soap_value = HeaderValue::from_str(action).unwrap();
-    let mut hmap = HeaderMap::new();
-    hmap.insert(CONTENT_TYPE, "text/xml; charset=utf-8".parse().unwrap());
-    hmap.insert(soap_action, soap_value);
+    send_soap_action(&reqwest::Client::new(), url, action, xml, None)
+}
+
+/// Perform a SOAP action to specified URL, authenticated with the `Authorization` header.
+///
+/// Creates a new client for every call, use `ReqwestTransport` to reuse connections.
+pub fn soap_action_with_auth(url: &str, action: &str, xml: &str, auth: &Auth) -> Result<Response> {
+    send_soap_action(&reqwest::Client::new(), url, action, xml, Some(auth))
+}
+
+/// Perform a non-blocking GET request to specified URL using the given client.
+pub fn get_async(client: &AsyncClient, url: &str) -> ResponseFuture {
+    Box::new(
+        client
+            .get(url)
+            .send()
+            .map_err(HttpError::from)
+            .and_then(read_async_response),
+    )
+}
 
-    let client = reqwest::Client::new();
-    let mut response = client
+/// Perform a non-blocking SOAP action to specified URL using the given client.
+pub fn soap_action_async(
+    client: &AsyncClient,
+    url: &str,
+    action: &str,
+    xml: &str,
+) -> ResponseFuture {
+    let headers = match soap_headers(action) {
+        Ok(headers) => headers,
+        Err(e) => return Box::new(future::err(e)),
+    };
+
+    Box::new(
+        client
+            .post(url)
+            .headers(headers)
+            .body(xml.to_string())
+            .send()
+            .map_err(HttpError::from)
+            .and_then(read_async_response),
+    )
+}
+
+fn send_soap_action(
+    client: &reqwest::Client,
+    url: &str,
+    action: &str,
+    xml: &str,
+    auth: Option<&Auth>,
+) -> Result<Response> {
+    let mut request = client
         .post(url)
-        .headers(hmap)
-        .body(xml.to_string())
-        .send()?;
+        .headers(soap_headers(action)?)
+        .body(xml.to_string());
+    if let Some(auth) = auth {
+        request = auth.apply(request);
+    }
+
+    read_response(request.send()?)
+}
+
+fn read_response(mut response: reqwest::Response) -> Result<Response> {
+    let status = response.status();
+    let headers = response.headers().clone();
+
+    let mut bytes = vec![];
+    if let Err(error) = response.read_to_end(&mut bytes) {
+        return Err(HttpError::Body { status, error });
+    }
+    let body = String::from_utf8_lossy(&bytes).into_owned();
+
+    Ok(Response {
+        status,
+        headers,
+        body,
+    })
+}
 
-    let mut body = String::new();
-    response.read_to_string(&mut body).unwrap();
+fn read_async_response(
+    mut response: reqwest::async::Response,
+) -> impl Future<Item = Response, Error = HttpError> {
     let status = response.status();
+    let headers = response.headers().clone();
+    response
+        .text()
+        .map(move |body| Response {
+            status,
+            headers,
+            body,
+        })
+        .map_err(HttpError::from)
+}
 
-    Ok(Response { status, body })
+fn soap_headers(action: &str) -> Result<HeaderMap> {
+    let soap_action = HeaderName::from_static("soapaction");
+    let soap_value = HeaderValue::from_str(action).map_err(|_| HttpError::InvalidHeader {
+        name: soap_action.to_string(),
+        value: action.into(),
+    })?;
+    let mut hmap = HeaderMap::new();
+    hmap.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("text/xml; charset=utf-8"),
+    );
+    hmap.insert(soap_action, soap_value);
+    Ok(hmap)
+}
+
+/// Cut the text to a length suitable for error messages.
+pub fn excerpt(text: &str) -> String {
+    const MAX_CHARS: usize = 256;
+
+    match text.char_indices().nth(MAX_CHARS) {
+        Some((end, _)) => format!("{}...", &text[..end]),
+        None => text.to_string(),
+    }
 }
 
 pub type Result<T> = result::Result<T, HttpError>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Accept one request and return its head, answering with `ok`.
+    fn serve_once() -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/rpc", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..read]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+                .unwrap();
+            String::from_utf8_lossy(&request).to_lowercase()
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn rejects_invalid_user_agent() {
+        match ReqwestTransport::builder()
+            .user_agent("sync\r\nX-Injected: 1")
+            .build()
+        {
+            Err(HttpError::InvalidHeader { name, .. }) => assert_eq!(name, "user-agent"),
+            other => panic!("expected invalid header, received {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_soap_action() {
+        let transport = ReqwestTransport::new();
+
+        match transport.soap_action("http://127.0.0.1:9/", "getPage\r\nX-Injected: 1", "") {
+            Err(HttpError::InvalidHeader { name, value }) => {
+                assert_eq!(name, "soapaction");
+                assert_eq!(value, "getPage\r\nX-Injected: 1");
+            }
+            other => panic!("expected invalid header, received {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn sends_authorization_header() {
+        let transport = ReqwestTransport::builder()
+            .auth(Auth::Bearer("pat-123".into()))
+            .build()
+            .unwrap();
+
+        let (url, request) = serve_once();
+        let response = transport.soap_action(&url, "getSpace", "<x/>").unwrap();
+
+        assert_eq!(response.body, "ok");
+        assert!(request
+            .join()
+            .unwrap()
+            .contains("authorization: bearer pat-123"));
+    }
+
+    #[test]
+    fn hides_credentials_in_debug_output() {
+        let auth = Auth::Basic {
+            user: "bot".into(),
+            pass: "secret".into(),
+        };
+
+        assert_eq!(
+            format!("{:?}", auth),
+            "Basic { user: \"bot\", pass: ****** }"
+        );
+        assert!(!format!("{:?}", Auth::Bearer("secret".into())).contains("secret"));
+    }
+
+    fn pooled(config: &ClientConfig, last_used: Instant) -> PooledClient {
+        PooledClient {
+            client: config.build().unwrap(),
+            last_used,
+        }
+    }
+
+    #[test]
+    fn reuses_client_within_idle_timeout() {
+        let config = ClientConfig {
+            pool_idle_timeout: Some(Duration::from_secs(30)),
+            ..ClientConfig::default()
+        };
+        let start = Instant::now();
+        let mut pool = pooled(&config, start);
+
+        // Every call restarts the idle period, so the total may exceed the timeout.
+        for step in 1..4 {
+            let now = start + Duration::from_secs(20 * step);
+            assert!(!pool.refresh(&config, now).unwrap());
+            assert_eq!(pool.last_used, now);
+        }
+        assert!(!pool
+            .refresh(&config, pool.last_used + Duration::from_secs(30))
+            .unwrap());
+    }
+
+    #[test]
+    fn rebuilds_client_after_idle_timeout() {
+        let config = ClientConfig {
+            pool_idle_timeout: Some(Duration::from_secs(30)),
+            ..ClientConfig::default()
+        };
+        let start = Instant::now();
+        let mut pool = pooled(&config, start);
+
+        let later = start + Duration::from_secs(31);
+        assert!(pool.refresh(&config, later).unwrap());
+        assert_eq!(pool.last_used, later);
+        assert!(!pool
+            .refresh(&config, later + Duration::from_secs(1))
+            .unwrap());
+    }
+
+    #[test]
+    fn keeps_client_without_idle_timeout() {
+        let config = ClientConfig::default();
+        let start = Instant::now();
+        let mut pool = pooled(&config, start);
+
+        assert!(!pool
+            .refresh(&config, start + Duration::from_secs(86_400))
+            .unwrap());
+    }
+}
@@ -6,50 +6,182 @@ Access and modify [Atlassian Confluence](https://www.atlassian.com/software/conf
 To start, create a new `Session` by calling a `login` on it
 with your credentials.
 
+When password login is disabled, authenticate at the HTTP layer instead,
+with `SessionBuilder::personal_access_token` (or `auth`), and create the
+session with `SessionBuilder::connect`.
+
 Internally, the `Session` struct stores the auth `token`
 and uses it when calling remote methods.
 
-The token will be destroyed (automatic logout) when `Session` goes out of scope.
+The token will be destroyed (automatic logout) when `Session` goes out of scope,
+unless a different `DropBehavior` is configured.
+
+## Choosing the API
+
+Sessions use the SOAP API by default. Confluence exposes the same methods over
+XML-RPC at `/rpc/xmlrpc`, which can be used instead with
+`SessionBuilder::backend(Backend::XmlRpc)`, for example when the SOAP plugin
+is disabled.
+
+Confluence 7 and Cloud no longer have either endpoint. With `Backend::Rest`, the
+page and space getters, `store_page`, `update_page`, `get_children` and
+`restore_page` use the REST API at `/rest/api` instead, and the other operations
+fail with `Error::Unsupported`.
+
+## Reusing a session
+
+Short-lived programs can keep the token between runs: save `Session::state`
+to disk, and resume it later with `SessionBuilder::resume_or_login`, which
+logs in again only when the saved token has expired.
+
+## Testing
+
+Code written against the `ConfluenceClient` trait can be tested without a
+server: the `testing` feature adds `testing::FakeConfluence`, an in-memory
+store of spaces and pages. Code using `Session` itself can log in to
+`testing::StubServer`, which serves that store over SOAP on `localhost`.
+
+Traffic with a real server can also be recorded once with
+`cassette::RecordingTransport` and replayed in CI with `cassette::ReplayTransport`.
+
+## Non-blocking usage
+
+`AsyncSession` exposes the same operations as futures, built on the
+non-blocking `reqwest` client. It is cheap to clone, so many requests
+can be issued concurrently from the same session. Create it with
+`SessionBuilder::login_async` to apply the retry, rate limit and re-login settings.
 */
 
 #[macro_use]
 extern crate log;
 extern crate chrono;
+extern crate futures;
 extern crate reqwest;
+#[macro_use]
+extern crate serde_json;
+#[cfg(test)]
+extern crate tokio;
+extern crate tokio_timer;
 extern crate xml;
 extern crate xmltree;
 
+pub mod cassette;
 pub mod http;
+pub mod rest;
 pub mod rpser;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod wsdl;
-
+pub mod xmlrpc;
+
+mod async_session;
+mod builder;
+mod client;
+#[cfg(test)]
+mod fixtures;
+mod limit;
 mod page;
+mod relogin;
+mod retry;
 mod space;
+mod state;
 mod transforms;
-
-pub use page::{Page, PageSummary, PageUpdateOptions, UpdatePage};
-pub use space::Space;
+mod trash;
+
+pub use async_session::{AsyncSession, BoxFuture};
+pub use builder::SessionBuilder;
+pub use client::ConfluenceClient;
+pub use page::{MovePosition, Page, PageSummary, PageUpdateOptions, Pages, UpdatePage};
+pub use relogin::{CredentialProvider, ReloginEvent};
+pub use retry::{RetryPolicy, RetryableError};
+pub use space::{Space, SpaceSummary, UpdateSpace};
+pub use state::SessionState;
 pub use transforms::FromElement;
+pub use trash::{ContentSummary, TrashContents};
+pub use xmlrpc::FromValue;
 
 use std::io::Error as IoError;
 use std::result;
-
-use self::http::HttpError;
+use std::sync::RwLock;
+use std::thread;
+use std::time::Duration;
+
+use self::http::{HttpError, Method as HttpMethod, ReqwestTransport, StatusCode, Transport};
+use self::limit::Limiter;
+use self::relogin::Relogin;
+use self::rest::RestError;
+use self::rpser::value::SoapValue;
 use self::rpser::xml::BuildElement;
+pub use self::rpser::FaultKind;
 use self::rpser::{Method, RpcError};
+use self::xmlrpc::{MethodCall, XmlRpcError};
 use xmltree::Element;
 
 const V2_API_RPC_PATH: &str = "/rpc/soap-axis/confluenceservice-v2?wsdl";
 
 /// Client's session.
 pub struct Session {
+    url: String,
+    transport: Box<dyn Transport>,
     wsdl: wsdl::Wsdl,
-    token: String,
+    token: RwLock<String>,
+    relogin: Option<Relogin>,
+    retry: RetryPolicy,
+    limiter: Limiter,
+    drop_behavior: DropBehavior,
+    validate_arguments: bool,
+    backend: Backend,
+    /// Whether requests are authenticated at the HTTP layer, as the REST API requires.
+    http_auth: bool,
+}
+
+/// Remote API the session calls methods over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    /// SOAP service at `/rpc/soap-axis/confluenceservice-v2`, described by the WSDL.
+    #[default]
+    Soap,
+    /// XML-RPC endpoint at `/rpc/xmlrpc`, with the `confluence2` methods.
+    ///
+    /// No WSDL is fetched.
+    XmlRpc,
+    /// REST API at `/rest/api`, for Confluence 7 and Cloud.
+    ///
+    /// Only `get_space`, the page getters, `store_page`, `update_page`, `get_children`
+    /// and `restore_page` are available. The other methods fail with `Error::Unsupported`
+    /// before sending anything, and `call` still needs the SOAP endpoint. Requests are
+    /// authenticated at the HTTP layer: `SessionBuilder::login` sends the credentials
+    /// with basic auth, and there is nothing to log out from.
+    Rest,
+}
+
+/// What happens to the auth token when `Session` is dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropBehavior {
+    /// Log out, ignoring any errors.
+    Logout,
+    /// Log out, reporting errors to the log.
+    #[default]
+    LogoutAndLog,
+    /// Do not log out, so the token stays valid until it expires.
+    KeepToken,
 }
 
 impl Drop for Session {
     fn drop(&mut self) {
-        self.logout().unwrap();
+        match self.drop_behavior {
+            DropBehavior::Logout => {
+                let _ = self.logout();
+            }
+            DropBehavior::LogoutAndLog => {
+                if let Err(e) = self.logout() {
+                    warn!("failed to log out when dropping session: {:?}", e);
+                }
+            }
+            DropBehavior::KeepToken => {
+                debug!("session dropped, keeping the token");
+            }
+        }
     }
 }
 
@@ -68,57 +200,184 @@ impl Session {
     ```
     */
     pub fn login(url: &str, user: &str, pass: &str) -> Result<Session> {
-        debug!("logging in at url {:?} with user {:?}", url, user);
+        Session::login_with_transport(ReqwestTransport::new(), url, user, pass)
+    }
 
-        let url = if url.ends_with('/') {
-            &url[..url.len() - 1]
-        } else {
-            url
-        };
-        let wsdl_url = [url, V2_API_RPC_PATH].concat();
+    /**
+    Create new confluence session which sends all requests over the given transport.
 
-        debug!("getting wsdl from url {:?}", wsdl_url);
+    ## Example
 
-        let wsdl = try!(wsdl::fetch(&wsdl_url));
-        let mut session = Session {
-            wsdl,
-            token: String::new(),
-        };
+    ```no_run
+    use confluence::http::ReqwestTransport;
+
+    let session = confluence::Session::login_with_transport(
+        ReqwestTransport::new(),
+        "https://confluence",
+        "user",
+        "pass"
+    ).unwrap();
+    ```
+    */
+    pub fn login_with_transport<T>(
+        transport: T,
+        url: &str,
+        user: &str,
+        pass: &str,
+    ) -> Result<Session>
+    where
+        T: Transport + 'static,
+    {
+        Session::builder(url).transport(transport).login(user, pass)
+    }
+
+    /**
+    Create a builder to configure the session before logging in.
+
+    ## Example
+
+    ```no_run
+    use std::time::Duration;
+
+    let session = confluence::Session::builder("https://confluence")
+        .timeout(Duration::from_secs(30))
+        .auto_relogin()
+        .login("user", "pass")
+        .unwrap();
+    ```
+    */
+    pub fn builder<S: Into<String>>(url: S) -> SessionBuilder {
+        SessionBuilder::new(url)
+    }
+
+    /**
+    Create session using a token received from an earlier login.
+
+    The WSDL is fetched, but no login is made. The token is kept when the
+    session is dropped, use `close` to log out.
+
+    ## Example
+
+    ```no_run
+    let session = confluence::Session::from_token("https://confluence", "a3a8ecc6d5").unwrap();
+    ```
+    */
+    pub fn from_token(url: &str, token: &str) -> Result<Session> {
+        Session::builder(url)
+            .on_drop(DropBehavior::KeepToken)
+            .resume_token(token)
+    }
+
+    /**
+    Create session from a saved state, without any requests to the server.
+
+    The token is not validated, use `SessionBuilder::resume_or_login` to
+    fall back to login when it has expired. The token is kept when the
+    session is dropped, use `close` to log out.
+
+    ## Example
+
+    ```no_run
+    use confluence::{Session, SessionState};
+
+    let state = SessionState::load("confluence-session.xml").unwrap();
+    let session = Session::resume(state).unwrap();
+    ```
+    */
+    pub fn resume(state: SessionState) -> Result<Session> {
+        Session::builder(state.url.as_str())
+            .on_drop(DropBehavior::KeepToken)
+            .resume(state)
+    }
+
+    /// State of this session, which can be saved and resumed later.
+    ///
+    /// The token changes when the session logs in again, so save the state
+    /// after the session is no longer used.
+    pub fn state(&self) -> SessionState {
+        SessionState {
+            url: self.url.clone(),
+            token: self.token(),
+            wsdl: self.wsdl.clone(),
+        }
+    }
 
-        let response = try!(session.call(
-            Method::new("login")
-                .with(Element::node("username").with_text(user))
-                .with(Element::node("password").with_text(pass))
-        ));
+    /// Check whether the token is still accepted by the server, with a cheap `getServerInfo` call.
+    fn check_token(&self) -> Result<bool> {
+        let method =
+            Method::new("getServerInfo").with(Element::node("token").with_text(self.token()));
 
-        let token = match try!(response.body.descend(&["loginReturn"])).text {
-            Some(token) => token,
-            _ => return Err(Error::ReceivedNoLoginToken),
+        let result = match self.backend {
+            Backend::Soap => self.send(&method).map(|_| ()),
+            Backend::XmlRpc => self.send_xml_rpc(&method).map(|_| ()),
+            // authenticated at the HTTP layer, there is no token to check
+            Backend::Rest => Ok(()),
         };
 
-        session.token = token;
+        match result {
+            Ok(_) => Ok(true),
+            Err(ref e)
+                if e.fault_kind() == Some(FaultKind::InvalidSession)
+                    || e.fault_kind() == Some(FaultKind::AuthenticationFailed) =>
+            {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Call `login` and return the received token.
+    fn login_call(&self, user: &str, pass: &str) -> Result<String> {
+        let method = Method::new("login")
+            .with(Element::node("username").with_text(user))
+            .with(Element::node("password").with_text(pass));
+
+        match self.backend {
+            Backend::Soap => login_token(self.send(&method)?),
+            Backend::XmlRpc => match self.send_xml_rpc(&method)? {
+                xmlrpc::Value::String(token) => Ok(token),
+                _ => Err(Error::ReceivedNoLoginToken),
+            },
+            // authenticated at the HTTP layer, there is no token
+            Backend::Rest => Ok(String::new()),
+        }
+    }
+
+    /**
+    Log out and close the session, returning the result of the logout.
+
+    ## Example
+
+    ```no_run
+    let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+
+    if !session.close().unwrap() {
+        println!("token had already expired");
+    }
+    ```
+    */
+    pub fn close(mut self) -> Result<bool> {
+        self.drop_behavior = DropBehavior::KeepToken;
+        self.logout()
+    }
 
-        Ok(session)
+    /// Change what happens to the auth token when this session is dropped.
+    pub fn set_drop_behavior(&mut self, behavior: DropBehavior) {
+        self.drop_behavior = behavior;
     }
 
     /// Explicitly log out out of confluence.
     ///
-    /// This is done automatically at the end of Session's lifetime.
+    /// This is done automatically at the end of Session's lifetime, unless
+    /// configured otherwise with `DropBehavior`.
     pub fn logout(&self) -> Result<bool> {
-        let response = try!(self.call(
-            Method::new("logout").with(Element::node("token").with_text(self.token.clone()))
-        ));
-
-        Ok(match try!(response.body.descend(&["logoutReturn"])).text {
-            Some(ref v) if v == "true" => {
-                debug!("logged out successfully");
-                true
-            }
-            _ => {
-                debug!("log out failed (maybe expired token, maybe not loged in)");
-                false
-            }
-        })
+        let method = Method::new("logout").with(Element::node("token").with_text(self.token()));
+
+        match self.backend {
+            Backend::Soap => logout_result(self.call(method)?),
+            Backend::XmlRpc => Ok(self.call_xml_rpc(method)?.as_boolean() == Some(true)),
+            Backend::Rest => Ok(false),
+        }
     }
 
     /**
@@ -140,15 +399,137 @@ impl Session {
     ```
     */
     pub fn get_space(&self, space_key: &str) -> Result<Space> {
-        let response = try!(self.call(
+        if self.backend == Backend::Rest {
+            return rest::get_space(self, space_key);
+        }
+
+        self.invoke(
             Method::new("getSpace")
-                .with(Element::node("token").with_text(self.token.clone()))
-                .with(Element::node("spaceKey").with_text(space_key))
-        ));
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key)),
+        )
+    }
 
-        let element = try!(response.body.descend(&["getSpaceReturn"]));
+    /**
+    Returns all spaces visible to the user.
 
-        Ok(try!(Space::from_element(element)))
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    for space in session.get_spaces().unwrap() {
+        println!("{}: {}", space.key, space.name);
+    }
+    ```
+    */
+    pub fn get_spaces(&self) -> Result<Vec<SpaceSummary>> {
+        self.invoke(Method::new("getSpaces").with(Element::node("token").with_text(self.token())))
+    }
+
+    /**
+    Creates a global space.
+
+    The Space given as an argument should have the key, name and (optional) description.
+    Use helper `UpdateSpace::with_create_fields` to create such space.
+
+    ## Example
+
+    ```no_run
+    use confluence::UpdateSpace;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let space = session.add_space(
+        UpdateSpace::with_create_fields("DOC", "Documentation", Some("Product manuals"))
+    ).unwrap();
+    println!("Home page: {}", space.home_page);
+    ```
+    */
+    pub fn add_space(&self, space: UpdateSpace) -> Result<Space> {
+        self.invoke(
+            Method::new("addSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(transforms::update_space_element(space)),
+        )
+    }
+
+    /**
+    Creates a personal space for the user.
+
+    The key of the given space is ignored: Confluence derives it from the user name.
+    */
+    pub fn add_personal_space(&self, space: UpdateSpace, user_name: &str) -> Result<Space> {
+        self.invoke(
+            Method::new("addPersonalSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(transforms::update_space_element(space))
+                .with(Element::node("userName").with_text(user_name)),
+        )
+    }
+
+    /**
+    Removes the space with all its pages, returning `true` on success.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    if session.remove_space("SANDBOX").unwrap() {
+        println!("removed");
+    }
+    ```
+    */
+    pub fn remove_space(&self, space_key: &str) -> Result<bool> {
+        self.invoke(
+            Method::new("removeSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key)),
+        )
+    }
+
+    /**
+    Updates the name, description or home page of the space.
+
+    ## Example
+
+    ```no_run
+    use confluence::UpdateSpace;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let mut space: UpdateSpace = session.get_space("DOC").unwrap().into();
+    space.description = Some("Product manuals, updated nightly".into());
+
+    session.store_space(space).unwrap();
+    ```
+    */
+    pub fn store_space(&self, space: UpdateSpace) -> Result<Space> {
+        self.invoke(
+            Method::new("storeSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(transforms::update_space_element(space)),
+        )
+    }
+
+    /**
+    Archives the space, returning `true` on success.
+
+    Archived spaces are read-only and hidden from search and the space directory.
+    */
+    pub fn archive_space(&self, space_key: &str) -> Result<bool> {
+        self.set_space_status(space_key, "ARCHIVED")
+    }
+
+    /// Restores the archived space, returning `true` on success.
+    pub fn unarchive_space(&self, space_key: &str) -> Result<bool> {
+        self.set_space_status(space_key, "CURRENT")
+    }
+
+    fn set_space_status(&self, space_key: &str, status: &str) -> Result<bool> {
+        self.invoke(
+            Method::new("setSpaceStatus")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("status").with_text(status)),
+        )
     }
 
     /**
@@ -166,16 +547,16 @@ impl Session {
     ```
     */
     pub fn get_page_by_title(&self, space_key: &str, page_title: &str) -> Result<Page> {
-        let response = try!(self.call(
+        if self.backend == Backend::Rest {
+            return rest::get_page_by_title(self, space_key, page_title);
+        }
+
+        self.invoke(
             Method::new("getPage")
-                .with(Element::node("token").with_text(self.token.clone()))
+                .with(Element::node("token").with_text(self.token()))
                 .with(Element::node("spaceKey").with_text(space_key))
-                .with(Element::node("pageTitle").with_text(page_title))
-        ));
-
-        let element = try!(response.body.descend(&["getPageReturn"]));
-
-        Ok(try!(Page::from_element(element)))
+                .with(Element::node("pageTitle").with_text(page_title)),
+        )
     }
 
     /**
@@ -193,15 +574,15 @@ impl Session {
     ```
     */
     pub fn get_page_by_id(&self, page_id: i64) -> Result<Page> {
-        let response = try!(self.call(
-            Method::new("getPage")
-                .with(Element::node("token").with_text(self.token.clone()))
-                .with(Element::node("pageId").with_text(page_id.to_string()))
-        ));
-
-        let element = try!(response.body.descend(&["getPageReturn"]));
+        if self.backend == Backend::Rest {
+            return rest::get_page_by_id(self, page_id);
+        }
 
-        Ok(try!(Page::from_element(element)))
+        self.invoke(
+            Method::new("getPage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string())),
+        )
     }
 
     /**
@@ -267,113 +648,301 @@ impl Session {
     ```
     */
     pub fn store_page(&self, page: UpdatePage) -> Result<Page> {
-        let mut element_items = vec![
-            Element::node("space").with_text(page.space),
-            Element::node("title").with_text(page.title),
-            Element::node("content").with_text(page.content),
-        ];
-
-        if let Some(id) = page.id {
-            element_items.push(Element::node("id").with_text(id.to_string()));
+        if self.backend == Backend::Rest {
+            return rest::store_page(self, page, None);
         }
 
-        if let Some(version) = page.version {
-            element_items.push(Element::node("version").with_text(version.to_string()));
+        self.invoke(
+            Method::new("storePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(transforms::update_page_element(page)),
+        )
+    }
+
+    /**
+    Updates the page.
+
+    Same as `store_page`, but with additional update options parameter.
+    */
+    pub fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> Result<Page> {
+        if self.backend == Backend::Rest {
+            return rest::store_page(self, page, Some(options));
         }
 
-        if let Some(parent_id) = page.parent_id {
-            element_items.push(Element::node("parentId").with_text(parent_id.to_string()));
+        self.invoke(
+            Method::new("updatePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(transforms::update_page_element(page))
+                .with(transforms::page_update_options_element(options)),
+        )
+    }
+
+    /**
+    Returns all the direct children of this page.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    println!("Page Summaries: {:#?}",
+        session.get_children(
+            123456
+        )
+    );
+    ```
+    */
+    pub fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        if self.backend == Backend::Rest {
+            return rest::get_children(self, page_id);
         }
 
-        let response = try!(self.call(
-            Method::new("storePage")
-                .with(Element::node("token").with_text(self.token.clone()))
-                .with(Element::node("page").with_children(element_items))
-        ));
+        self.invoke(
+            Method::new("getChildren")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string())),
+        )
+    }
+
+    /**
+    Returns all the descendants of this page: its children, their children, and so on.
 
-        let element = try!(response.body.descend(&["storePageReturn"]));
+    ## Example
 
-        Ok(try!(Page::from_element(element)))
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    println!("Pages below: {}", session.get_descendants(123456).unwrap().len());
+    ```
+    */
+    pub fn get_descendants(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        self.invoke(
+            Method::new("getDescendents")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string())),
+        )
     }
 
     /**
-    Updates the page.
+    Returns the summaries of all pages in the space, including orphaned pages.
 
-    Same as `store_page`, but with additional update options parameter.
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    println!("Pages: {}", session.get_pages("SomeSpaceKey").unwrap().len());
+    ```
     */
-    pub fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> Result<Page> {
-        let mut element_items = vec![
-            Element::node("space").with_text(page.space),
-            Element::node("title").with_text(page.title),
-            Element::node("content").with_text(page.content),
-        ];
+    pub fn get_pages(&self, space_key: &str) -> Result<Vec<PageSummary>> {
+        self.invoke(
+            Method::new("getPages")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key)),
+        )
+    }
 
-        if let Some(id) = page.id {
-            element_items.push(Element::node("id").with_text(id.to_string()));
-        }
+    /**
+    Returns an iterator over the full pages of the space.
 
-        if let Some(version) = page.version {
-            element_items.push(Element::node("version").with_text(version.to_string()));
-        }
+    The summaries are listed with `get_pages` up front, and each page is fetched
+    by id only when the iterator reaches it, so a large space can be scanned
+    without holding all the content in memory.
 
-        if let Some(parent_id) = page.parent_id {
-            element_items.push(Element::node("parentId").with_text(parent_id.to_string()));
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    for page in session.iter_pages("SomeSpaceKey").unwrap() {
+        let page = page.unwrap();
+        if page.content.contains("TODO") {
+            println!("{}", page.url);
         }
+    }
+    ```
+    */
+    pub fn iter_pages(&self, space_key: &str) -> Result<Pages<'_>> {
+        Ok(Pages::new(self, self.get_pages(space_key)?))
+    }
 
-        let mut update_options = vec![];
+    /**
+    Moves the page next to, or under the target page, which may be in another space.
 
-        if let Some(comment) = options.version_comment {
-            update_options.push(Element::node("versionComment").with_text(comment));
-        }
+    The children of the page move with it.
 
-        update_options.push(Element::node("minorEdit").with_text(if options.minor_edit {
-            "true"
-        } else {
-            "false"
-        }));
+    ## Example
 
-        let response = try!(self.call(
-            Method::new("updatePage")
-                .with(Element::node("token").with_text(self.token.clone()))
-                .with(Element::node("page").with_children(element_items))
-                .with(Element::node("pageUpdateOptions").with_children(update_options))
-        ));
+    ```no_run
+    use confluence::MovePosition;
 
-        let element = try!(response.body.descend(&["updatePageReturn"]));
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    // put page 123 right before its sibling 456
+    session.move_page(123, 456, MovePosition::Above).unwrap();
+    ```
+    */
+    pub fn move_page(
+        &self,
+        source_page_id: i64,
+        target_page_id: i64,
+        position: MovePosition,
+    ) -> Result<bool> {
+        self.invoke(
+            Method::new("movePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("sourcePageId").with_text(source_page_id.to_string()))
+                .with(Element::node("targetPageId").with_text(target_page_id.to_string()))
+                .with(Element::node("position").with_text(position.as_str())),
+        )
+    }
 
-        Ok(try!(Page::from_element(element)))
+    /// Moves the page to the top level of the space, out from under its parent.
+    pub fn move_page_to_top_level(&self, page_id: i64, space_key: &str) -> Result<bool> {
+        self.invoke(
+            Method::new("movePageToTopLevel")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string()))
+                .with(Element::node("targetSpaceKey").with_text(space_key)),
+        )
     }
 
     /**
-    Returns all the direct children of this page.
+    Moves the page with all its descendants into the space, returning the number of moved pages.
+
+    The page goes under `parent_id`, which must be a page of that space, or to the
+    top level of the space when it is `None`. Confluence moves the descendants along
+    with the page, so they are only listed to be counted, and the tree keeps its order.
 
     ## Example
 
     ```no_run
     # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
-    println!("Page Summaries: {:#?}",
-        session.get_children(
-            123456
-        )
-    );
+    let archive = session.get_space("ARCHIVE").unwrap();
+    let moved = session.move_page_tree(123456, "ARCHIVE", Some(archive.home_page)).unwrap();
+    println!("moved {} pages", moved);
     ```
     */
-    pub fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
-        let response = try!(self.call(
-            Method::new("getChildren")
-                .with(Element::node("token").with_text(self.token.clone()))
+    pub fn move_page_tree(
+        &self,
+        page_id: i64,
+        space_key: &str,
+        parent_id: Option<i64>,
+    ) -> Result<usize> {
+        ConfluenceClient::move_page_tree(self, page_id, space_key, parent_id)
+    }
+
+    /**
+    Moves the page to the trash of its space, returning `true` on success.
+
+    Removing a page which is already in the trash fails with `FaultKind::AlreadyDeleted`,
+    while a page the user may not remove fails with `FaultKind::NotPermitted`.
+
+    ## Example
+
+    ```no_run
+    use confluence::FaultKind;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    match session.remove_page(123456) {
+        Ok(_) => println!("moved to trash"),
+        Err(ref e) if e.fault_kind() == Some(FaultKind::AlreadyDeleted) => println!("already gone"),
+        Err(e) => panic!("{:?}", e),
+    }
+    ```
+    */
+    pub fn remove_page(&self, page_id: i64) -> Result<bool> {
+        self.invoke(
+            Method::new("removePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("pageId").with_text(page_id.to_string())),
+        )
+    }
+
+    /// Removes one historical version of the page, returning `true` on success.
+    pub fn remove_page_version(&self, page_id: i64, version: i32) -> Result<bool> {
+        self.invoke(
+            Method::new("removePageVersionByVersion")
+                .with(Element::node("token").with_text(self.token()))
                 .with(Element::node("pageId").with_text(page_id.to_string()))
-        ));
+                .with(version.to_soap("version")),
+        )
+    }
+
+    /**
+    Returns a batch of at most `count` items from the trash of the space, starting at `offset`.
+
+    ## Example
+
+    ```no_run
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    let trash = session.get_trash_contents("SomeSpaceKey", 0, 100).unwrap();
+    println!("{} of {} items", trash.items.len(), trash.total_available);
+    ```
+    */
+    pub fn get_trash_contents(
+        &self,
+        space_key: &str,
+        offset: i32,
+        count: i32,
+    ) -> Result<TrashContents> {
+        self.invoke(
+            Method::new("getTrashContents")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(offset.to_soap("offset"))
+                .with(count.to_soap("count")),
+        )
+    }
 
-        let element = try!(response.body.descend(&["getChildrenReturn"]));
+    /// Permanently removes the content from the trash of the space, returning `true` on success.
+    pub fn purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool> {
+        self.invoke(
+            Method::new("purgeFromTrash")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key))
+                .with(Element::node("contentId").with_text(content_id.to_string())),
+        )
+    }
 
-        let mut summaries = vec![];
+    /// Permanently removes everything in the trash of the space, returning `true` on success.
+    pub fn empty_trash(&self, space_key: &str) -> Result<bool> {
+        self.invoke(
+            Method::new("emptyTrash")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("spaceKey").with_text(space_key)),
+        )
+    }
 
-        for element in element.children {
-            summaries.push(try!(PageSummary::from_element(element)));
+    /**
+    Restores the page from the trash, returning the restored page.
+
+    Confluence has no SOAP or XML-RPC method for this, so the page is restored
+    with the REST API for every backend. The transport must authenticate at the
+    HTTP layer: use `Backend::Rest`, or `SessionBuilder::personal_access_token`.
+    Sessions which only have a SOAP or XML-RPC token fail with `Error::Unsupported`
+    before sending anything.
+    */
+    pub fn restore_page(&self, page_id: i64) -> Result<Page> {
+        if !self.http_auth {
+            return Err(Error::Unsupported {
+                method: "restorePage".into(),
+                backend: self.backend,
+            });
         }
 
-        Ok(summaries)
+        rest::restore_page(self, page_id)
+    }
+
+    /// Call the method over the session's backend and decode the returned value.
+    fn invoke<T: FromElement + FromValue>(&self, method: Method) -> Result<T> {
+        match self.backend {
+            Backend::Soap => {
+                let return_name = format!("{}Return", method.name);
+                let response = self.call(method)?;
+                T::from_element(response.body.descend(&[&return_name])?)
+            }
+            Backend::XmlRpc => T::from_value(self.call_xml_rpc(method)?),
+            Backend::Rest => Err(Error::Unsupported {
+                method: method.name,
+                backend: self.backend,
+            }),
+        }
     }
 
     /// Call a custom method on this session.
@@ -393,11 +962,339 @@ impl Session {
     ///
     /// If you need an example, look at how these convenience methods are implemented.
     ///
+    /// Typed wrappers for every remote method can be generated from the server's WSDL
+    /// with `wsdl::codegen` (see `examples/generate_bindings.rs`).
+    ///
     /// Pull requests are welcome!
+    ///
+    /// ## Expired token
+    ///
+    /// If the session was built with `auto_relogin` or `credential_provider`, a method which
+    /// fails with `InvalidSessionException` is retried once after logging in again.
+    /// The `token` argument of the method is replaced with the new token.
     pub fn call(&self, method: rpser::Method) -> Result<rpser::Response> {
-        let url = match self.wsdl.operations.get(&method.name) {
-            None => return Err(Error::MethodNotFoundInWsdl(method.name)),
-            Some(ref op) => &op.url,
+        self.with_relogin(method, |method| self.send(method))
+    }
+
+    /**
+    Call a custom method over XML-RPC, regardless of the session's backend.
+
+    The arguments are converted with `xmlrpc::Value::from_argument`, and the
+    method is called as `confluence2.<name>`. Expired tokens are handled the
+    same way as in `call`.
+
+    ## Example
+
+    ```no_run
+    use confluence::rpser::xml::BuildElement;
+    use confluence::rpser::Method;
+    use confluence::xmlrpc::Value;
+    use confluence::{Backend, Session};
+    # extern crate confluence;
+    # extern crate xmltree;
+    use xmltree::Element;
+
+    # fn main() {
+    let session = Session::builder("https://confluence")
+        .backend(Backend::XmlRpc)
+        .login("user", "pass")
+        .unwrap();
+
+    let info = session
+        .call_xml_rpc(Method::new("getServerInfo").with(Element::node("token").with_text(session.token())))
+        .unwrap();
+    println!("Version: {:?}", info.member("majorVersion").and_then(Value::as_long));
+    # }
+    ```
+    */
+    pub fn call_xml_rpc(&self, method: rpser::Method) -> Result<xmlrpc::Value> {
+        self.with_relogin(method, |method| self.send_xml_rpc(method))
+    }
+
+    /// Send the method, logging in again and retrying once if the token has expired.
+    fn with_relogin<T, F>(&self, mut method: rpser::Method, send: F) -> Result<T>
+    where
+        F: Fn(&rpser::Method) -> Result<T>,
+    {
+        let token = self.token();
+
+        match send(&method) {
+            Err(ref e)
+                if self.relogin.is_some()
+                    && e.fault_kind() == Some(FaultKind::InvalidSession)
+                    && method.name != "logout" => {}
+            other => return other,
+        }
+
+        self.relogin(&method.name, &token)?;
+
+        let token = self.token();
+        for arg in method.args.iter_mut().filter(|arg| arg.name == "token") {
+            arg.text = Some(token.clone());
+        }
+
+        send(&method)
+    }
+
+    /// Log in again, unless another call has already done so since `expired_token` was used.
+    fn relogin(&self, method: &str, expired_token: &str) -> Result<()> {
+        let relogin = match self.relogin {
+            Some(ref relogin) => relogin,
+            None => return Ok(()),
+        };
+
+        let mut token = self.token.write().unwrap_or_else(|e| e.into_inner());
+        if *token != expired_token {
+            return Ok(());
+        }
+
+        let (user, pass) = relogin.credentials.credentials();
+
+        debug!(
+            "token expired during {:?}, logging in again as {:?}",
+            method, user
+        );
+
+        let result = self.login_call(&user, &pass);
+        if let Ok(ref new_token) = result {
+            *token = new_token.clone();
+        }
+        // the hook may read the token
+        drop(token);
+
+        if let Some(ref hook) = relogin.hook {
+            hook(&ReloginEvent {
+                method,
+                user: &user,
+                error: result.as_ref().err(),
+            });
+        }
+
+        result.map(|_| ())
+    }
+
+    /// Current auth token, empty for sessions authenticated at the HTTP layer.
+    pub fn token(&self) -> String {
+        self.token.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Send the method and parse the response, retrying according to the retry policy.
+    fn send(&self, method: &rpser::Method) -> Result<rpser::Response> {
+        let call = PreparedCall::new(&self.wsdl, method)?;
+
+        if self.validate_arguments {
+            self.wsdl
+                .validate(method)
+                .map_err(Error::InvalidArguments)?;
+        }
+
+        self.send_prepared(&call, parse_soap_response)
+    }
+
+    /// Send the method as XML-RPC call and parse the response, retrying according to the retry policy.
+    fn send_xml_rpc(&self, method: &rpser::Method) -> Result<xmlrpc::Value> {
+        let call = PreparedCall::xml_rpc(&self.url, &self.wsdl, method);
+
+        self.send_prepared(&call, parse_xml_rpc_response)
+    }
+
+    /// Send the REST API request and parse the JSON response, retrying according to the retry policy.
+    ///
+    /// The `action` is the name of the equivalent SOAP method, used by the retry policy.
+    fn send_rest(
+        &self,
+        action: &str,
+        method: HttpMethod,
+        path: &str,
+        query: &[(&str, &str)],
+        json: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let call = PreparedCall::rest(&self.url, action, method, path, query, json)?;
+
+        self.send_prepared(&call, |response, retry_after| {
+            parse_rest_response(path, response, retry_after)
+        })
+    }
+
+    fn send_prepared<T, F>(&self, call: &PreparedCall, parse: F) -> Result<T>
+    where
+        F: Fn(http::Response, Option<Duration>) -> Result<T>,
+    {
+        let mut attempt = 1;
+        loop {
+            match self.send_once(call, &parse) {
+                Err(ref e)
+                    if self
+                        .retry
+                        .should_retry_call(&call.action, call.idempotent, e, attempt) =>
+                {
+                    let delay = self.retry.retry_delay(e, attempt);
+                    warn!(
+                        "[call] {} failed (attempt {}), retrying in {:?}: {:?}",
+                        call.action, attempt, delay, e
+                    );
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+                other => return other,
+            }
+        }
+    }
+
+    fn send_once<T, F>(&self, call: &PreparedCall, parse: &F) -> Result<T>
+    where
+        F: Fn(http::Response, Option<Duration>) -> Result<T>,
+    {
+        let http_response = {
+            let _permit = self.limiter.acquire();
+
+            match call.backend {
+                Backend::Soap => self
+                    .transport
+                    .soap_action(&call.url, &call.action, &call.body)?,
+                Backend::XmlRpc => self.transport.post_xml(&call.url, &call.body)?,
+                Backend::Rest => {
+                    let json = if call.body.is_empty() {
+                        None
+                    } else {
+                        Some(call.body.as_str())
+                    };
+                    self.transport
+                        .send_json(call.http_method.clone(), &call.url, json)?
+                }
+            }
+        };
+
+        trace!("[response xml] {}", http_response.body);
+
+        let retry_after = throttled_for(&http_response);
+        if let Some(retry_after) = retry_after {
+            self.limiter.pause_for(retry_after);
+        }
+
+        parse(http_response, retry_after)
+    }
+}
+
+/// How long the server asked to wait with `Retry-After`, when it is throttling or unavailable.
+fn throttled_for(http_response: &http::Response) -> Option<Duration> {
+    match http_response.status {
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => {
+            http_response.retry_after()
+        }
+        _ => None,
+    }
+}
+
+/// Parse the SOAP envelope received in HTTP response.
+///
+/// SOAP faults are returned with `500 Internal Server Error`. Any other unsuccessful status,
+/// or a body which is not a SOAP envelope (such as a login page or a proxy error page),
+/// is reported as `Error::UnexpectedResponse`.
+fn parse_soap_response(
+    http_response: http::Response,
+    retry_after: Option<Duration>,
+) -> Result<rpser::Response> {
+    let status = http_response.status;
+
+    if status.is_success() || status == StatusCode::INTERNAL_SERVER_ERROR {
+        match rpser::Response::from_xml(&http_response.body) {
+            Err(ref e @ RpcError::MalformedXml { .. })
+            | Err(ref e @ RpcError::UnexpectedElement { .. }) => {
+                debug!("[response] not a SOAP envelope: {:?}", e);
+            }
+            other => return Ok(other?),
+        }
+    }
+
+    Err(Error::UnexpectedResponse {
+        status,
+        body_excerpt: http_response.body_excerpt(),
+        retry_after,
+    })
+}
+
+/// Parse the XML-RPC method response received in HTTP response.
+///
+/// XML-RPC faults are returned with `200 OK`. Any other status, or a body which is
+/// not a method response, is reported as `Error::UnexpectedResponse`.
+fn parse_xml_rpc_response(
+    http_response: http::Response,
+    retry_after: Option<Duration>,
+) -> Result<xmlrpc::Value> {
+    let status = http_response.status;
+
+    if status.is_success() {
+        match xmlrpc::parse_response(&http_response.body) {
+            Err(ref e @ XmlRpcError::MalformedXml { .. })
+            | Err(ref e @ XmlRpcError::UnexpectedElement { .. }) => {
+                debug!("[response] not an XML-RPC method response: {:?}", e);
+            }
+            other => return Ok(other?),
+        }
+    }
+
+    Err(Error::UnexpectedResponse {
+        status,
+        body_excerpt: http_response.body_excerpt(),
+        retry_after,
+    })
+}
+
+/// Parse the JSON received in HTTP response from the REST API.
+///
+/// Client errors with a JSON message (such as `404 Not Found`) are reported as
+/// `Error::Rest`. Any other unsuccessful status, or a successful response which
+/// is not JSON, is reported as `Error::UnexpectedResponse`.
+fn parse_rest_response(
+    path: &str,
+    http_response: http::Response,
+    retry_after: Option<Duration>,
+) -> Result<serde_json::Value> {
+    let status = http_response.status;
+
+    if status.is_success() {
+        match rest::parse_body(&http_response.body) {
+            Err(Error::Rest(ref e @ RestError::InvalidJson { .. })) => {
+                debug!("[response] not JSON: {:?}", e);
+            }
+            other => return other,
+        }
+    } else if status.is_client_error() && status != StatusCode::TOO_MANY_REQUESTS {
+        if let Some(message) = rest::error_message(&http_response.body) {
+            return Err(RestError::from_status(path, status, message).into());
+        }
+    }
+
+    Err(Error::UnexpectedResponse {
+        status,
+        body_excerpt: http_response.body_excerpt(),
+        retry_after,
+    })
+}
+
+/// Build the WSDL url from the base confluence url.
+fn wsdl_url(url: &str) -> String {
+    [url.strip_suffix('/').unwrap_or(url), V2_API_RPC_PATH].concat()
+}
+
+/// SOAP, XML-RPC or REST request ready to be sent over HTTP.
+struct PreparedCall {
+    backend: Backend,
+    http_method: HttpMethod,
+    url: String,
+    action: String,
+    body: String,
+    /// Whether repeating the call is safe, see `RetryPolicy`.
+    idempotent: bool,
+}
+
+impl PreparedCall {
+    /// Find the method's endpoint in WSDL and build the SOAP envelope for it.
+    fn new(wsdl: &wsdl::Wsdl, method: &Method) -> Result<PreparedCall> {
+        let url = match wsdl.operations.get(&method.name) {
+            None => return Err(Error::MethodNotFoundInWsdl(method.name.clone())),
+            Some(op) => op.url.clone(),
         };
 
         // do now show password in logs
@@ -407,21 +1304,109 @@ impl Session {
             debug!("[call] {}", method);
         }
 
-        let envelope = method.as_xml(url);
+        let envelope = method.as_xml(&url);
 
         // do now show password in logs
         if method.name != "login" {
             trace!("[method xml] {}", envelope);
         }
 
-        let http_response = try!(http::soap_action(url, &method.name, &envelope));
+        Ok(PreparedCall {
+            backend: Backend::Soap,
+            http_method: HttpMethod::POST,
+            url,
+            action: method.name.clone(),
+            body: envelope,
+            idempotent: retry::is_read_only(&method.name),
+        })
+    }
 
-        trace!("[response xml] {}", http_response.body);
+    /// Build the XML-RPC method call for the endpoint at the base confluence url.
+    fn xml_rpc(url: &str, wsdl: &wsdl::Wsdl, method: &Method) -> PreparedCall {
+        let call = MethodCall::from_method_in(method, wsdl);
 
-        Ok(try!(rpser::Response::from_xml(&http_response.body)))
+        // do now show password in logs
+        if method.name == "login" {
+            debug!("[call] {} ******", call.name);
+        } else {
+            debug!("[call] {}", call);
+        }
+
+        let body = call.as_xml();
+
+        if method.name != "login" {
+            trace!("[method xml] {}", body);
+        }
+
+        PreparedCall {
+            backend: Backend::XmlRpc,
+            http_method: HttpMethod::POST,
+            url: [url.strip_suffix('/').unwrap_or(url), xmlrpc::XML_RPC_PATH].concat(),
+            action: method.name.clone(),
+            body,
+            idempotent: retry::is_read_only(&method.name),
+        }
+    }
+
+    /// Build the REST API request for the path relative to the base confluence url.
+    fn rest(
+        url: &str,
+        action: &str,
+        http_method: HttpMethod,
+        path: &str,
+        query: &[(&str, &str)],
+        json: Option<&serde_json::Value>,
+    ) -> Result<PreparedCall> {
+        let url = [url.strip_suffix('/').unwrap_or(url), path].concat();
+        let url = match reqwest::Url::parse_with_params(&url, query) {
+            Ok(url) => url.into_string(),
+            Err(e) => {
+                return Err(RestError::InvalidUrl {
+                    url,
+                    message: e.to_string(),
+                }
+                .into())
+            }
+        };
+
+        debug!("[call] {} {}", http_method, url);
+
+        let body = json.map(|json| json.to_string()).unwrap_or_default();
+        trace!("[request json] {}", body);
+
+        Ok(PreparedCall {
+            backend: Backend::Rest,
+            idempotent: http_method == HttpMethod::GET,
+            http_method,
+            url,
+            action: action.into(),
+            body,
+        })
+    }
+}
+
+/// Extract the auth token from `login` response.
+fn login_token(response: rpser::Response) -> Result<String> {
+    match response.body.descend(&["loginReturn"])?.text {
+        Some(token) => Ok(token),
+        _ => Err(Error::ReceivedNoLoginToken),
     }
 }
 
+/// Extract the result of `logout` response.
+fn logout_result(response: rpser::Response) -> Result<bool> {
+    Ok(match response.body.descend(&["logoutReturn"])?.text {
+        Some(ref v) if v == "true" => {
+            debug!("logged out successfully");
+            true
+        }
+        _ => {
+            debug!("log out failed (maybe expired token, maybe not loged in)");
+            false
+        }
+    })
+}
+
 /// Confluence library error.
 #[derive(Debug)]
 pub enum Error {
@@ -430,6 +1415,52 @@ pub enum Error {
     Io(IoError),
     Http(HttpError),
     Rpc(Box<RpcError>),
+    /// XML-RPC fault or response which could not be decoded, see `Backend::XmlRpc`.
+    XmlRpc(XmlRpcError),
+    /// REST API error response or unexpected JSON, see `Backend::Rest`.
+    Rest(RestError),
+    /// Method arguments do not match the WSDL, see `SessionBuilder::validate_arguments`.
+    InvalidArguments(wsdl::ValidationError),
+    /// Saved `SessionState` could not be read.
+    InvalidSessionState(String),
+    /// Server responded with something else than a SOAP envelope, XML-RPC method response or JSON.
+    UnexpectedResponse {
+        status: StatusCode,
+        body_excerpt: String,
+        retry_after: Option<Duration>,
+    },
+    /// The session's backend has no such operation, so nothing was sent.
+    Unsupported {
+        method: String,
+        backend: Backend,
+    },
+}
+
+impl Error {
+    /**
+    Kind of the remote exception, if the server responded with a fault.
+
+    ## Example
+
+    ```no_run
+    use confluence::FaultKind;
+
+    # let session = confluence::Session::login("https://confluence", "user", "pass").unwrap();
+    match session.get_page_by_title("SomeSpaceKey", "Page Title") {
+        Ok(page) => println!("Page: {:#?}", page),
+        Err(ref e) if e.fault_kind() == Some(FaultKind::PageNotFound) => println!("No such page"),
+        Err(e) => panic!("failed to get the page: {:?}", e),
+    }
+    ```
+    */
+    pub fn fault_kind(&self) -> Option<FaultKind> {
+        match *self {
+            Error::Rpc(ref e) => e.fault_kind(),
+            Error::XmlRpc(ref e) => e.fault_kind(),
+            Error::Rest(ref e) => e.fault_kind(),
+            _ => None,
+        }
+    }
 }
 
 impl From<HttpError> for Error {
@@ -444,6 +1475,18 @@ impl From<RpcError> for Error {
     }
 }
 
+impl From<XmlRpcError> for Error {
+    fn from(other: XmlRpcError) -> Error {
+        Error::XmlRpc(other)
+    }
+}
+
+impl From<RestError> for Error {
+    fn from(other: RestError) -> Error {
+        Error::Rest(other)
+    }
+}
+
 impl From<rpser::xml::Error> for Error {
     fn from(other: rpser::xml::Error) -> Error {
         RpcError::from(other).into()
@@ -457,3 +1500,249 @@ impl From<IoError> for Error {
 }
 
 pub type Result<T> = result::Result<T, Error>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fixtures::{self, CannedTransport, GET_SPACE, INVALID_SESSION_FAULT};
+    use http::{Response as HttpResponse, StatusCode};
+    use reqwest::header::HeaderMap;
+    use std::sync::{Arc, Mutex};
+
+    /// Transport which knows the space `DOC`.
+    fn transport() -> Arc<CannedTransport> {
+        Arc::new(CannedTransport::new(&[("getSpace", GET_SPACE)]))
+    }
+
+    #[test]
+    fn session_uses_provided_transport() {
+        let transport = transport();
+
+        {
+            let session = Session::login_with_transport(
+                transport.clone(),
+                "https://confluence/",
+                "user",
+                "pass",
+            )
+            .unwrap();
+            assert_eq!(session.token(), "a3a8ecc6d5");
+
+            let space = session.get_space("DOC").unwrap();
+            assert_eq!(space.key, "DOC");
+            assert_eq!(space.home_page, 42);
+        }
+
+        assert_eq!(transport.actions(), vec!["login", "getSpace", "logout"]);
+    }
+
+    #[test]
+    fn logs_in_again_when_token_expires() {
+        let transport = transport();
+        let relogins = Arc::new(Mutex::new(vec![]));
+
+        let session = {
+            let relogins = relogins.clone();
+            Session::builder("https://confluence")
+                .transport(transport.clone())
+                .auto_relogin()
+                .on_relogin(move |event| {
+                    relogins
+                        .lock()
+                        .unwrap()
+                        .push((event.method.to_string(), event.user.to_string()));
+                })
+                .login("user", "pass")
+                .unwrap()
+        };
+
+        transport.expire_token();
+
+        let space = session.get_space("DOC").unwrap();
+        assert_eq!(space.key, "DOC");
+
+        assert_eq!(
+            transport.actions(),
+            vec!["login", "getSpace", "login", "getSpace"]
+        );
+        assert_eq!(
+            *relogins.lock().unwrap(),
+            vec![("getSpace".to_string(), "user".to_string())]
+        );
+    }
+
+    #[test]
+    fn relogin_hook_reads_new_token() {
+        let transport = transport();
+        let observed: Arc<Mutex<Option<Arc<Session>>>> = Arc::new(Mutex::new(None));
+        let tokens = Arc::new(Mutex::new(vec![]));
+
+        let session = {
+            let (observed, tokens) = (observed.clone(), tokens.clone());
+            Session::builder("https://confluence")
+                .transport(transport.clone())
+                .auto_relogin()
+                .on_relogin(move |_| {
+                    if let Some(ref session) = *observed.lock().unwrap() {
+                        tokens.lock().unwrap().push(session.token());
+                    }
+                })
+                .login("user", "pass")
+                .map(Arc::new)
+                .unwrap()
+        };
+        *observed.lock().unwrap() = Some(session.clone());
+
+        transport.expire_token();
+        session.get_space("DOC").unwrap();
+
+        // break the cycle between the session and its hook
+        observed.lock().unwrap().take();
+        assert_eq!(*tokens.lock().unwrap(), vec!["a3a8ecc6d5"]);
+    }
+
+    #[test]
+    fn restores_pages_only_with_http_auth() {
+        let transport = transport();
+        let session = Session::builder("https://confluence")
+            .transport(transport.clone())
+            .on_drop(DropBehavior::KeepToken)
+            .login("user", "pass")
+            .unwrap();
+
+        match session.restore_page(42) {
+            Err(Error::Unsupported {
+                ref method,
+                backend: Backend::Soap,
+            }) if method == "restorePage" => {}
+            other => panic!("expected unsupported error, received {:?}", other),
+        }
+        assert_eq!(transport.actions(), vec!["login"]);
+    }
+
+    #[test]
+    fn reports_expired_token_without_relogin() {
+        let transport = transport();
+        let session =
+            Session::login_with_transport(transport.clone(), "https://confluence", "user", "pass")
+                .unwrap();
+
+        transport.expire_token();
+
+        match session.get_space("DOC") {
+            Err(ref e) if e.fault_kind() == Some(FaultKind::InvalidSession) => {}
+            other => panic!("expected invalid session fault, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reports_non_soap_responses_with_status_and_excerpt() {
+        let login_page = HttpResponse {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: "<html><body>Please log in</body></html>".into(),
+        };
+        match parse_soap_response(login_page, None) {
+            Err(Error::UnexpectedResponse {
+                status,
+                body_excerpt,
+                ..
+            }) => {
+                assert_eq!(status, StatusCode::OK);
+                assert_eq!(body_excerpt, "<html><body>Please log in</body></html>");
+            }
+            other => panic!("expected unexpected response, received {:?}", other),
+        }
+
+        let proxy_error = HttpResponse {
+            status: StatusCode::BAD_GATEWAY,
+            headers: HeaderMap::new(),
+            body: "Bad Gateway".repeat(100),
+        };
+        match parse_soap_response(proxy_error, None) {
+            Err(Error::UnexpectedResponse {
+                status,
+                body_excerpt,
+                ..
+            }) => {
+                assert_eq!(status, StatusCode::BAD_GATEWAY);
+                assert_eq!(body_excerpt.len(), 256 + "...".len());
+            }
+            other => panic!("expected unexpected response, received {:?}", other),
+        }
+
+        let fault =
+            fixtures::soap_response(StatusCode::INTERNAL_SERVER_ERROR, INVALID_SESSION_FAULT);
+        assert_eq!(
+            parse_soap_response(fault, None).unwrap_err().fault_kind(),
+            Some(FaultKind::InvalidSession)
+        );
+    }
+
+    #[test]
+    fn close_logs_out_once() {
+        let transport = transport();
+        let session =
+            Session::login_with_transport(transport.clone(), "https://confluence", "user", "pass")
+                .unwrap();
+
+        assert!(session.close().unwrap());
+        assert_eq!(transport.actions(), vec!["login", "logout"]);
+    }
+
+    #[test]
+    fn keeps_token_on_drop_when_configured() {
+        let transport = transport();
+        let session = Session::builder("https://confluence")
+            .transport(transport.clone())
+            .on_drop(DropBehavior::KeepToken)
+            .login("user", "pass")
+            .unwrap();
+
+        drop(session);
+        assert_eq!(transport.actions(), vec!["login"]);
+    }
+
+    #[test]
+    fn archives_spaces_with_space_status() {
+        let transport = Arc::new(CannedTransport::new(&[(
+            "setSpaceStatus",
+            r#"<ns1:setSpaceStatusResponse><setSpaceStatusReturn xsi:type="xsd:boolean">true</setSpaceStatusReturn></ns1:setSpaceStatusResponse>"#,
+        )]));
+        let session = Session::builder("https://confluence")
+            .transport(transport.clone())
+            .bundled_wsdl()
+            .on_drop(DropBehavior::KeepToken)
+            .login("user", "pass")
+            .unwrap();
+
+        assert!(session.archive_space("DOC").unwrap());
+        assert!(session.unarchive_space("DOC").unwrap());
+        assert_eq!(
+            transport.actions(),
+            vec!["login", "setSpaceStatus", "setSpaceStatus"]
+        );
+    }
+
+    #[test]
+    fn reports_deleted_pages() {
+        let transport = Arc::new(CannedTransport::new(&[(
+            "removePage",
+            r#"<soapenv:Fault>
+                <faultcode>soapenv:Server.userException</faultcode>
+                <faultstring>com.atlassian.confluence.rpc.RemoteException: The page has already been deleted</faultstring>
+                <detail><com.atlassian.confluence.rpc.RemoteException/></detail>
+            </soapenv:Fault>"#,
+        )]));
+        let session = Session::builder("https://confluence")
+            .transport(transport)
+            .bundled_wsdl()
+            .login("user", "pass")
+            .unwrap();
+
+        match session.remove_page(7) {
+            Err(ref e) if e.fault_kind() == Some(FaultKind::AlreadyDeleted) => {}
+            other => panic!("expected already deleted fault, received {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! Client-side rate limiting and concurrency caps.
+
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Limits how fast and how many calls at once a session sends to the server.
+#[derive(Default)]
+pub(crate) struct Limiter {
+    bucket: Option<Mutex<TokenBucket>>,
+    in_flight: Option<InFlight>,
+    paused_until: Mutex<Option<Instant>>,
+}
+
+impl Limiter {
+    /// Allow at most `requests` calls per `per` period, with bursts up to `requests`.
+    pub fn rate_limit(&mut self, requests: u32, per: Duration) {
+        let capacity = f64::from(requests.max(1));
+
+        self.bucket = Some(Mutex::new(TokenBucket {
+            capacity,
+            per_second: capacity / per.as_secs_f64(),
+            tokens: capacity,
+            refilled_at: Instant::now(),
+        }));
+    }
+
+    /// Allow at most `max` calls to be sent at the same time.
+    pub fn max_in_flight(&mut self, max: usize) {
+        self.in_flight = Some(InFlight {
+            max: max.max(1),
+            count: Mutex::new(0),
+            released: Condvar::new(),
+        });
+    }
+
+    /// Do not send anything until the duration passes, as requested by the server.
+    pub fn pause_for(&self, duration: Duration) {
+        self.pause_until(Instant::now() + duration);
+    }
+
+    /// Do not send anything until the instant, unless already paused for longer.
+    fn pause_until(&self, until: Instant) {
+        let mut paused_until = lock(&self.paused_until);
+
+        if paused_until.map_or(true, |current| current < until) {
+            *paused_until = Some(until);
+        }
+    }
+
+    /// Block until the call is allowed to be sent.
+    ///
+    /// The returned permit has to be kept until the response is received.
+    pub fn acquire(&self) -> Permit<'_> {
+        while let Some(delay) = self.delay() {
+            thread::sleep(delay);
+        }
+
+        Permit {
+            in_flight: self.in_flight.as_ref().map(InFlight::enter),
+        }
+    }
+
+    /// How long to wait before sending the call, or `None` when it may be sent now.
+    ///
+    /// Takes a token from the rate limit when the call may be sent. Does not block,
+    /// so `AsyncSession` can wait on a timer instead.
+    pub fn delay(&self) -> Option<Duration> {
+        self.delay_at(Instant::now())
+    }
+
+    /// How long to wait at `now`, see `delay`.
+    fn delay_at(&self, now: Instant) -> Option<Duration> {
+        let paused_for = lock(&self.paused_until)
+            .and_then(|until| until.checked_duration_since(now))
+            .filter(|delay| !delay.is_zero());
+        if let Some(delay) = paused_for {
+            debug!("server asked to slow down, waiting {:?}", delay);
+            return Some(delay);
+        }
+
+        self.bucket
+            .as_ref()
+            .and_then(|bucket| lock(bucket).take(now))
+    }
+
+    /// Take an in-flight slot without blocking, `false` if all are taken.
+    ///
+    /// The slot has to be given back with `leave`.
+    pub fn try_enter(&self) -> bool {
+        match self.in_flight {
+            Some(ref in_flight) => in_flight.try_enter(),
+            None => true,
+        }
+    }
+
+    /// Give back the in-flight slot taken with `try_enter`.
+    pub fn leave(&self) {
+        if let Some(ref in_flight) = self.in_flight {
+            in_flight.leave();
+        }
+    }
+}
+
+/// Token bucket refilled at constant rate.
+struct TokenBucket {
+    capacity: f64,
+    per_second: f64,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    /// Take a token at `now`, or return how long to wait until one is available.
+    fn take(&mut self, now: Instant) -> Option<Duration> {
+        let elapsed = now
+            .saturating_duration_since(self.refilled_at)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second).min(self.capacity);
+        self.refilled_at = self.refilled_at.max(now);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.per_second,
+            ))
+        }
+    }
+}
+
+/// Counting semaphore for calls in flight.
+struct InFlight {
+    max: usize,
+    count: Mutex<usize>,
+    released: Condvar,
+}
+
+impl InFlight {
+    fn enter(&self) -> &InFlight {
+        let mut count = lock(&self.count);
+        while *count >= self.max {
+            count = self.released.wait(count).unwrap_or_else(|e| e.into_inner());
+        }
+        *count += 1;
+        self
+    }
+
+    fn try_enter(&self) -> bool {
+        let mut count = lock(&self.count);
+        if *count >= self.max {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+
+    fn leave(&self) {
+        *lock(&self.count) -= 1;
+        self.released.notify_one();
+    }
+}
+
+/// Permission to send a call, releases the in-flight slot when dropped.
+pub(crate) struct Permit<'a> {
+    in_flight: Option<&'a InFlight>,
+}
+
+impl<'a> Drop for Permit<'a> {
+    fn drop(&mut self) {
+        if let Some(in_flight) = self.in_flight {
+            in_flight.leave();
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::mpsc;
+    use std::sync::Arc;
+
+    #[test]
+    fn rate_limit_allows_burst_then_waits() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket {
+            capacity: 5.0,
+            per_second: 50.0,
+            tokens: 5.0,
+            refilled_at: start,
+        };
+
+        for _ in 0..5 {
+            assert_eq!(bucket.take(start), None);
+        }
+        assert_eq!(bucket.take(start), Some(Duration::from_millis(20)));
+        assert_eq!(
+            bucket.take(start + Duration::from_millis(10)),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(bucket.take(start + Duration::from_millis(20)), None);
+
+        // idle time refills the bucket up to its capacity only
+        let later = start + Duration::from_secs(60);
+        for _ in 0..5 {
+            assert_eq!(bucket.take(later), None);
+        }
+        assert!(bucket.take(later).is_some());
+    }
+
+    #[test]
+    fn max_in_flight_blocks_until_permit_is_dropped() {
+        let mut limiter = Limiter::default();
+        limiter.max_in_flight(1);
+        let limiter = Arc::new(limiter);
+
+        let permit = limiter.acquire();
+        assert!(!limiter.try_enter());
+
+        let (entered, waiting) = mpsc::channel();
+        let handle = {
+            let limiter = limiter.clone();
+            thread::spawn(move || {
+                let _permit = limiter.acquire();
+                entered.send(()).unwrap();
+            })
+        };
+        assert!(waiting.try_recv().is_err());
+
+        drop(permit);
+        waiting.recv().unwrap();
+        handle.join().unwrap();
+
+        assert!(limiter.try_enter());
+        limiter.leave();
+    }
+
+    #[test]
+    fn pause_delays_next_call() {
+        let limiter = Limiter::default();
+        let start = Instant::now();
+
+        limiter.pause_until(start + Duration::from_millis(30));
+        // a shorter pause does not cut the longer one short
+        limiter.pause_until(start + Duration::from_millis(10));
+
+        assert_eq!(limiter.delay_at(start), Some(Duration::from_millis(30)));
+        assert_eq!(
+            limiter.delay_at(start + Duration::from_millis(20)),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(limiter.delay_at(start + Duration::from_millis(30)), None);
+    }
+}
@@ -1,8 +1,11 @@
 use chrono::offset::Utc;
 use chrono::DateTime;
+use std::vec;
+
+use {ConfluenceClient, Result};
 
 /// Page.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Page {
     /// The id of the page
     pub id: i64,
@@ -35,7 +38,7 @@ pub struct Page {
 }
 
 /// Page Summary.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PageSummary {
     /// The id of the page
     pub id: i64,
@@ -50,7 +53,7 @@ pub struct PageSummary {
 }
 
 /// Page Object for creating a Page.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdatePage {
     /// The id of the page
     pub id: Option<i64>,
@@ -67,7 +70,7 @@ pub struct UpdatePage {
 }
 
 /// Options for updating the page.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PageUpdateOptions {
     // Edit comment for the updated page
     pub version_comment: Option<String>,
@@ -125,3 +128,128 @@ impl From<Page> for UpdatePage {
         }
     }
 }
+
+/// Where `Session::move_page` puts the page, relative to the target page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovePosition {
+    /// Before the target, as its previous sibling.
+    Above,
+    /// After the target, as its next sibling.
+    Below,
+    /// As the last child of the target.
+    Append,
+}
+
+impl MovePosition {
+    /// Name of the position in the `movePage` call.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MovePosition::Above => "above",
+            MovePosition::Below => "below",
+            MovePosition::Append => "append",
+        }
+    }
+}
+
+/**
+Iterator over the pages of a space, returned by `ConfluenceClient::iter_pages`.
+
+Holds only the page summaries, and fetches each full `Page` when it is reached.
+A page which fails to load is yielded as an error, and iteration continues with
+the next one.
+*/
+pub struct Pages<'a> {
+    client: &'a dyn ConfluenceClient,
+    summaries: vec::IntoIter<PageSummary>,
+}
+
+impl<'a> Pages<'a> {
+    pub(crate) fn new(client: &'a dyn ConfluenceClient, summaries: Vec<PageSummary>) -> Pages<'a> {
+        Pages {
+            client,
+            summaries: summaries.into_iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for Pages<'a> {
+    type Item = Result<Page>;
+
+    fn next(&mut self) -> Option<Result<Page>> {
+        let summary = self.summaries.next()?;
+        Some(self.client.get_page_by_id(summary.id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.summaries.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Pages<'a> {}
+
+#[cfg(test)]
+mod test {
+    use fixtures::CannedTransport;
+    use std::sync::Arc;
+    use Session;
+
+    /// Transport which lists the pages 42 and 7 in `DOC`, and only has page 42.
+    fn transport() -> Arc<CannedTransport> {
+        Arc::new(CannedTransport::new(&[
+            (
+                "getPages",
+                r#"<ns1:getPagesResponse><getPagesReturn>
+                    <getPagesReturn>
+                        <id xsi:type="xsd:long">42</id>
+                        <space xsi:type="xsd:string">DOC</space>
+                        <parentId xsi:type="xsd:long">0</parentId>
+                        <title xsi:type="xsd:string">Home</title>
+                        <url xsi:type="xsd:string">https://confluence/display/DOC/Home</url>
+                    </getPagesReturn>
+                    <getPagesReturn>
+                        <id xsi:type="xsd:long">7</id>
+                        <space xsi:type="xsd:string">DOC</space>
+                        <parentId xsi:type="xsd:long">0</parentId>
+                        <title xsi:type="xsd:string">Orphan</title>
+                        <url xsi:type="xsd:string">https://confluence/display/DOC/Orphan</url>
+                    </getPagesReturn>
+                </getPagesReturn></ns1:getPagesResponse>"#,
+            ),
+            (
+                "getPage",
+                r#"<ns1:getPageResponse><getPageReturn>
+                    <id xsi:type="xsd:long">42</id>
+                    <space xsi:type="xsd:string">DOC</space>
+                    <parentId xsi:type="xsd:long">0</parentId>
+                    <title xsi:type="xsd:string">Home</title>
+                    <url xsi:type="xsd:string">https://confluence/display/DOC/Home</url>
+                    <version xsi:type="xsd:int">3</version>
+                    <content xsi:type="xsd:string">&lt;p&gt;Hello&lt;/p&gt;</content>
+                    <created xsi:type="xsd:dateTime">2019-04-17T13:45:02.000Z</created>
+                    <creator xsi:type="xsd:string">admin</creator>
+                    <modified xsi:type="xsd:dateTime">2019-04-18T08:00:00.000Z</modified>
+                    <modifier xsi:type="xsd:string">admin</modifier>
+                    <homePage xsi:type="xsd:boolean">true</homePage>
+                    <contentStatus xsi:type="xsd:string">current</contentStatus>
+                    <current xsi:type="xsd:boolean">true</current>
+                </getPageReturn></ns1:getPageResponse>"#,
+            ),
+        ]))
+    }
+
+    #[test]
+    fn fetches_pages_of_space_lazily() {
+        let transport = transport();
+        let session = Session::builder("https://confluence")
+            .transport(transport.clone())
+            .bundled_wsdl()
+            .login("user", "pass")
+            .unwrap();
+
+        let mut pages = session.iter_pages("DOC").unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages.next().unwrap().unwrap().title, "Home");
+        assert_eq!(transport.actions(), vec!["login", "getPages", "getPage"]);
+        assert_eq!(pages.len(), 1);
+    }
+}
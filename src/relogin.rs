@@ -0,0 +1,52 @@
+//! Automatic re-login when the session token expires.
+
+use Error;
+
+/// Supplies credentials when the session has to log in again.
+///
+/// Implemented for closures returning `(user, pass)`.
+pub trait CredentialProvider: Send + Sync {
+    /// Returns `(user, pass)` to log in with.
+    fn credentials(&self) -> (String, String);
+}
+
+impl<F> CredentialProvider for F
+where
+    F: Fn() -> (String, String) + Send + Sync,
+{
+    fn credentials(&self) -> (String, String) {
+        self()
+    }
+}
+
+/// Credentials kept from the initial login.
+pub(crate) struct RetainedCredentials {
+    pub user: String,
+    pub pass: String,
+}
+
+impl CredentialProvider for RetainedCredentials {
+    fn credentials(&self) -> (String, String) {
+        (self.user.clone(), self.pass.clone())
+    }
+}
+
+/// Re-authentication event, passed to the hook set with `SessionBuilder::on_relogin`.
+#[derive(Debug)]
+pub struct ReloginEvent<'a> {
+    /// The method which failed because of the expired token.
+    pub method: &'a str,
+    /// The user the session logged in as.
+    pub user: &'a str,
+    /// Login error, if logging in again has failed.
+    pub error: Option<&'a Error>,
+}
+
+/// Callback observing re-authentication events.
+pub(crate) type ReloginHook = Box<dyn Fn(&ReloginEvent) + Send + Sync>;
+
+/// Re-login settings of a session.
+pub(crate) struct Relogin {
+    pub credentials: Box<dyn CredentialProvider>,
+    pub hook: Option<ReloginHook>,
+}
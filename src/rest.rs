@@ -0,0 +1,623 @@
+//! Page and space operations over the REST API at `/rest/api`.
+//!
+//! Used by sessions created with `Backend::Rest`, for Confluence versions
+//! which no longer have the SOAP and XML-RPC endpoints.
+
+use chrono::{DateTime, Utc};
+use serde_json::{self, Value};
+use std::convert::TryFrom;
+use std::fmt;
+
+use http::{Method, StatusCode};
+use rpser;
+use {FaultKind, Page, PageSummary, PageUpdateOptions, Result, Session, Space, UpdatePage};
+
+/// Path of the content resource, relative to the confluence base url.
+pub const CONTENT_PATH: &str = "/rest/api/content";
+
+/// Path of the space resource, relative to the confluence base url.
+pub const SPACE_PATH: &str = "/rest/api/space";
+
+/// Expansions needed to fill every field of `Page`.
+const PAGE_EXPAND: &str = "space.homepage,version,body.storage,ancestors,history";
+
+/// Number of children requested per page of results.
+const CHILDREN_LIMIT: &str = "200";
+
+/// REST API error response or unexpected JSON.
+#[derive(Debug, PartialEq)]
+pub enum RestError {
+    /// The server responded with an error status, such as `404 Not Found`.
+    Status {
+        status: StatusCode,
+        message: String,
+        kind: FaultKind,
+    },
+    /// The response body is not JSON.
+    InvalidJson { message: String },
+    /// The response lacks a field, or it has an unexpected type.
+    MissingField { pointer: String },
+    /// The number in the field does not fit the type of the decoded value.
+    OutOfRange { pointer: String, value: i64 },
+    /// The request url could not be built from the base url.
+    InvalidUrl { url: String, message: String },
+}
+
+impl RestError {
+    /// Create the error for an error status of the request to `path`, classifying it like a SOAP fault.
+    ///
+    /// The status decides the kind, and `404 Not Found` is told apart by the resource
+    /// at `path` (`SPACE_PATH` or `CONTENT_PATH`). The message is only matched for the
+    /// statuses which do not tell the failure apart, such as `400 Bad Request`.
+    pub fn from_status(path: &str, status: StatusCode, message: String) -> RestError {
+        let kind = match status {
+            StatusCode::UNAUTHORIZED => FaultKind::AuthenticationFailed,
+            StatusCode::FORBIDDEN => FaultKind::NotPermitted,
+            StatusCode::NOT_FOUND if path.starts_with(SPACE_PATH) => FaultKind::SpaceNotFound,
+            StatusCode::NOT_FOUND => FaultKind::PageNotFound,
+            StatusCode::CONFLICT => FaultKind::VersionConflict,
+            StatusCode::BAD_REQUEST if message.contains("already exists") => {
+                FaultKind::DuplicateTitle
+            }
+            _ if rpser::is_already_deleted(&message.to_lowercase()) => FaultKind::AlreadyDeleted,
+            _ => FaultKind::Remote,
+        };
+
+        RestError::Status {
+            status,
+            message,
+            kind,
+        }
+    }
+
+    /// Kind of the failure, if the server responded with an error status.
+    pub fn fault_kind(&self) -> Option<FaultKind> {
+        match *self {
+            RestError::Status { kind, .. } => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RestError::Status {
+                status,
+                ref message,
+                ..
+            } => write!(f, "{}: {}", status, message),
+            RestError::InvalidJson { ref message } => write!(f, "invalid JSON: {}", message),
+            RestError::MissingField { ref pointer } => write!(f, "missing field {}", pointer),
+            RestError::OutOfRange { ref pointer, value } => {
+                write!(f, "field {} is out of range: {}", pointer, value)
+            }
+            RestError::InvalidUrl {
+                ref url,
+                ref message,
+            } => write!(f, "invalid url {}: {}", url, message),
+        }
+    }
+}
+
+pub(crate) fn get_space(session: &Session, space_key: &str) -> Result<Space> {
+    let json = session.send_rest(
+        "getSpace",
+        Method::GET,
+        &format!("{}/{}", SPACE_PATH, space_key),
+        &[("expand", "description.plain,homepage")],
+        None,
+    )?;
+
+    space(&json, &session.url)
+}
+
+pub(crate) fn get_page_by_id(session: &Session, page_id: i64) -> Result<Page> {
+    let json = session.send_rest(
+        "getPage",
+        Method::GET,
+        &format!("{}/{}", CONTENT_PATH, page_id),
+        &[("expand", PAGE_EXPAND)],
+        None,
+    )?;
+
+    page(&json, &session.url)
+}
+
+pub(crate) fn get_page_by_title(session: &Session, space_key: &str, title: &str) -> Result<Page> {
+    let json = session.send_rest(
+        "getPage",
+        Method::GET,
+        CONTENT_PATH,
+        &[
+            ("type", "page"),
+            ("spaceKey", space_key),
+            ("title", title),
+            ("expand", PAGE_EXPAND),
+        ],
+        None,
+    )?;
+
+    match json.pointer("/results/0") {
+        Some(found) => page(found, &session.url),
+        None => Err(RestError::from_status(
+            CONTENT_PATH,
+            StatusCode::NOT_FOUND,
+            format!("page {:?} does not exist in space {}", title, space_key),
+        )
+        .into()),
+    }
+}
+
+pub(crate) fn store_page(
+    session: &Session,
+    page: UpdatePage,
+    options: Option<PageUpdateOptions>,
+) -> Result<Page> {
+    let (action, method, path) = match page.id {
+        None => ("storePage", Method::POST, CONTENT_PATH.to_string()),
+        Some(id) => (
+            if options.is_some() {
+                "updatePage"
+            } else {
+                "storePage"
+            },
+            Method::PUT,
+            format!("{}/{}", CONTENT_PATH, id),
+        ),
+    };
+
+    let json = session.send_rest(
+        action,
+        method,
+        &path,
+        &[("expand", PAGE_EXPAND)],
+        Some(&page_json(&page, options.as_ref())),
+    )?;
+
+    self::page(&json, &session.url)
+}
+
+/// Restore the trashed page, by storing its next version with the `current` status.
+pub(crate) fn restore_page(session: &Session, page_id: i64) -> Result<Page> {
+    let path = format!("{}/{}", CONTENT_PATH, page_id);
+
+    let trashed = session.send_rest(
+        "restorePage",
+        Method::GET,
+        &path,
+        &[("status", "trashed"), ("expand", "version")],
+        None,
+    )?;
+
+    let json = session.send_rest(
+        "restorePage",
+        Method::PUT,
+        &path,
+        &[("expand", PAGE_EXPAND)],
+        Some(&json!({
+            "id": page_id.to_string(),
+            "type": string(&trashed, "/type")?,
+            "title": string(&trashed, "/title")?,
+            "status": "current",
+            "version": { "number": long(&trashed, "/version/number")? + 1 },
+        })),
+    )?;
+
+    page(&json, &session.url)
+}
+
+pub(crate) fn get_children(session: &Session, page_id: i64) -> Result<Vec<PageSummary>> {
+    let mut summaries = vec![];
+    let mut start = 0;
+
+    loop {
+        let start_param = start.to_string();
+        let json = session.send_rest(
+            "getChildren",
+            Method::GET,
+            &format!("{}/{}/child/page", CONTENT_PATH, page_id),
+            &[
+                ("expand", "space"),
+                ("limit", CHILDREN_LIMIT),
+                ("start", &start_param),
+            ],
+            None,
+        )?;
+
+        let results = json
+            .get("results")
+            .and_then(Value::as_array)
+            .ok_or_else(|| missing("/results"))?;
+        for child in results {
+            summaries.push(PageSummary {
+                id: long(child, "/id")?,
+                space: string(child, "/space/key")?,
+                parent_id: page_id,
+                title: string(child, "/title")?,
+                url: web_url(child, &session.url)?,
+            });
+        }
+
+        if results.is_empty() || json.pointer("/_links/next").is_none() {
+            return Ok(summaries);
+        }
+        start += results.len();
+    }
+}
+
+/// Build the request body for creating or updating the page.
+///
+/// The REST API expects the number of the new version, so the version of the
+/// stored page is incremented.
+fn page_json(page: &UpdatePage, options: Option<&PageUpdateOptions>) -> Value {
+    let mut json = json!({
+        "type": "page",
+        "title": page.title,
+        "space": { "key": page.space },
+        "body": {
+            "storage": { "value": page.content, "representation": "storage" }
+        },
+    });
+
+    if let Some(id) = page.id {
+        json["id"] = json!(id.to_string());
+    }
+
+    if let Some(parent_id) = page.parent_id {
+        json["ancestors"] = json!([{ "id": parent_id.to_string() }]);
+    }
+
+    if let Some(version) = page.version {
+        json["version"] = json!({ "number": version + 1 });
+
+        if let Some(options) = options {
+            json["version"]["minorEdit"] = json!(options.minor_edit);
+            if let Some(ref comment) = options.version_comment {
+                json["version"]["message"] = json!(comment);
+            }
+        }
+    }
+
+    json
+}
+
+fn page(json: &Value, base_url: &str) -> Result<Page> {
+    let id = string(json, "/id")?;
+    let status = string(json, "/status")?;
+
+    Ok(Page {
+        id: long(json, "/id")?,
+        space: string(json, "/space/key")?,
+        parent_id: match json.pointer("/ancestors").and_then(Value::as_array) {
+            Some(ancestors) if !ancestors.is_empty() => {
+                long(&ancestors[ancestors.len() - 1], "/id")?
+            }
+            _ => 0,
+        },
+        title: string(json, "/title")?,
+        url: web_url(json, base_url)?,
+        version: int(json, "/version/number")?,
+        content: string(json, "/body/storage/value")?,
+        created: datetime(json, "/history/createdDate")?,
+        creator: user(json, "/history/createdBy")?,
+        modified: datetime(json, "/version/when")?,
+        modifier: user(json, "/version/by")?,
+        home_page: json.pointer("/space/homepage/id").and_then(Value::as_str) == Some(&id),
+        current: status == "current",
+        content_status: status,
+    })
+}
+
+fn space(json: &Value, base_url: &str) -> Result<Space> {
+    Ok(Space {
+        key: string(json, "/key")?,
+        name: string(json, "/name")?,
+        space_group: None,
+        space_type: string(json, "/type")?,
+        url: web_url(json, base_url)?,
+        home_page: match json.pointer("/homepage") {
+            Some(_) => long(json, "/homepage/id")?,
+            None => 0,
+        },
+        description: json
+            .pointer("/description/plain/value")
+            .and_then(Value::as_str)
+            .map(String::from),
+    })
+}
+
+/// Parse the body of a successful response, `null` if it is empty.
+pub(crate) fn parse_body(body: &str) -> Result<Value> {
+    if body.trim().is_empty() {
+        return Ok(Value::Null);
+    }
+
+    serde_json::from_str(body).map_err(|e| {
+        RestError::InvalidJson {
+            message: e.to_string(),
+        }
+        .into()
+    })
+}
+
+/// Message of an error response, such as `{"statusCode":404,"message":"No content found"}`.
+pub(crate) fn error_message(body: &str) -> Option<String> {
+    serde_json::from_str::<Value>(body)
+        .ok()?
+        .get("message")?
+        .as_str()
+        .map(String::from)
+}
+
+/// Absolute url of the `webui` link.
+fn web_url(json: &Value, base_url: &str) -> Result<String> {
+    let webui = string(json, "/_links/webui")?;
+
+    Ok([base_url.strip_suffix('/').unwrap_or(base_url), &webui].concat())
+}
+
+fn string(json: &Value, pointer: &str) -> Result<String> {
+    json.pointer(pointer)
+        .and_then(Value::as_str)
+        .map(String::from)
+        .ok_or_else(|| missing(pointer))
+}
+
+/// Ids are strings in the REST API, numbers are accepted too.
+fn long(json: &Value, pointer: &str) -> Result<i64> {
+    match json.pointer(pointer) {
+        Some(Value::String(s)) => s.parse().ok(),
+        Some(other) => other.as_i64(),
+        None => None,
+    }
+    .ok_or_else(|| missing(pointer))
+}
+
+/// Number which has to fit `i32`, such as the page version.
+fn int(json: &Value, pointer: &str) -> Result<i32> {
+    let value = long(json, pointer)?;
+
+    i32::try_from(value).map_err(|_| {
+        RestError::OutOfRange {
+            pointer: pointer.into(),
+            value,
+        }
+        .into()
+    })
+}
+
+fn datetime(json: &Value, pointer: &str) -> Result<DateTime<Utc>> {
+    json.pointer(pointer)
+        .and_then(Value::as_str)
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|d| d.with_timezone(&Utc))
+        .ok_or_else(|| missing(pointer))
+}
+
+/// Username of the user object, or the display name where usernames are hidden (Cloud).
+fn user(json: &Value, pointer: &str) -> Result<String> {
+    let user = json.pointer(pointer).ok_or_else(|| missing(pointer))?;
+
+    ["username", "publicName", "displayName"]
+        .iter()
+        .filter_map(|field| user.get(*field).and_then(Value::as_str))
+        .next()
+        .map(String::from)
+        .ok_or_else(|| missing(&format!("{}/username", pointer)))
+}
+
+fn missing(pointer: &str) -> ::Error {
+    RestError::MissingField {
+        pointer: pointer.into(),
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fixtures::CannedTransport;
+    use std::sync::Arc;
+    use {Backend, Error};
+
+    #[test]
+    fn reads_page_from_content() {
+        let mut json: Value = serde_json::from_str(
+            r#"{
+                "id": "42",
+                "type": "page",
+                "status": "current",
+                "title": "Home",
+                "space": { "key": "DOC", "homepage": { "id": "42" } },
+                "history": {
+                    "createdBy": { "username": "admin" },
+                    "createdDate": "2019-04-17T13:45:02.000Z"
+                },
+                "version": {
+                    "by": { "username": "editor" },
+                    "when": "2019-04-18T08:00:00.000+02:00",
+                    "number": 3
+                },
+                "ancestors": [{ "id": "1" }, { "id": "7" }],
+                "body": { "storage": { "value": "<p>Hello</p>", "representation": "storage" } },
+                "_links": { "webui": "/display/DOC/Home" }
+            }"#,
+        )
+        .unwrap();
+
+        let page = page(&json, "https://confluence/").unwrap();
+
+        assert_eq!(page.id, 42);
+        assert_eq!(page.parent_id, 7);
+        assert_eq!(page.version, 3);
+        assert_eq!(page.url, "https://confluence/display/DOC/Home");
+        assert_eq!(page.modifier, "editor");
+        assert_eq!(page.modified.to_rfc3339(), "2019-04-18T06:00:00+00:00");
+        assert!(page.home_page);
+        assert!(page.current);
+
+        json["version"]["number"] = json!(2_147_483_648i64);
+        match super::page(&json, "https://confluence/") {
+            Err(::Error::Rest(e)) => assert_eq!(
+                e,
+                RestError::OutOfRange {
+                    pointer: "/version/number".into(),
+                    value: 2_147_483_648,
+                }
+            ),
+            other => panic!("expected out of range version, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn writes_next_version_of_page() {
+        let page = UpdatePage {
+            id: Some(42),
+            space: "DOC".into(),
+            title: "Home".into(),
+            content: "<p>Hi</p>".into(),
+            version: Some(3),
+            parent_id: Some(7),
+        };
+
+        let json = page_json(
+            &page,
+            Some(&PageUpdateOptions::new_minor_with_comment("typo")),
+        );
+
+        assert_eq!(json["id"], "42");
+        assert_eq!(json["ancestors"][0]["id"], "7");
+        assert_eq!(json["version"]["number"], 4);
+        assert_eq!(json["version"]["minorEdit"], true);
+        assert_eq!(json["version"]["message"], "typo");
+        assert_eq!(json["body"]["storage"]["value"], "<p>Hi</p>");
+    }
+
+    #[test]
+    fn classifies_error_statuses() {
+        let error = RestError::from_status(
+            "/rest/api/content/42",
+            StatusCode::NOT_FOUND,
+            "No content found".into(),
+        );
+
+        assert_eq!(error.fault_kind(), Some(FaultKind::PageNotFound));
+        assert_eq!(
+            RestError::from_status(
+                "/rest/api/space/NONE",
+                StatusCode::NOT_FOUND,
+                "No space with key : NONE".into()
+            )
+            .fault_kind(),
+            Some(FaultKind::SpaceNotFound)
+        );
+        assert_eq!(
+            RestError::from_status(
+                "/rest/api/content/42",
+                StatusCode::CONFLICT,
+                "Content 42 has already been deleted".into()
+            )
+            .fault_kind(),
+            Some(FaultKind::VersionConflict)
+        );
+        assert_eq!(
+            RestError::from_status(
+                "/rest/api/content/42",
+                StatusCode::BAD_REQUEST,
+                "Content 42 has already been deleted".into()
+            )
+            .fault_kind(),
+            Some(FaultKind::AlreadyDeleted)
+        );
+        assert_eq!(
+            error_message(r#"{"statusCode":404,"message":"No content found"}"#),
+            Some("No content found".into())
+        );
+    }
+
+    fn content(version: i32) -> String {
+        json!({
+            "id": "42",
+            "type": "page",
+            "status": "current",
+            "title": "Home",
+            "space": { "key": "DOC", "homepage": { "id": "42" } },
+            "history": {
+                "createdBy": { "username": "admin" },
+                "createdDate": "2019-04-17T13:45:02.000Z"
+            },
+            "version": {
+                "by": { "username": "admin" },
+                "when": "2019-04-18T08:00:00.000Z",
+                "number": version
+            },
+            "ancestors": [],
+            "body": { "storage": { "value": "<p>Hello</p>" } },
+            "_links": { "webui": "/display/DOC/Home" }
+        })
+        .to_string()
+    }
+
+    /// Transport which serves the version 3 of the page 42 over the REST API, and
+    /// stores its version 4.
+    fn transport() -> Arc<CannedTransport> {
+        Arc::new(
+            CannedTransport::new(&[])
+                .with_json("GET /rest/api/content/42", StatusCode::OK, &content(3))
+                .with_json("PUT /rest/api/content/42", StatusCode::OK, &content(4))
+                .with_json(
+                    "GET /rest/api/space/NONE",
+                    StatusCode::NOT_FOUND,
+                    r#"{"statusCode":404,"message":"No space with key : NONE"}"#,
+                ),
+        )
+    }
+
+    #[test]
+    fn calls_rest_api() {
+        let transport = transport();
+
+        {
+            let session = Session::builder("https://confluence")
+                .transport(transport.clone())
+                .backend(Backend::Rest)
+                .login("user", "pass")
+                .unwrap();
+
+            let page = session.get_page_by_id(42).unwrap();
+            assert_eq!(page.version, 3);
+            assert_eq!(page.parent_id, 0);
+            assert_eq!(page.url, "https://confluence/display/DOC/Home");
+
+            let stored = session.store_page(page.into()).unwrap();
+            assert_eq!(stored.version, 4);
+            assert!(session.restore_page(42).unwrap().current);
+
+            match session.get_space("NONE") {
+                Err(ref e) if e.fault_kind() == Some(FaultKind::SpaceNotFound) => {}
+                other => panic!("expected not found error, received {:?}", other),
+            }
+
+            match session.get_spaces() {
+                Err(Error::Unsupported {
+                    ref method,
+                    backend: Backend::Rest,
+                }) if method == "getSpaces" => {}
+                other => panic!("expected unsupported error, received {:?}", other),
+            }
+        }
+
+        assert_eq!(transport.wsdl_requests(), 0);
+        assert_eq!(
+            transport.actions(),
+            vec![
+                "GET /rest/api/content/42",
+                "PUT /rest/api/content/42",
+                "GET /rest/api/content/42",
+                "PUT /rest/api/content/42",
+                "GET /rest/api/space/NONE"
+            ]
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! Retrying of calls which failed because of transient errors.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use http::{HttpError, StatusCode};
+use Error;
+
+/// Methods of `confluenceservice-v2` which only read data, retried by default.
+const READ_ONLY_METHODS: &[&str] = &[
+    "getActiveUsers",
+    "getAncestors",
+    "getAttachment",
+    "getAttachmentData",
+    "getAttachments",
+    "getBlogEntries",
+    "getBlogEntry",
+    "getBlogEntryByDateAndTitle",
+    "getBlogEntryByDayAndTitle",
+    "getChildren",
+    "getClusterInformation",
+    "getClusterNodeStatuses",
+    "getComment",
+    "getComments",
+    "getContentPermissionSet",
+    "getContentPermissionSets",
+    "getDescendents",
+    "getGroups",
+    "getLabelContentById",
+    "getLabelContentByName",
+    "getLabelContentByObject",
+    "getLabelsByDetail",
+    "getLabelsById",
+    "getMostPopularLabels",
+    "getMostPopularLabelsInSpace",
+    "getPage",
+    "getPageHistory",
+    "getPagePermissions",
+    "getPageSummary",
+    "getPages",
+    "getPermissions",
+    "getPermissionsForUser",
+    "getRecentlyUsedLabels",
+    "getRecentlyUsedLabelsInSpace",
+    "getRelatedLabels",
+    "getRelatedLabelsInSpace",
+    "getServerInfo",
+    "getSpace",
+    "getSpaceLevelPermissions",
+    "getSpacePermissionSet",
+    "getSpacePermissionSets",
+    "getSpaceStatus",
+    "getSpaces",
+    "getSpacesContainingContentWithLabel",
+    "getSpacesInGroup",
+    "getSpacesWithLabel",
+    "getTrashContents",
+    "getUser",
+    "getUserGroups",
+    "getUserInformation",
+    "getUserPreferenceBoolean",
+    "getUserPreferenceLong",
+    "getUserPreferenceString",
+    "getWatchersForPage",
+    "getWatchersForSpace",
+    "hasGroup",
+    "hasUser",
+    "isActiveUser",
+    "isDarkFeatureEnabled",
+    "isPluginEnabled",
+    "isPluginInstalled",
+    "isWatchingPage",
+    "isWatchingSpace",
+    "renderContent",
+    "search",
+];
+
+/// Check if the SOAP or XML-RPC method only reads data, so repeating it is safe.
+pub(crate) fn is_read_only(method: &str) -> bool {
+    READ_ONLY_METHODS.contains(&method)
+}
+
+/// Class of a failure which may go away when the call is repeated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetryableError {
+    /// Connection could not be established or was reset.
+    Connection,
+    /// Request timed out.
+    Timeout,
+    /// Server (or proxy in front of it) responded with a 5xx status instead of a SOAP response.
+    ServerError,
+    /// Server responded with `429 Too Many Requests`.
+    Throttled,
+}
+
+impl RetryableError {
+    /// Find the class of the error, `None` if repeating the call can not help.
+    pub fn classify(error: &Error) -> Option<RetryableError> {
+        match *error {
+            Error::Http(HttpError::Request(ref e)) if e.is_timeout() => {
+                Some(RetryableError::Timeout)
+            }
+            Error::Http(HttpError::Request(ref e))
+                if e.status().is_none() && !e.is_redirect() && !e.is_serialization() =>
+            {
+                Some(RetryableError::Connection)
+            }
+            Error::Http(HttpError::Body { .. }) => Some(RetryableError::Connection),
+            Error::UnexpectedResponse { status, .. } if status == StatusCode::TOO_MANY_REQUESTS => {
+                Some(RetryableError::Throttled)
+            }
+            Error::UnexpectedResponse { status, .. } if status.is_server_error() => {
+                Some(RetryableError::ServerError)
+            }
+            _ => None,
+        }
+    }
+}
+
+/**
+Policy for repeating calls which failed because of transient errors.
+
+Only idempotent calls are retried: the methods of the v2 API which only read data,
+such as `getPage` or `search`, REST API `GET` requests, and any method allowed with
+`allow_method`. Other methods, such as `storePage`, are retried only with
+`retry_non_idempotent`.
+
+The delay before the retry grows exponentially from `initial_backoff` up to `max_backoff`,
+and is reduced by a random amount up to the `jitter` fraction.
+
+## Example
+
+```no_run
+use confluence::{RetryPolicy, Session};
+use std::time::Duration;
+
+let session = Session::builder("https://confluence")
+    .retry_policy(
+        RetryPolicy::new()
+            .max_attempts(5)
+            .backoff(Duration::from_millis(200), Duration::from_secs(10))
+            .allow_method("storePage"),
+    )
+    .login("user", "pass")
+    .unwrap();
+```
+*/
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: u32,
+    jitter: f64,
+    retry_on: HashSet<RetryableError>,
+    allowed_methods: HashSet<String>,
+    retry_non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// Policy with 3 attempts, 500ms initial backoff doubling up to 30s, and 20% jitter,
+    /// retrying on all `RetryableError` classes.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2,
+            jitter: 0.2,
+            retry_on: [
+                RetryableError::Connection,
+                RetryableError::Timeout,
+                RetryableError::ServerError,
+                RetryableError::Throttled,
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+            allowed_methods: HashSet::new(),
+            retry_non_idempotent: false,
+        }
+    }
+
+    /// Policy which never retries.
+    pub fn never() -> RetryPolicy {
+        RetryPolicy::new().max_attempts(1)
+    }
+
+    /// Maximum number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry, and the upper bound of the delay.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+        self
+    }
+
+    /// Factor the delay is multiplied by after each retry.
+    pub fn multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Fraction (from 0 to 1) of the delay which is randomly cut off.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Only retry on the given classes of errors.
+    pub fn retry_on(mut self, errors: &[RetryableError]) -> Self {
+        self.retry_on = errors.iter().cloned().collect();
+        self
+    }
+
+    /// Allow retrying the method even if it is not considered idempotent.
+    pub fn allow_method<S: Into<String>>(mut self, method: S) -> Self {
+        self.allowed_methods.insert(method.into());
+        self
+    }
+
+    /// Allow retrying all methods.
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    /// Check if the method which failed at given attempt (starting from 1) should be retried.
+    pub fn should_retry(&self, method: &str, error: &Error, attempt: u32) -> bool {
+        self.should_retry_call(method, is_read_only(method), error, attempt)
+    }
+
+    /// Check if the call should be retried, `idempotent` if repeating it is known to be safe.
+    pub(crate) fn should_retry_call(
+        &self,
+        method: &str,
+        idempotent: bool,
+        error: &Error,
+        attempt: u32,
+    ) -> bool {
+        attempt < self.max_attempts
+            && (idempotent || self.may_retry_method(method))
+            && RetryableError::classify(error).is_some_and(|class| self.retry_on.contains(&class))
+    }
+
+    /// Delay before the next attempt, after the given attempt (starting from 1) has failed.
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let mut delay = self.initial_backoff;
+        for _ in 1..attempt {
+            delay = delay
+                .checked_mul(self.multiplier)
+                .unwrap_or(self.max_backoff);
+            if delay >= self.max_backoff {
+                break;
+            }
+        }
+        let delay = delay.min(self.max_backoff);
+
+        if self.jitter > 0.0 {
+            delay.mul_f64(1.0 - self.jitter * random_fraction())
+        } else {
+            delay
+        }
+    }
+
+    /// Delay before retrying the call which failed with the error.
+    ///
+    /// The delay requested by the server with `Retry-After` is used when it is longer
+    /// than the backoff.
+    pub(crate) fn retry_delay(&self, error: &Error, attempt: u32) -> Duration {
+        let backoff = self.backoff_delay(attempt);
+
+        match *error {
+            Error::UnexpectedResponse {
+                retry_after: Some(retry_after),
+                ..
+            } => retry_after.max(backoff),
+            _ => backoff,
+        }
+    }
+
+    fn may_retry_method(&self, method: &str) -> bool {
+        self.retry_non_idempotent || self.allowed_methods.contains(method)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy::new()
+    }
+}
+
+/// Random value in `[0, 1)`, good enough for jitter.
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u8(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn server_error() -> Error {
+        Error::UnexpectedResponse {
+            status: StatusCode::BAD_GATEWAY,
+            body_excerpt: String::new(),
+            retry_after: None,
+        }
+    }
+
+    #[test]
+    fn backoff_grows_up_to_max() {
+        let policy = RetryPolicy::new()
+            .backoff(Duration::from_millis(100), Duration::from_millis(500))
+            .jitter(0.0);
+
+        assert_eq!(policy.backoff_delay(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff_delay(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff_delay(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff_delay(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff_delay(40), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn jitter_only_shortens_delay() {
+        let policy = RetryPolicy::new()
+            .backoff(Duration::from_millis(100), Duration::from_millis(100))
+            .jitter(0.5);
+
+        for _ in 0..100 {
+            let delay = policy.backoff_delay(1);
+            assert!(delay <= Duration::from_millis(100));
+            assert!(delay >= Duration::from_millis(50));
+        }
+    }
+
+    #[test]
+    fn retries_only_idempotent_methods_by_default() {
+        let policy = RetryPolicy::new();
+
+        assert!(policy.should_retry("getPage", &server_error(), 1));
+        assert!(policy.should_retry("getChildren", &server_error(), 2));
+        assert!(!policy.should_retry("getPage", &server_error(), 3));
+        assert!(!policy.should_retry("storePage", &server_error(), 1));
+        assert!(policy.should_retry("hasUser", &server_error(), 1));
+        assert!(!policy.should_retry("getCustomCounter", &server_error(), 1));
+        assert!(policy.should_retry_call("restorePage", true, &server_error(), 1));
+        assert!(!policy.should_retry_call("restorePage", false, &server_error(), 1));
+        assert!(policy
+            .allow_method("storePage")
+            .should_retry("storePage", &server_error(), 1));
+    }
+
+    #[test]
+    fn does_not_retry_faults() {
+        let policy = RetryPolicy::new().retry_non_idempotent(true);
+
+        assert!(!policy.should_retry("getPage", &Error::ReceivedNoLoginToken, 1));
+        assert!(!policy.retry_on(&[RetryableError::Timeout]).should_retry(
+            "getPage",
+            &server_error(),
+            1
+        ));
+    }
+}
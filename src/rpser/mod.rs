@@ -1,5 +1,6 @@
 //! Remote procedule call implementation and serialization to XML.
 
+pub mod value;
 pub mod xml;
 
 use std::fmt;
@@ -38,6 +39,9 @@ impl Method {
 
         let envelope = Element::node("soap:Envelope")
             .with_attr("xmlns:soap", "http://schemas.xmlsoap.org/soap/envelope/")
+            .with_attr("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance")
+            .with_attr("xmlns:xsd", "http://www.w3.org/2001/XMLSchema")
+            .with_attr("xmlns:soapenc", "http://schemas.xmlsoap.org/soap/encoding/")
             .with_attr(format!("xmlns:{}", namespace), api_url)
             .with_children(vec![
                 Element::node("soap:Header"),
@@ -61,7 +65,14 @@ impl Response {
     /// Parse response from XML.
     pub fn from_xml(xml: &str) -> Result<Response> {
         let mut bytes = xml.as_bytes();
-        let mut element = Element::parse(&mut bytes).unwrap();
+        let mut element = match Element::parse(&mut bytes) {
+            Ok(element) => element,
+            Err(e) => {
+                return Err(RpcError::MalformedXml {
+                    message: format!("{:?}", e),
+                })
+            }
+        };
 
         if element.name != "Envelope" {
             return Err(RpcError::UnexpectedElement { tag: element.name });
@@ -70,14 +81,18 @@ impl Response {
         element = try!(element.descend_first());
 
         if element.name == "Fault" {
+            let fault_string = try!(element.get_at_path(&["faultstring"]))
+                .text
+                .unwrap_or_default();
+            let fault_detail = try!(element.get_at_path(&["detail"]));
+
             return Err(RpcError::Fault {
                 fault_code: try!(element.get_at_path(&["faultcode"]))
                     .text
                     .unwrap_or_default(),
-                fault_string: try!(element.get_at_path(&["faultstring"]))
-                    .text
-                    .unwrap_or_default(),
-                fault_detail: Box::new(try!(element.get_at_path(&["detail"]))),
+                kind: FaultKind::classify(&fault_string, &fault_detail),
+                fault_string,
+                fault_detail: Box::new(fault_detail),
             });
         }
 
@@ -91,6 +106,100 @@ impl fmt::Display for Method {
     }
 }
 
+/// Kind of the remote exception reported in a fault.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultKind {
+    /// Wrong username or password.
+    AuthenticationFailed,
+    /// The token has expired or was never valid.
+    InvalidSession,
+    /// The user is not allowed to perform the operation.
+    NotPermitted,
+    /// The page does not exist (or is not visible to the user).
+    PageNotFound,
+    /// The space does not exist (or is not visible to the user).
+    SpaceNotFound,
+    /// The page was modified since the version being stored.
+    VersionConflict,
+    /// A page with the same title already exists in the space.
+    DuplicateTitle,
+    /// The page has already been deleted, and is in the trash or purged.
+    AlreadyDeleted,
+    /// Any other `RemoteException`.
+    Remote,
+    /// The fault does not look like a Confluence exception.
+    Unknown,
+}
+
+impl FaultKind {
+    /**
+    Classify the fault by the exception class found in fault detail or fault string.
+
+    Confluence reports most failures, such as a missing page, with the generic
+    `RemoteException`. Only for that class the message is matched against the
+    phrases of the English messages, and a message which is not recognized
+    (for example a localized one) is classified as `FaultKind::Remote`.
+    */
+    pub fn classify(fault_string: &str, fault_detail: &Element) -> FaultKind {
+        let exception = fault_detail
+            .children
+            .iter()
+            .map(|child| child.name.as_str())
+            .find(|name| name.ends_with("Exception"))
+            .or_else(|| {
+                fault_string
+                    .split(':')
+                    .next()
+                    .filter(|name| name.ends_with("Exception"))
+            });
+
+        let exception = match exception {
+            Some(exception) => exception.rsplit('.').next().unwrap_or(exception),
+            None => return FaultKind::Unknown,
+        };
+
+        match exception {
+            "AuthenticationFailedException" => FaultKind::AuthenticationFailed,
+            "InvalidSessionException" => FaultKind::InvalidSession,
+            "NotPermittedException" => FaultKind::NotPermitted,
+            "VersionMismatchException" => FaultKind::VersionConflict,
+            "RemoteException" => classify_remote_message(&fault_string.to_lowercase()),
+            _ => FaultKind::Unknown,
+        }
+    }
+}
+
+/// Kind of a `RemoteException`, told by the lowercase message.
+fn classify_remote_message(message: &str) -> FaultKind {
+    let says = |phrases: &[&str]| phrases.iter().any(|phrase| message.contains(phrase));
+
+    if is_already_deleted(message) {
+        FaultKind::AlreadyDeleted
+    } else if says(&["already exists with the title", "page already exists"]) {
+        FaultKind::DuplicateTitle
+    } else if says(&["outdated version", "version conflict"]) {
+        FaultKind::VersionConflict
+    } else if says(&[
+        "that page, or it does not exist",
+        "page does not exist",
+        "page not found",
+        "no page found",
+    ]) {
+        FaultKind::PageNotFound
+    } else if says(&["no space found", "space does not exist", "space not found"]) {
+        FaultKind::SpaceNotFound
+    } else {
+        FaultKind::Remote
+    }
+}
+
+/// Whether the lowercase message says the content was deleted before.
+pub(crate) fn is_already_deleted(message: &str) -> bool {
+    message.contains("already deleted")
+        || message.contains("already been deleted")
+        || message.contains("already in the trash")
+}
+
 /// Method parsing / response error.
 #[derive(Debug, PartialEq)]
 pub enum RpcError {
@@ -98,10 +207,14 @@ pub enum RpcError {
         fault_code: String,
         fault_string: String,
         fault_detail: Box<Element>,
+        kind: FaultKind,
     },
     XmlError {
         error: self::xml::Error,
     },
+    MalformedXml {
+        message: String,
+    },
     ExpectedElementText {
         tag: String,
     },
@@ -116,6 +229,16 @@ pub enum RpcError {
     },
 }
 
+impl RpcError {
+    /// Kind of the remote exception, if this error is a fault.
+    pub fn fault_kind(&self) -> Option<FaultKind> {
+        match *self {
+            RpcError::Fault { kind, .. } => Some(kind),
+            _ => None,
+        }
+    }
+}
+
 impl From<self::xml::Error> for RpcError {
     fn from(other: self::xml::Error) -> RpcError {
         RpcError::XmlError { error: other }
@@ -150,8 +273,10 @@ mod test {
             Err(RpcError::Fault {
                 fault_code,
                 fault_string,
+                kind,
                 ..
             }) => {
+                assert_eq!(kind, FaultKind::AuthenticationFailed);
                 assert_eq!(fault_code, "soapenv:Server.userException");
                 assert_eq!(fault_string, "com.atlassian.confluence.rpc.AuthenticationFailedException: Attempt to log in user 'ADUser' failed - incorrect username/password combination.");
             }
@@ -162,6 +287,107 @@ mod test {
         };
     }
 
+    #[test]
+    fn classifies_remote_exceptions_by_message() {
+        let detail = Element::node("detail").with_child(Element::node(
+            "com.atlassian.confluence.rpc.RemoteException",
+        ));
+
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.RemoteException: You're not allowed to view that page, or it does not exist.",
+                &detail
+            ),
+            FaultKind::PageNotFound
+        );
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.RemoteException: Unsupported operation",
+                &detail
+            ),
+            FaultKind::Remote
+        );
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.RemoteException: A page already exists with the title Home in the space with key DOC",
+                &detail
+            ),
+            FaultKind::DuplicateTitle
+        );
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.RemoteException: No space found for space key: NONE",
+                &detail
+            ),
+            FaultKind::SpaceNotFound
+        );
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.RemoteException: The page 42 has already been deleted",
+                &detail
+            ),
+            FaultKind::AlreadyDeleted
+        );
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.NotPermittedException: You're not allowed to edit that page",
+                &Element::node("detail")
+            ),
+            FaultKind::NotPermitted
+        );
+        assert_eq!(
+            FaultKind::classify("Internal error", &Element::node("detail")),
+            FaultKind::Unknown
+        );
+    }
+
+    #[test]
+    fn matches_messages_only_of_remote_exceptions() {
+        let detail = |exception: &str| Element::node("detail").with_child(Element::node(exception));
+
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.RemoteException: Attachment not found in the index",
+                &detail("com.atlassian.confluence.rpc.RemoteException")
+            ),
+            FaultKind::Remote
+        );
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.RemoteException: Seite wurde nicht gefunden",
+                &detail("com.atlassian.confluence.rpc.RemoteException")
+            ),
+            FaultKind::Remote
+        );
+        assert_eq!(
+            FaultKind::classify(
+                "java.lang.IllegalStateException: page does not exist",
+                &detail("java.lang.IllegalStateException")
+            ),
+            FaultKind::Unknown
+        );
+        assert_eq!(
+            FaultKind::classify(
+                "com.atlassian.confluence.rpc.InvalidSessionException: The page has already been deleted",
+                &detail("com.atlassian.confluence.rpc.InvalidSessionException")
+            ),
+            FaultKind::InvalidSession
+        );
+    }
+
+    #[test]
+    fn reports_non_xml_response() {
+        match Response::from_xml("<html><body>Proxy Error") {
+            Err(RpcError::MalformedXml { .. }) => {}
+            other => panic!("expected malformed xml error, received {:?}", other),
+        };
+
+        match Response::from_xml("<html><body>Please log in</body></html>") {
+            Err(RpcError::UnexpectedElement { tag }) => assert_eq!(tag, "html"),
+            other => panic!("expected unexpected element error, received {:?}", other),
+        };
+    }
+
     #[test]
     fn returns_result_element() {
         let ok_response = r#"<?xml version="1.0" encoding="utf-8"?>
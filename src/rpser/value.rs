@@ -0,0 +1,195 @@
+//! Conversion of typed values to and from SOAP encoded elements.
+//!
+//! Used by the bindings generated with `wsdl::codegen`.
+
+use chrono::{DateTime, Utc};
+use xmltree::Element;
+
+use super::xml::{BuildElement, Error};
+use Result;
+
+/// Value which can be sent as a method argument or read from a response.
+pub trait SoapValue {
+    /// Read the value from the element.
+    fn from_soap(element: &Element) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Write the value as an element with the given name.
+    fn to_soap(&self, name: &str) -> Element;
+}
+
+/// Read the optional field of a complex value, `None` if it is missing or nil.
+pub fn field<T: SoapValue>(element: &Element, name: &str) -> Result<Option<T>> {
+    match element.get_child(name) {
+        Some(child) if child.attributes.get("nil").map(String::as_str) != Some("true") => {
+            Ok(Some(T::from_soap(child)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Write the items as an array element with the given name.
+pub fn array<T: SoapValue>(name: &str, items: &[T]) -> Element {
+    Element::node(name)
+        .with_attr("xsi:type", "soapenc:Array")
+        .with_children(items.iter().map(|item| item.to_soap("item")))
+}
+
+impl SoapValue for String {
+    fn from_soap(element: &Element) -> Result<String> {
+        Ok(element.text.clone().unwrap_or_default())
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        Element::node(name)
+            .with_attr("xsi:type", "xsd:string")
+            .with_text(self.as_str())
+    }
+}
+
+impl SoapValue for i64 {
+    fn from_soap(element: &Element) -> Result<i64> {
+        text(element).parse().map_err(|inner| {
+            Error::ParseIntError {
+                name: element.name.clone(),
+                inner,
+            }
+            .into()
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        Element::node(name)
+            .with_attr("xsi:type", "xsd:long")
+            .with_text(self.to_string())
+    }
+}
+
+impl SoapValue for i32 {
+    fn from_soap(element: &Element) -> Result<i32> {
+        text(element).parse().map_err(|inner| {
+            Error::ParseIntError {
+                name: element.name.clone(),
+                inner,
+            }
+            .into()
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        Element::node(name)
+            .with_attr("xsi:type", "xsd:int")
+            .with_text(self.to_string())
+    }
+}
+
+impl SoapValue for bool {
+    fn from_soap(element: &Element) -> Result<bool> {
+        match text(element) {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(unexpected(element, "boolean")),
+        }
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        Element::node(name)
+            .with_attr("xsi:type", "xsd:boolean")
+            .with_text(if *self { "true" } else { "false" })
+    }
+}
+
+impl SoapValue for f64 {
+    fn from_soap(element: &Element) -> Result<f64> {
+        text(element)
+            .parse()
+            .map_err(|_| unexpected(element, "double"))
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        Element::node(name)
+            .with_attr("xsi:type", "xsd:double")
+            .with_text(self.to_string())
+    }
+}
+
+impl SoapValue for DateTime<Utc> {
+    fn from_soap(element: &Element) -> Result<DateTime<Utc>> {
+        text(element).parse().map_err(|inner| {
+            Error::ParseDateTimeError {
+                name: element.name.clone(),
+                inner,
+            }
+            .into()
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        Element::node(name)
+            .with_attr("xsi:type", "xsd:dateTime")
+            .with_text(self.to_rfc3339())
+    }
+}
+
+impl SoapValue for Element {
+    fn from_soap(element: &Element) -> Result<Element> {
+        Ok(element.clone())
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        self.clone().with_name(name)
+    }
+}
+
+impl<T: SoapValue> SoapValue for Vec<T> {
+    fn from_soap(element: &Element) -> Result<Vec<T>> {
+        element.children.iter().map(T::from_soap).collect()
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        array(name, self)
+    }
+}
+
+fn text(element: &Element) -> &str {
+    element.text.as_deref().unwrap_or("").trim()
+}
+
+fn unexpected(element: &Element, expected_type: &str) -> ::Error {
+    Error::ExpectedElementWithType {
+        name: element.name.clone(),
+        expected_type: expected_type.into(),
+        given: element.text.clone(),
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reads_optional_fields() {
+        let page = Element::node("page")
+            .with_child(Element::node("id").with_text("42"))
+            .with_child(Element::node("title").with_attr("nil", "true"))
+            .with_child(Element::node("current").with_text("true"));
+
+        assert_eq!(field::<i64>(&page, "id").unwrap(), Some(42));
+        assert_eq!(field::<String>(&page, "title").unwrap(), None);
+        assert_eq!(field::<String>(&page, "content").unwrap(), None);
+        assert_eq!(field::<bool>(&page, "current").unwrap(), Some(true));
+        assert!(field::<i32>(&page, "current").is_err());
+    }
+
+    #[test]
+    fn writes_arrays_of_items() {
+        let element = vec![1i64, 2].to_soap("pageIds");
+
+        assert_eq!(element.name, "pageIds");
+        assert_eq!(element.children.len(), 2);
+        assert_eq!(element.children[1].text, Some("2".into()));
+        assert_eq!(Vec::<i64>::from_soap(&element).unwrap(), vec![1, 2]);
+    }
+}
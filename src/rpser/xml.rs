@@ -299,7 +299,8 @@ impl BuildElement for Element {
 
 fn get_typed_string(element: &Element, value_type: &str) -> Result<String, Error> {
     Ok(match (element.attributes.get("type"), &element.text) {
-        (Some(value), &Some(ref text)) if value.ends_with(value_type) => text.clone(),
+        // an empty string has no text
+        (Some(value), text) if value.ends_with(value_type) => text.clone().unwrap_or_default(),
         (other_type, _) => {
             return Err(Error::ExpectedElementWithType {
                 name: element.name.clone(),
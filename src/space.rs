@@ -1,5 +1,5 @@
 /// Space.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Space {
     /// The space key
     pub key: String,
@@ -16,3 +16,54 @@ pub struct Space {
     /// The HTML rendered space description
     pub description: Option<String>,
 }
+
+/// Space as listed by `Session::get_spaces`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpaceSummary {
+    /// The space key
+    pub key: String,
+    /// The name of the space
+    pub name: String,
+    /// Space type
+    pub space_type: String,
+    /// The url to view this space online
+    pub url: String,
+}
+
+/// Space Object for creating or updating a Space.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateSpace {
+    /// The space key
+    pub key: String,
+    /// The name of the space
+    pub name: String,
+    /// The space description
+    pub description: Option<String>,
+    /// The id of the space homepage
+    pub home_page: Option<i64>,
+}
+
+impl UpdateSpace {
+    /// Space with the fields needed to create it with `Session::add_space`.
+    ///
+    /// The home page is left unset, Confluence creates one with the space.
+    pub fn with_create_fields(key: &str, name: &str, description: Option<&str>) -> UpdateSpace {
+        UpdateSpace {
+            key: key.into(),
+            name: name.into(),
+            description: description.map(String::from),
+            home_page: None,
+        }
+    }
+}
+
+impl From<Space> for UpdateSpace {
+    fn from(other: Space) -> UpdateSpace {
+        UpdateSpace {
+            key: other.key,
+            name: other.name,
+            description: other.description,
+            home_page: Some(other.home_page),
+        }
+    }
+}
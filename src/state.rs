@@ -0,0 +1,236 @@
+//! Session state which can be saved to disk and resumed later.
+
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process;
+
+use rpser::xml::BuildElement;
+use wsdl::{Operation, Wsdl};
+use xmltree::Element;
+use {Error, Result};
+
+/**
+Everything needed to resume a `Session` without logging in: the base url,
+the auth token and the operations from the WSDL.
+
+The state is saved as a small XML document. It contains the auth token,
+so `save` makes the file readable only by the owner on unix systems.
+
+## Example
+
+```no_run
+use confluence::{DropBehavior, Session, SessionState};
+
+let path = "confluence-session.xml";
+
+let session = Session::builder("https://confluence")
+    .on_drop(DropBehavior::KeepToken)
+    .resume_or_login(SessionState::load(path).ok(), "user", "pass")
+    .unwrap();
+
+// ... use the session ...
+
+session.state().save(path).unwrap();
+```
+*/
+#[derive(Debug, Clone)]
+pub struct SessionState {
+    /// Base url of confluence
+    pub url: String,
+    /// The auth token
+    pub token: String,
+    /// Operations found in the WSDL
+    pub wsdl: Wsdl,
+}
+
+impl SessionState {
+    /// Serialize the state to XML.
+    pub fn to_xml(&self) -> String {
+        let mut operations: Vec<_> = self.wsdl.operations.iter().collect();
+        operations.sort_by(|a, b| a.0.cmp(b.0));
+
+        Element::node("sessionState")
+            .with_child(Element::node("url").with_text(self.url.as_str()))
+            .with_child(Element::node("token").with_text(self.token.as_str()))
+            .with_child(
+                Element::node("operations").with_children(operations.into_iter().map(
+                    |(name, operation)| {
+                        Element::node("operation")
+                            .with_attr("name", name.as_str())
+                            .with_attr("url", operation.url.as_str())
+                    },
+                )),
+            )
+            .to_string()
+    }
+
+    /// Read the state from XML produced by `to_xml`.
+    pub fn from_xml(xml: &str) -> Result<SessionState> {
+        let root = Element::parse(xml.as_bytes())
+            .map_err(|e| Error::InvalidSessionState(e.to_string()))?;
+
+        if root.name != "sessionState" {
+            return Err(Error::InvalidSessionState(format!(
+                "expected sessionState, found {}",
+                root.name
+            )));
+        }
+
+        let text = |name: &str| {
+            root.get_child(name)
+                .and_then(|child| child.text.clone())
+                .ok_or_else(|| Error::InvalidSessionState(format!("missing {}", name)))
+        };
+
+        let mut operations = HashMap::new();
+        if let Some(list) = root.get_child("operations") {
+            for operation in &list.children {
+                match (
+                    operation.attributes.get("name"),
+                    operation.attributes.get("url"),
+                ) {
+                    (Some(name), Some(url)) => {
+                        operations.insert(name.clone(), Operation::new(url.as_str()));
+                    }
+                    _ => {
+                        return Err(Error::InvalidSessionState(
+                            "operation without name or url".into(),
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(SessionState {
+            url: text("url")?,
+            token: text("token")?,
+            wsdl: Wsdl {
+                operations,
+                ..Wsdl::default()
+            },
+        })
+    }
+
+    /// Write the state to a file, replacing it if it exists.
+    ///
+    /// The state is written to a new file next to it, which is renamed over it,
+    /// so a replaced file does not keep its previous permissions.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut file_name = path
+            .file_name()
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "state path has no file name")
+            })?
+            .to_os_string();
+        file_name.push(format!(".{}.tmp", process::id()));
+        let temporary = path.with_file_name(file_name);
+
+        let saved = write_owner_only(&temporary, self.to_xml().as_bytes())
+            .and_then(|()| fs::rename(&temporary, path));
+        if saved.is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+
+        Ok(saved?)
+    }
+
+    /// Read the state from a file written by `save`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<SessionState> {
+        SessionState::from_xml(&fs::read_to_string(path)?)
+    }
+}
+
+/// Create the file readable only by the owner, and write the contents.
+fn write_owner_only(path: &Path, contents: &[u8]) -> io::Result<()> {
+    // a leftover file would keep its permissions
+    match fs::remove_file(path) {
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => {}
+        other => other?,
+    }
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    owner_only(&mut options);
+
+    options.open(path)?.write_all(contents)
+}
+
+#[cfg(unix)]
+fn owner_only(options: &mut OpenOptions) {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    options.mode(0o600);
+}
+
+#[cfg(not(unix))]
+fn owner_only(_options: &mut OpenOptions) {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_xml() {
+        let mut operations = HashMap::new();
+        operations.insert(
+            "getPage".to_string(),
+            Operation::new("https://confluence/rpc/soap-axis/confluenceservice-v2"),
+        );
+        let state = SessionState {
+            url: "https://confluence".into(),
+            token: "a3a8ecc6d5".into(),
+            wsdl: Wsdl {
+                operations,
+                ..Wsdl::default()
+            },
+        };
+
+        let restored = SessionState::from_xml(&state.to_xml()).unwrap();
+
+        assert_eq!(restored.url, "https://confluence");
+        assert_eq!(restored.token, "a3a8ecc6d5");
+        assert_eq!(
+            restored.wsdl.operations["getPage"].url,
+            "https://confluence/rpc/soap-axis/confluenceservice-v2"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn replaces_readable_file_with_owner_only_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = ::std::env::temp_dir().join(format!("confluence-state-{}.xml", process::id()));
+        fs::write(&path, "previous").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let state = SessionState {
+            url: "https://confluence".into(),
+            token: "a3a8ecc6d5".into(),
+            wsdl: Wsdl::default(),
+        };
+        state.save(&path).unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        let loaded = SessionState::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(loaded.unwrap().token, "a3a8ecc6d5");
+    }
+
+    #[test]
+    fn rejects_unrelated_documents() {
+        match SessionState::from_xml("<html><body>hi</body></html>") {
+            Err(Error::InvalidSessionState(_)) => {}
+            other => panic!("expected invalid state error, received {:?}", other),
+        }
+        match SessionState::from_xml("<sessionState><url>x</url></sessionState>") {
+            Err(Error::InvalidSessionState(ref message)) => assert_eq!(message, "missing token"),
+            other => panic!("expected invalid state error, received {:?}", other),
+        }
+    }
+}
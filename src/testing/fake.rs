@@ -0,0 +1,1017 @@
+//! In-memory store of spaces and pages.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+use xmltree::Element;
+
+use rpser::xml::BuildElement;
+use rpser::{FaultKind, RpcError};
+use {
+    ConfluenceClient, ContentSummary, Error, MovePosition, Page, PageSummary, PageUpdateOptions,
+    Result, Space, SpaceSummary, TrashContents, UpdatePage, UpdateSpace,
+};
+
+/// Base url of the pages and spaces in the fake.
+pub const FAKE_URL: &str = "https://confluence.example";
+
+/// User which creates and modifies everything in the fake.
+pub const FAKE_USER: &str = "admin";
+
+/**
+In-memory store of spaces and pages which answers the operations of `ConfluenceClient`.
+
+Pages get ids and version numbers like in Confluence, and the operations fail
+with the same faults: storing an outdated version is a `VersionConflict`,
+a missing page is `PageNotFound`, and a second page with the same title in a
+space is `DuplicateTitle`. Removed pages go to the trash of their space,
+where they are hidden from the title lookup and the page lists until they
+are restored or purged. Every saved version is kept with the options of
+`update_page`, see `page_history`. Timestamps come from a clock which advances
+by one minute on every change, so the results are deterministic.
+
+## Example
+
+```
+use confluence::testing::FakeConfluence;
+use confluence::{ConfluenceClient, FaultKind, UpdatePage};
+
+let confluence = FakeConfluence::new();
+let space = confluence.seed_space("DOC", "Documentation");
+
+let page = confluence
+    .store_page(UpdatePage::with_create_fields(Some(space.home_page), "DOC", "Guide", "<p>v1</p>"))
+    .unwrap();
+assert_eq!(page.version, 1);
+
+let mut outdated: UpdatePage = page.clone().into();
+let mut updated: UpdatePage = page.into();
+updated.content = "<p>v2</p>".into();
+assert_eq!(confluence.store_page(updated).unwrap().version, 2);
+
+outdated.content = "<p>lost update</p>".into();
+let error = confluence.store_page(outdated).unwrap_err();
+assert_eq!(error.fault_kind(), Some(FaultKind::VersionConflict));
+```
+*/
+#[derive(Debug)]
+pub struct FakeConfluence {
+    store: Mutex<Store>,
+}
+
+/// Version of a page saved in the fake, with the options it was saved with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageVersion {
+    pub version: i32,
+    pub title: String,
+    pub content: String,
+    pub modified: DateTime<Utc>,
+    /// Saved with `PageUpdateOptions::minor_edit`, `false` for `store_page`.
+    pub minor_edit: bool,
+    pub version_comment: Option<String>,
+}
+
+#[derive(Debug)]
+struct Store {
+    spaces: BTreeMap<String, Space>,
+    /// Keys of the archived spaces.
+    archived: BTreeSet<String>,
+    pages: BTreeMap<i64, Page>,
+    /// Saved versions of every page, oldest first.
+    history: BTreeMap<i64, Vec<PageVersion>>,
+    next_id: i64,
+    clock: DateTime<Utc>,
+}
+
+impl FakeConfluence {
+    /// Create an empty fake.
+    pub fn new() -> FakeConfluence {
+        FakeConfluence {
+            store: Mutex::new(Store {
+                spaces: BTreeMap::new(),
+                archived: BTreeSet::new(),
+                pages: BTreeMap::new(),
+                history: BTreeMap::new(),
+                next_id: 1,
+                clock: Utc.with_ymd_and_hms(2019, 1, 1, 0, 0, 0).unwrap(),
+            }),
+        }
+    }
+
+    /// Add a global space, with a home page titled `<name> Home` like Confluence does.
+    ///
+    /// An existing space with the same key is returned unchanged.
+    pub fn seed_space(&self, key: &str, name: &str) -> Space {
+        let mut store = self.store();
+
+        match store.spaces.get(key) {
+            Some(space) => space.clone(),
+            None => store.create_space(key, name, None, "global"),
+        }
+    }
+
+    /// All spaces, ordered by key.
+    pub fn spaces(&self) -> Vec<Space> {
+        self.store().spaces.values().cloned().collect()
+    }
+
+    /// All pages, ordered by id.
+    pub fn pages(&self) -> Vec<Page> {
+        self.store().pages.values().cloned().collect()
+    }
+
+    /// Whether the space was archived with `archive_space`.
+    pub fn is_archived(&self, space_key: &str) -> bool {
+        self.store().archived.contains(space_key)
+    }
+
+    /// Saved versions of the page, oldest first, empty for an unknown page.
+    pub fn page_history(&self, page_id: i64) -> Vec<PageVersion> {
+        self.store()
+            .history
+            .get(&page_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Add or update the page, recording the version with the update options.
+    fn save_page(&self, page: UpdatePage, options: Option<PageUpdateOptions>) -> Result<Page> {
+        let mut store = self.store();
+
+        if !store.spaces.contains_key(&page.space) {
+            return Err(space_not_found(&page.space));
+        }
+
+        if let Some(parent_id) = page.parent_id {
+            match store.pages.get(&parent_id) {
+                Some(parent) if parent.space == page.space => {}
+                _ => return Err(page_not_found()),
+            }
+        }
+
+        let duplicate = store.pages.values().any(|other| {
+            other.current
+                && other.space == page.space
+                && other.title == page.title
+                && Some(other.id) != page.id
+        });
+        if duplicate {
+            return Err(fault(&format!(
+                "com.atlassian.confluence.rpc.RemoteException: A page already exists with the title {} in the space with key {}",
+                page.title, page.space
+            )));
+        }
+
+        let stored = match page.id {
+            None => {
+                let id = store.next_id();
+                let now = store.tick();
+                Page {
+                    id,
+                    parent_id: page.parent_id.unwrap_or(0),
+                    url: page_url(&page.space, &page.title),
+                    space: page.space,
+                    title: page.title,
+                    version: 1,
+                    content: page.content,
+                    created: now,
+                    creator: FAKE_USER.into(),
+                    modified: now,
+                    modifier: FAKE_USER.into(),
+                    home_page: false,
+                    content_status: "current".into(),
+                    current: true,
+                }
+            }
+            Some(id) => {
+                let existing = match store.pages.get(&id) {
+                    Some(existing) => existing.clone(),
+                    None => return Err(page_not_found()),
+                };
+                if page.version != Some(existing.version) {
+                    return Err(fault(
+                        "com.atlassian.confluence.rpc.RemoteException: You're trying to edit an outdated version of that page.",
+                    ));
+                }
+                if page.parent_id == Some(id) {
+                    return Err(fault(
+                        "com.atlassian.confluence.rpc.RemoteException: A page can not be its own parent",
+                    ));
+                }
+
+                let now = store.tick();
+                Page {
+                    parent_id: page.parent_id.unwrap_or(existing.parent_id),
+                    url: page_url(&page.space, &page.title),
+                    space: page.space,
+                    title: page.title,
+                    version: existing.version + 1,
+                    content: page.content,
+                    modified: now,
+                    modifier: FAKE_USER.into(),
+                    ..existing
+                }
+            }
+        };
+
+        let options = options.unwrap_or(PageUpdateOptions {
+            version_comment: None,
+            minor_edit: false,
+        });
+        store
+            .history
+            .entry(stored.id)
+            .or_default()
+            .push(PageVersion {
+                version: stored.version,
+                title: stored.title.clone(),
+                content: stored.content.clone(),
+                modified: stored.modified,
+                minor_edit: options.minor_edit,
+                version_comment: options.version_comment,
+            });
+        store.pages.insert(stored.id, stored.clone());
+
+        Ok(stored)
+    }
+
+    fn store(&self) -> MutexGuard<'_, Store> {
+        self.store.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for FakeConfluence {
+    fn default() -> FakeConfluence {
+        FakeConfluence::new()
+    }
+}
+
+impl ConfluenceClient for FakeConfluence {
+    fn get_space(&self, space_key: &str) -> Result<Space> {
+        self.store()
+            .spaces
+            .get(space_key)
+            .cloned()
+            .ok_or_else(|| space_not_found(space_key))
+    }
+
+    fn get_spaces(&self) -> Result<Vec<SpaceSummary>> {
+        Ok(self
+            .store()
+            .spaces
+            .values()
+            .map(|space| SpaceSummary {
+                key: space.key.clone(),
+                name: space.name.clone(),
+                space_type: space.space_type.clone(),
+                url: space.url.clone(),
+            })
+            .collect())
+    }
+
+    fn add_space(&self, space: UpdateSpace) -> Result<Space> {
+        let mut store = self.store();
+
+        if store.spaces.contains_key(&space.key) {
+            return Err(space_exists(&space.key));
+        }
+
+        Ok(store.create_space(&space.key, &space.name, space.description, "global"))
+    }
+
+    fn add_personal_space(&self, space: UpdateSpace, user_name: &str) -> Result<Space> {
+        let mut store = self.store();
+        let key = format!("~{}", user_name);
+
+        if store.spaces.contains_key(&key) {
+            return Err(space_exists(&key));
+        }
+
+        Ok(store.create_space(&key, &space.name, space.description, "personal"))
+    }
+
+    fn remove_space(&self, space_key: &str) -> Result<bool> {
+        let mut store = self.store();
+
+        if store.spaces.remove(space_key).is_none() {
+            return Err(space_not_found(space_key));
+        }
+        store.archived.remove(space_key);
+
+        let removed: Vec<i64> = store
+            .pages
+            .values()
+            .filter(|page| page.space == space_key)
+            .map(|page| page.id)
+            .collect();
+        for id in removed {
+            store.pages.remove(&id);
+            store.history.remove(&id);
+        }
+
+        Ok(true)
+    }
+
+    fn store_space(&self, space: UpdateSpace) -> Result<Space> {
+        let mut store = self.store();
+        store.space_mut(&space.key)?;
+
+        if let Some(home_page) = space.home_page {
+            match store.pages.get(&home_page) {
+                Some(page) if page.space == space.key => {}
+                _ => return Err(page_not_found()),
+            }
+        }
+
+        let stored = store.space_mut(&space.key)?;
+        stored.name = space.name;
+        if space.description.is_some() {
+            stored.description = space.description;
+        }
+        if let Some(home_page) = space.home_page {
+            stored.home_page = home_page;
+        }
+
+        Ok(stored.clone())
+    }
+
+    fn archive_space(&self, space_key: &str) -> Result<bool> {
+        let mut store = self.store();
+        store.space_mut(space_key)?;
+        store.archived.insert(space_key.into());
+
+        Ok(true)
+    }
+
+    fn unarchive_space(&self, space_key: &str) -> Result<bool> {
+        let mut store = self.store();
+        store.space_mut(space_key)?;
+        store.archived.remove(space_key);
+
+        Ok(true)
+    }
+
+    fn get_page_by_title(&self, space_key: &str, page_title: &str) -> Result<Page> {
+        self.store()
+            .pages
+            .values()
+            .find(|page| page.current && page.space == space_key && page.title == page_title)
+            .cloned()
+            .ok_or_else(page_not_found)
+    }
+
+    fn get_page_by_id(&self, page_id: i64) -> Result<Page> {
+        self.store()
+            .pages
+            .get(&page_id)
+            .cloned()
+            .ok_or_else(page_not_found)
+    }
+
+    fn store_page(&self, page: UpdatePage) -> Result<Page> {
+        self.save_page(page, None)
+    }
+
+    fn update_page(&self, page: UpdatePage, options: PageUpdateOptions) -> Result<Page> {
+        if page.id.is_none() {
+            return Err(page_not_found());
+        }
+
+        self.save_page(page, Some(options))
+    }
+
+    fn get_children(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        let store = self.store();
+
+        if !store.pages.contains_key(&page_id) {
+            return Err(page_not_found());
+        }
+
+        Ok(store
+            .pages
+            .values()
+            .filter(|page| page.current && page.parent_id == page_id)
+            .map(summary)
+            .collect())
+    }
+
+    fn get_descendants(&self, page_id: i64) -> Result<Vec<PageSummary>> {
+        let store = self.store();
+
+        if !store.pages.contains_key(&page_id) {
+            return Err(page_not_found());
+        }
+
+        Ok(store
+            .descendants(page_id)
+            .into_iter()
+            .map(|id| summary(&store.pages[&id]))
+            .collect())
+    }
+
+    fn get_pages(&self, space_key: &str) -> Result<Vec<PageSummary>> {
+        let store = self.store();
+
+        if !store.spaces.contains_key(space_key) {
+            return Err(space_not_found(space_key));
+        }
+
+        Ok(store
+            .pages
+            .values()
+            .filter(|page| page.current && page.space == space_key)
+            .map(summary)
+            .collect())
+    }
+
+    fn move_page(
+        &self,
+        source_page_id: i64,
+        target_page_id: i64,
+        position: MovePosition,
+    ) -> Result<bool> {
+        let mut store = self.store();
+
+        let target = match store.pages.get(&target_page_id) {
+            Some(target) if target.current => target.clone(),
+            _ => return Err(page_not_found()),
+        };
+        if target.id == source_page_id || store.descendants(source_page_id).contains(&target.id) {
+            return Err(fault(
+                "com.atlassian.confluence.rpc.RemoteException: A page can not be moved below itself",
+            ));
+        }
+
+        let parent_id = match position {
+            MovePosition::Append => target.id,
+            MovePosition::Above | MovePosition::Below => target.parent_id,
+        };
+        store.move_tree(source_page_id, &target.space, parent_id)
+    }
+
+    fn move_page_to_top_level(&self, page_id: i64, space_key: &str) -> Result<bool> {
+        let mut store = self.store();
+        store.space_mut(space_key)?;
+
+        store.move_tree(page_id, space_key, 0)
+    }
+
+    fn remove_page(&self, page_id: i64) -> Result<bool> {
+        let mut store = self.store();
+
+        let parent_id = match store.pages.get_mut(&page_id) {
+            None => return Err(page_not_found()),
+            Some(page) if !page.current => return Err(fault(
+                "com.atlassian.confluence.rpc.RemoteException: The page has already been deleted",
+            )),
+            Some(page) => {
+                page.current = false;
+                page.content_status = "deleted".into();
+                page.parent_id
+            }
+        };
+
+        // like in Confluence, the children move up to the parent of the removed page
+        for child in store.pages.values_mut() {
+            if child.parent_id == page_id {
+                child.parent_id = parent_id;
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn remove_page_version(&self, page_id: i64, version: i32) -> Result<bool> {
+        let mut store = self.store();
+
+        let current_version = match store.pages.get(&page_id) {
+            Some(page) => page.version,
+            None => return Err(page_not_found()),
+        };
+        let history = store.history.entry(page_id).or_default();
+        match history.iter().position(|saved| saved.version == version) {
+            Some(position) if version != current_version => {
+                history.remove(position);
+                Ok(true)
+            }
+            _ => Err(fault(&format!(
+                "com.atlassian.confluence.rpc.RemoteException: Version {} of page {} can not be removed",
+                version, page_id
+            ))),
+        }
+    }
+
+    fn get_trash_contents(
+        &self,
+        space_key: &str,
+        offset: i32,
+        count: i32,
+    ) -> Result<TrashContents> {
+        let store = self.store();
+
+        if !store.spaces.contains_key(space_key) {
+            return Err(space_not_found(space_key));
+        }
+
+        let trash: Vec<ContentSummary> = store
+            .pages
+            .values()
+            .filter(|page| !page.current && page.space == space_key)
+            .map(|page| ContentSummary {
+                id: page.id,
+                content_type: "page".into(),
+                space: page.space.clone(),
+                status: page.content_status.clone(),
+                title: page.title.clone(),
+                created: page.created,
+                creator: page.creator.clone(),
+                modified: page.modified,
+                modifier: page.modifier.clone(),
+            })
+            .collect();
+
+        Ok(TrashContents {
+            total_available: trash.len() as i32,
+            offset,
+            items: trash
+                .into_iter()
+                .skip(offset.max(0) as usize)
+                .take(count.max(0) as usize)
+                .collect(),
+        })
+    }
+
+    fn purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool> {
+        let mut store = self.store();
+
+        match store.pages.get(&content_id) {
+            Some(page) if !page.current && page.space == space_key => {}
+            _ => return Err(page_not_found()),
+        }
+        store.pages.remove(&content_id);
+        store.history.remove(&content_id);
+
+        Ok(true)
+    }
+
+    fn empty_trash(&self, space_key: &str) -> Result<bool> {
+        let mut store = self.store();
+
+        if !store.spaces.contains_key(space_key) {
+            return Err(space_not_found(space_key));
+        }
+
+        let trash: Vec<i64> = store
+            .pages
+            .values()
+            .filter(|page| !page.current && page.space == space_key)
+            .map(|page| page.id)
+            .collect();
+        for id in trash {
+            store.pages.remove(&id);
+            store.history.remove(&id);
+        }
+
+        Ok(true)
+    }
+
+    fn restore_page(&self, page_id: i64) -> Result<Page> {
+        let mut store = self.store();
+
+        let mut page = match store.pages.get(&page_id) {
+            Some(page) if !page.current => page.clone(),
+            _ => return Err(page_not_found()),
+        };
+
+        page.current = true;
+        page.content_status = "current".into();
+        page.version += 1;
+        page.modified = store.tick();
+        store.history.entry(page_id).or_default().push(PageVersion {
+            version: page.version,
+            title: page.title.clone(),
+            content: page.content.clone(),
+            modified: page.modified,
+            minor_edit: false,
+            version_comment: None,
+        });
+        store.pages.insert(page_id, page.clone());
+
+        Ok(page)
+    }
+}
+
+impl Store {
+    /// Add a space, with a home page titled `<name> Home` like Confluence does.
+    fn create_space(
+        &mut self,
+        key: &str,
+        name: &str,
+        description: Option<String>,
+        space_type: &str,
+    ) -> Space {
+        let home_page = self.next_id();
+        let now = self.tick();
+        let title = format!("{} Home", name);
+        self.history.insert(
+            home_page,
+            vec![PageVersion {
+                version: 1,
+                title: title.clone(),
+                content: String::new(),
+                modified: now,
+                minor_edit: false,
+                version_comment: None,
+            }],
+        );
+        self.pages.insert(
+            home_page,
+            Page {
+                id: home_page,
+                space: key.into(),
+                parent_id: 0,
+                url: page_url(key, &title),
+                title,
+                version: 1,
+                content: String::new(),
+                created: now,
+                creator: FAKE_USER.into(),
+                modified: now,
+                modifier: FAKE_USER.into(),
+                home_page: true,
+                content_status: "current".into(),
+                current: true,
+            },
+        );
+
+        let space = Space {
+            key: key.into(),
+            name: name.into(),
+            space_group: None,
+            space_type: space_type.into(),
+            url: format!("{}/display/{}", FAKE_URL, key),
+            home_page,
+            description,
+        };
+        self.spaces.insert(key.into(), space.clone());
+
+        space
+    }
+
+    /// Ids of the current pages below the page, breadth first.
+    fn descendants(&self, page_id: i64) -> Vec<i64> {
+        let mut descendants = vec![];
+        let mut parents = VecDeque::from(vec![page_id]);
+
+        while let Some(parent_id) = parents.pop_front() {
+            for page in self.pages.values() {
+                if page.current && page.parent_id == parent_id {
+                    parents.push_back(page.id);
+                    descendants.push(page.id);
+                }
+            }
+        }
+
+        descendants
+    }
+
+    /// Put the page under the parent, or at the top level for `0`, moving
+    /// its descendants along into the space.
+    fn move_tree(&mut self, page_id: i64, space_key: &str, parent_id: i64) -> Result<bool> {
+        match self.pages.get(&page_id) {
+            Some(page) if page.current => {}
+            _ => return Err(page_not_found()),
+        }
+
+        let mut moved = self.descendants(page_id);
+        moved.push(page_id);
+
+        let duplicate = moved.iter().map(|id| &self.pages[id]).find(|page| {
+            self.pages.values().any(|other| {
+                other.current
+                    && other.space == space_key
+                    && other.title == page.title
+                    && !moved.contains(&other.id)
+            })
+        });
+        if let Some(page) = duplicate {
+            return Err(fault(&format!(
+                "com.atlassian.confluence.rpc.RemoteException: A page already exists with the title {} in the space with key {}",
+                page.title, space_key
+            )));
+        }
+
+        for id in moved {
+            if let Some(page) = self.pages.get_mut(&id) {
+                if id == page_id {
+                    page.parent_id = parent_id;
+                }
+                page.space = space_key.into();
+                page.url = page_url(space_key, &page.title);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// The space, unless it is missing.
+    fn space_mut(&mut self, space_key: &str) -> Result<&mut Space> {
+        self.spaces
+            .get_mut(space_key)
+            .ok_or_else(|| space_not_found(space_key))
+    }
+
+    fn next_id(&mut self) -> i64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    /// Advance the clock, returning the new time.
+    fn tick(&mut self) -> DateTime<Utc> {
+        self.clock += Duration::minutes(1);
+        self.clock
+    }
+}
+
+fn page_url(space_key: &str, title: &str) -> String {
+    format!(
+        "{}/display/{}/{}",
+        FAKE_URL,
+        space_key,
+        title.replace(' ', "+")
+    )
+}
+
+fn summary(page: &Page) -> PageSummary {
+    PageSummary {
+        id: page.id,
+        space: page.space.clone(),
+        parent_id: page.parent_id,
+        title: page.title.clone(),
+        url: page.url.clone(),
+    }
+}
+
+fn page_not_found() -> Error {
+    fault("com.atlassian.confluence.rpc.RemoteException: You're not allowed to view that page, or it does not exist.")
+}
+
+fn space_exists(space_key: &str) -> Error {
+    fault(&format!(
+        "com.atlassian.confluence.rpc.AlreadyExistsException: A space already exists with key {}",
+        space_key
+    ))
+}
+
+fn space_not_found(space_key: &str) -> Error {
+    fault(&format!(
+        "com.atlassian.confluence.rpc.RemoteException: No space found for space key: {}",
+        space_key
+    ))
+}
+
+/// Fault as Confluence returns it, classified from the fault string.
+///
+/// The exception class before the first `:` of the fault string is also the fault detail.
+pub(super) fn fault(fault_string: &str) -> Error {
+    let exception = fault_string.split(':').next().unwrap_or(fault_string);
+    let fault_detail = Element::node("detail").with_child(Element::node(exception));
+
+    RpcError::Fault {
+        fault_code: "soapenv:Server.userException".into(),
+        fault_string: fault_string.into(),
+        kind: FaultKind::classify(fault_string, &fault_detail),
+        fault_detail: Box::new(fault_detail),
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stores_pages_with_versions_and_children() {
+        let confluence = FakeConfluence::new();
+        let space = confluence.seed_space("DOC", "Documentation");
+
+        let guide = confluence
+            .store_page(UpdatePage::with_create_fields(
+                Some(space.home_page),
+                "DOC",
+                "Guide",
+                "<p>v1</p>",
+            ))
+            .unwrap();
+        let mut update: UpdatePage = guide.clone().into();
+        update.content = "<p>v2</p>".into();
+        let updated = confluence
+            .update_page(update, PageUpdateOptions::new_minor_with_comment("typo"))
+            .unwrap();
+
+        assert_eq!(updated.version, 2);
+        let history = confluence.page_history(guide.id);
+        assert_eq!(history.len(), 2);
+        assert!(!history[0].minor_edit);
+        assert_eq!(history[0].content, "<p>v1</p>");
+        assert!(history[1].minor_edit);
+        assert_eq!(history[1].version_comment, Some("typo".into()));
+        assert!(updated.modified > guide.modified);
+        assert_eq!(
+            confluence.get_page_by_title("DOC", "Guide").unwrap(),
+            updated
+        );
+        assert_eq!(
+            confluence.get_children(space.home_page).unwrap()[0].id,
+            guide.id
+        );
+        let titles: Vec<String> = confluence
+            .iter_pages("DOC")
+            .unwrap()
+            .map(|page| page.unwrap().title)
+            .collect();
+        assert_eq!(titles, vec!["Documentation Home", "Guide"]);
+        assert!(
+            confluence
+                .get_page_by_id(space.home_page)
+                .unwrap()
+                .home_page
+        );
+    }
+
+    #[test]
+    fn manages_spaces_with_their_pages() {
+        let confluence = FakeConfluence::new();
+        let doc = confluence
+            .add_space(UpdateSpace::with_create_fields(
+                "DOC",
+                "Documentation",
+                None,
+            ))
+            .unwrap();
+
+        assert_eq!(
+            confluence.get_page_by_id(doc.home_page).unwrap().title,
+            "Documentation Home"
+        );
+        assert_eq!(
+            confluence
+                .add_space(UpdateSpace::with_create_fields("DOC", "Again", None))
+                .unwrap_err()
+                .fault_kind(),
+            Some(FaultKind::Unknown)
+        );
+
+        confluence.archive_space("DOC").unwrap();
+        assert!(confluence.is_archived("DOC"));
+
+        assert!(confluence.remove_space("DOC").unwrap());
+        assert!(!confluence.is_archived("DOC"));
+        assert!(confluence.pages().is_empty());
+        assert!(confluence.page_history(doc.home_page).is_empty());
+        assert_eq!(
+            confluence.archive_space("DOC").unwrap_err().fault_kind(),
+            Some(FaultKind::SpaceNotFound)
+        );
+    }
+
+    #[test]
+    fn keeps_removed_pages_in_trash() {
+        let confluence = FakeConfluence::new();
+        let space = confluence.seed_space("DOC", "Documentation");
+        let guide = confluence
+            .store_page(UpdatePage::with_create_fields(
+                Some(space.home_page),
+                "DOC",
+                "Guide",
+                "",
+            ))
+            .unwrap();
+        let draft = confluence
+            .store_page(UpdatePage::with_create_fields(
+                Some(guide.id),
+                "DOC",
+                "Draft",
+                "",
+            ))
+            .unwrap();
+
+        assert!(confluence.remove_page(guide.id).unwrap());
+        assert_eq!(
+            confluence.remove_page(guide.id).unwrap_err().fault_kind(),
+            Some(FaultKind::AlreadyDeleted)
+        );
+        assert_eq!(
+            confluence.get_page_by_id(draft.id).unwrap().parent_id,
+            space.home_page
+        );
+        assert_eq!(confluence.get_pages("DOC").unwrap().len(), 2);
+
+        let trash = confluence.get_trash_contents("DOC", 0, 10).unwrap();
+        assert_eq!(trash.total_available, 1);
+        assert_eq!(trash.items[0].title, "Guide");
+        assert_eq!(trash.items[0].status, "deleted");
+
+        let restored = confluence.restore_page(guide.id).unwrap();
+        assert!(restored.current);
+        assert_eq!(restored.version, 2);
+        assert!(confluence.remove_page_version(guide.id, 1).unwrap());
+        assert_eq!(confluence.page_history(guide.id).len(), 1);
+
+        confluence.remove_page(draft.id).unwrap();
+        assert!(confluence.purge_from_trash("DOC", draft.id).unwrap());
+        confluence.remove_page(guide.id).unwrap();
+        assert!(confluence.empty_trash("DOC").unwrap());
+        assert_eq!(confluence.pages().len(), 1);
+    }
+
+    #[test]
+    fn moves_pages_with_their_descendants() {
+        let confluence = FakeConfluence::new();
+        let doc = confluence.seed_space("DOC", "Documentation");
+        let archive = confluence.seed_space("ARCHIVE", "Archive");
+        let store = |parent_id: i64, title: &str| {
+            confluence
+                .store_page(UpdatePage::with_create_fields(
+                    Some(parent_id),
+                    "DOC",
+                    title,
+                    "",
+                ))
+                .unwrap()
+                .id
+        };
+        let guide = store(doc.home_page, "Guide");
+        let setup = store(guide, "Setup");
+        let linux = store(setup, "Linux");
+
+        let descendants: Vec<i64> = confluence
+            .get_descendants(guide)
+            .unwrap()
+            .into_iter()
+            .map(|page| page.id)
+            .collect();
+        assert_eq!(descendants, vec![setup, linux]);
+        assert_eq!(
+            confluence
+                .move_page(guide, linux, MovePosition::Append)
+                .unwrap_err()
+                .fault_kind(),
+            Some(FaultKind::Remote)
+        );
+
+        assert_eq!(
+            confluence
+                .move_page_tree(guide, "ARCHIVE", Some(archive.home_page))
+                .unwrap(),
+            3
+        );
+        let moved = confluence.get_page_by_id(linux).unwrap();
+        assert_eq!(moved.space, "ARCHIVE");
+        assert_eq!(moved.parent_id, setup);
+        assert_eq!(
+            confluence.get_page_by_id(guide).unwrap().parent_id,
+            archive.home_page
+        );
+
+        assert!(confluence.move_page_to_top_level(setup, "DOC").unwrap());
+        assert_eq!(confluence.get_page_by_id(linux).unwrap().space, "DOC");
+        assert_eq!(confluence.get_page_by_id(setup).unwrap().parent_id, 0);
+    }
+
+    #[test]
+    fn fails_with_confluence_faults() {
+        let confluence = FakeConfluence::new();
+        confluence.seed_space("DOC", "Documentation");
+
+        let kind = |result: Result<Page>| result.unwrap_err().fault_kind();
+
+        assert_eq!(
+            kind(confluence.get_page_by_id(404)),
+            Some(FaultKind::PageNotFound)
+        );
+        assert_eq!(
+            kind(confluence.store_page(UpdatePage::with_create_fields(
+                None,
+                "DOC",
+                "Documentation Home",
+                ""
+            ))),
+            Some(FaultKind::DuplicateTitle)
+        );
+        assert_eq!(
+            kind(confluence.store_page(UpdatePage::with_create_fields(
+                Some(404),
+                "DOC",
+                "Orphan",
+                ""
+            ))),
+            Some(FaultKind::PageNotFound)
+        );
+        assert_eq!(
+            confluence.get_space("NONE").unwrap_err().fault_kind(),
+            Some(FaultKind::SpaceNotFound)
+        );
+    }
+}
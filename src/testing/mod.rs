@@ -0,0 +1,10 @@
+//! Confluence doubles for tests, enabled with the `testing` feature.
+//!
+//! `FakeConfluence` implements `ConfluenceClient` in memory, and `StubServer`
+//! serves it over SOAP so a real `Session` can log in to it.
+
+mod fake;
+mod server;
+
+pub use self::fake::{FakeConfluence, PageVersion, FAKE_URL, FAKE_USER};
+pub use self::server::{StubServer, STUB_PASSWORD};
@@ -0,0 +1,1085 @@
+//! Local HTTP server answering the SOAP API from a `FakeConfluence`.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::collections::HashSet;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use xmltree::Element;
+
+use super::fake::{fault, FakeConfluence, FAKE_URL, FAKE_USER};
+use rpser::xml::BuildElement;
+use rpser::RpcError;
+use {
+    ConfluenceClient, ContentSummary, Error, MovePosition, Page, PageSummary, PageUpdateOptions,
+    Result, Space, SpaceSummary, TrashContents, UpdatePage, UpdateSpace, V2_API_RPC_PATH,
+};
+
+/// Password of `FAKE_USER` on a server started with `StubServer::start`.
+pub const STUB_PASSWORD: &str = "admin";
+
+const WSDL_NS: &str = "http://schemas.xmlsoap.org/wsdl/";
+const WSDL_SOAP_NS: &str = "http://schemas.xmlsoap.org/wsdl/soap/";
+const SOAP_ENV_NS: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+const SOAP_ENC_NS: &str = "http://schemas.xmlsoap.org/soap/encoding/";
+const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema";
+const XSI_NS: &str = "http://www.w3.org/2001/XMLSchema-instance";
+const BEANS_NS: &str = "http://beans.soap.rpc.confluence.atlassian.com";
+
+/// Operations listed in the WSDL: name, parameter types and return type.
+///
+/// `getPage` is overloaded like in Confluence, by id and by space and title.
+const OPERATIONS: &[(&str, &[&str], &str)] = &[
+    ("login", &["xsd:string", "xsd:string"], "xsd:string"),
+    ("logout", &["xsd:string"], "xsd:boolean"),
+    ("getServerInfo", &["xsd:string"], "tns1:RemoteServerInfo"),
+    (
+        "getSpace",
+        &["xsd:string", "xsd:string"],
+        "tns1:RemoteSpace",
+    ),
+    (
+        "getSpaces",
+        &["xsd:string"],
+        "impl:ArrayOf_tns1_RemoteSpaceSummary",
+    ),
+    (
+        "addSpace",
+        &["xsd:string", "tns1:RemoteSpace"],
+        "tns1:RemoteSpace",
+    ),
+    (
+        "addPersonalSpace",
+        &["xsd:string", "tns1:RemoteSpace", "xsd:string"],
+        "tns1:RemoteSpace",
+    ),
+    ("removeSpace", &["xsd:string", "xsd:string"], "xsd:boolean"),
+    (
+        "storeSpace",
+        &["xsd:string", "tns1:RemoteSpace"],
+        "tns1:RemoteSpace",
+    ),
+    (
+        "setSpaceStatus",
+        &["xsd:string", "xsd:string", "xsd:string"],
+        "xsd:boolean",
+    ),
+    ("getPage", &["xsd:string", "xsd:long"], "tns1:RemotePage"),
+    (
+        "getPage",
+        &["xsd:string", "xsd:string", "xsd:string"],
+        "tns1:RemotePage",
+    ),
+    (
+        "storePage",
+        &["xsd:string", "tns1:RemotePage"],
+        "tns1:RemotePage",
+    ),
+    (
+        "updatePage",
+        &[
+            "xsd:string",
+            "tns1:RemotePage",
+            "tns1:RemotePageUpdateOptions",
+        ],
+        "tns1:RemotePage",
+    ),
+    (
+        "getChildren",
+        &["xsd:string", "xsd:long"],
+        "impl:ArrayOf_tns1_RemotePageSummary",
+    ),
+    (
+        "getPages",
+        &["xsd:string", "xsd:string"],
+        "impl:ArrayOf_tns1_RemotePageSummary",
+    ),
+    (
+        "getDescendents",
+        &["xsd:string", "xsd:long"],
+        "impl:ArrayOf_tns1_RemotePageSummary",
+    ),
+    (
+        "movePage",
+        &["xsd:string", "xsd:long", "xsd:long", "xsd:string"],
+        "xsd:boolean",
+    ),
+    (
+        "movePageToTopLevel",
+        &["xsd:string", "xsd:long", "xsd:string"],
+        "xsd:boolean",
+    ),
+    ("removePage", &["xsd:string", "xsd:long"], "xsd:boolean"),
+    (
+        "removePageVersionByVersion",
+        &["xsd:string", "xsd:long", "xsd:int"],
+        "xsd:boolean",
+    ),
+    (
+        "getTrashContents",
+        &["xsd:string", "xsd:string", "xsd:int", "xsd:int"],
+        "tns1:RemoteContentSummaries",
+    ),
+    (
+        "purgeFromTrash",
+        &["xsd:string", "xsd:string", "xsd:long"],
+        "xsd:boolean",
+    ),
+    ("emptyTrash", &["xsd:string", "xsd:string"], "xsd:boolean"),
+];
+
+/**
+SOAP server on `localhost` which answers from a `FakeConfluence`.
+
+The server publishes a WSDL at `/rpc/soap-axis/confluenceservice-v2?wsdl`
+and answers `login`, `logout`, `getServerInfo`, the space operations,
+`getPage`, `storePage`, `updatePage`, `getChildren`, `getPages`, the move
+and the trash operations with the envelopes and faults Axis sends, so a `Session`
+can run end-to-end against it. `Session::restore_page` needs the REST API,
+which the server does not answer. Tokens are issued on login and checked on
+every call. Requests are served one at a time, on a thread which stops when
+the server is dropped.
+
+## Example
+
+```
+use confluence::testing::{FakeConfluence, StubServer, FAKE_USER, STUB_PASSWORD};
+use confluence::{Session, UpdatePage};
+
+let confluence = FakeConfluence::new();
+let space = confluence.seed_space("DOC", "Documentation");
+let server = StubServer::start(confluence).unwrap();
+
+let session = Session::login(&server.url(), FAKE_USER, STUB_PASSWORD).unwrap();
+let page = session
+    .store_page(UpdatePage::with_create_fields(Some(space.home_page), "DOC", "Guide", "<p>v1</p>"))
+    .unwrap();
+
+assert_eq!(server.confluence().pages().len(), 2);
+assert_eq!(session.get_children(space.home_page).unwrap()[0].id, page.id);
+```
+*/
+pub struct StubServer {
+    addr: SocketAddr,
+    service: Arc<Service>,
+    accept_thread: Option<JoinHandle<()>>,
+}
+
+struct Service {
+    confluence: FakeConfluence,
+    /// Url of the SOAP endpoint, which is also the namespace of the operations.
+    endpoint: String,
+    user: String,
+    pass: String,
+    tokens: Mutex<HashSet<String>>,
+    issued_tokens: AtomicUsize,
+    /// Number of the next calls to answer with `429 Too Many Requests`.
+    throttled: AtomicUsize,
+    operations: Mutex<Vec<String>>,
+    shutdown: AtomicBool,
+}
+
+impl StubServer {
+    /// Serve the fake on a free port, accepting `FAKE_USER` with `STUB_PASSWORD`.
+    pub fn start(confluence: FakeConfluence) -> io::Result<StubServer> {
+        StubServer::start_with_credentials(confluence, FAKE_USER, STUB_PASSWORD)
+    }
+
+    /// Serve the fake on a free port, accepting only the given user and password.
+    pub fn start_with_credentials(
+        confluence: FakeConfluence,
+        user: &str,
+        pass: &str,
+    ) -> io::Result<StubServer> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+
+        let service = Arc::new(Service {
+            confluence,
+            endpoint: format!(
+                "http://{}{}",
+                addr,
+                V2_API_RPC_PATH.trim_end_matches("?wsdl")
+            ),
+            user: user.into(),
+            pass: pass.into(),
+            tokens: Mutex::new(HashSet::new()),
+            issued_tokens: AtomicUsize::new(0),
+            throttled: AtomicUsize::new(0),
+            operations: Mutex::new(vec![]),
+            shutdown: AtomicBool::new(false),
+        });
+
+        let accept_thread = {
+            let service = service.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if service.shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let result = stream.and_then(|stream| service.serve(stream));
+                    if let Err(e) = result {
+                        debug!("[stub] connection failed: {}", e);
+                    }
+                }
+            })
+        };
+
+        Ok(StubServer {
+            addr,
+            service,
+            accept_thread: Some(accept_thread),
+        })
+    }
+
+    /// Base url to log in to, such as `http://127.0.0.1:41234`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// The fake answering the calls, to seed it or to inspect the changes.
+    pub fn confluence(&self) -> &FakeConfluence {
+        &self.service.confluence
+    }
+
+    /// Names of the operations called so far, in order.
+    pub fn operations(&self) -> Vec<String> {
+        lock(&self.service.operations).clone()
+    }
+
+    /// Invalidate all issued tokens, as if the sessions had expired.
+    pub fn expire_tokens(&self) {
+        lock(&self.service.tokens).clear();
+    }
+
+    /// Answer the next `calls` calls with `429 Too Many Requests` and `Retry-After: 0`,
+    /// as a server which is rate limiting.
+    pub fn throttle(&self, calls: usize) {
+        self.service.throttled.store(calls, Ordering::SeqCst);
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.service.shutdown.store(true, Ordering::SeqCst);
+        // wake up the accept loop, so it can see the flag
+        let _ = TcpStream::connect(self.addr);
+        if let Some(accept_thread) = self.accept_thread.take() {
+            let _ = accept_thread.join();
+        }
+    }
+}
+
+impl Service {
+    /// Read one HTTP request from the stream, and write the response.
+    fn serve(&self, mut stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+            let mut parts = header.splitn(2, ':');
+            if let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+
+        let mut words = request_line.split_whitespace();
+        let (status, response) = self.respond(
+            words.next().unwrap_or(""),
+            words.next().unwrap_or(""),
+            &String::from_utf8_lossy(&body),
+        );
+
+        let retry_after = if status.starts_with("429") {
+            "Retry-After: 0\r\n"
+        } else {
+            ""
+        };
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: text/xml; charset=utf-8\r\nContent-Length: {}\r\n{}Connection: close\r\n\r\n",
+            status,
+            response.len(),
+            retry_after
+        )?;
+        stream.write_all(response.as_bytes())?;
+        stream.flush()
+    }
+
+    fn respond(&self, method: &str, target: &str, body: &str) -> (&'static str, String) {
+        let service_path = V2_API_RPC_PATH.trim_end_matches("?wsdl");
+
+        match method {
+            "GET" if target == V2_API_RPC_PATH => ("200 OK", self.wsdl()),
+            "POST" if target == service_path && self.take_throttled() => {
+                ("429 Too Many Requests", "Too Many Requests".into())
+            }
+            "POST" if target == service_path => match self.call(body) {
+                Ok(response) => ("200 OK", envelope(response)),
+                Err(error) => ("500 Internal Server Error", envelope(fault_element(error))),
+            },
+            _ => ("404 Not Found", "Not Found".into()),
+        }
+    }
+
+    /// Whether to throttle this call, counting it off `StubServer::throttle`.
+    fn take_throttled(&self) -> bool {
+        self.throttled
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |calls| {
+                calls.checked_sub(1)
+            })
+            .is_ok()
+    }
+
+    /// Answer the operation in the SOAP envelope with the `<operation>Response` element.
+    fn call(&self, body: &str) -> Result<Element> {
+        let request = Element::parse(body.as_bytes()).map_err(|e| {
+            fault(&format!(
+                "org.xml.sax.SAXParseException: Could not parse the request: {}",
+                e
+            ))
+        })?;
+        let operation = match request.get_child("Body").and_then(|b| b.children.first()) {
+            Some(operation) => operation,
+            None => {
+                return Err(fault(
+                    "org.xml.sax.SAXException: The request has no SOAP Body",
+                ))
+            }
+        };
+
+        lock(&self.operations).push(operation.name.clone());
+        debug!("[stub] {}", operation.name);
+
+        let return_value = self
+            .dispatch(&operation.name, &operation.children)?
+            .with_name(format!("{}Return", operation.name));
+
+        Ok(Element::node(format!("ns1:{}Response", operation.name))
+            .with_attr("soapenv:encodingStyle", SOAP_ENC_NS)
+            .with_attr("xmlns:ns1", self.endpoint.as_str())
+            .with_child(return_value))
+    }
+
+    fn dispatch(&self, operation: &str, args: &[Element]) -> Result<Element> {
+        match operation {
+            "login" => return self.login(text(args, 0), text(args, 1)),
+            "logout" => return Ok(boolean(lock(&self.tokens).remove(text(args, 0)))),
+            _ => {}
+        }
+
+        if !lock(&self.tokens).contains(text(args, 0)) {
+            return Err(fault(
+                "com.atlassian.confluence.rpc.InvalidSessionException: User not authenticated or session expired. Call login() to open a new session",
+            ));
+        }
+
+        let confluence = &self.confluence;
+
+        match (operation, args.len()) {
+            ("getServerInfo", 1) => Ok(server_info()),
+            ("getSpace", 2) => confluence.get_space(text(args, 1)).map(space_element),
+            ("getSpaces", 1) => confluence.get_spaces().map(|spaces| {
+                array(
+                    "RemoteSpaceSummary",
+                    spaces.iter().map(space_summary_element),
+                )
+            }),
+            ("addSpace", 2) => confluence
+                .add_space(update_space(&args[1])?)
+                .map(space_element),
+            ("addPersonalSpace", 3) => confluence
+                .add_personal_space(update_space(&args[1])?, text(args, 2))
+                .map(space_element),
+            ("removeSpace", 2) => confluence.remove_space(text(args, 1)).map(boolean),
+            ("storeSpace", 2) => confluence
+                .store_space(update_space(&args[1])?)
+                .map(space_element),
+            ("setSpaceStatus", 3) => match text(args, 2) {
+                "ARCHIVED" => confluence.archive_space(text(args, 1)).map(boolean),
+                "CURRENT" => confluence.unarchive_space(text(args, 1)).map(boolean),
+                status => Err(fault(&format!(
+                    "com.atlassian.confluence.rpc.RemoteException: Unknown space status: {}",
+                    status
+                ))),
+            },
+            ("getPage", 2) => confluence
+                .get_page_by_id(number(args, 1)?)
+                .map(page_element),
+            ("getPage", 3) => confluence
+                .get_page_by_title(text(args, 1), text(args, 2))
+                .map(page_element),
+            ("storePage", 2) => confluence
+                .store_page(update_page(&args[1])?)
+                .map(page_element),
+            ("updatePage", 3) => confluence
+                .update_page(update_page(&args[1])?, update_options(&args[2]))
+                .map(page_element),
+            ("getChildren", 2) => confluence
+                .get_children(number(args, 1)?)
+                .map(page_summaries),
+            ("getPages", 2) => confluence.get_pages(text(args, 1)).map(page_summaries),
+            ("getDescendents", 2) => confluence
+                .get_descendants(number(args, 1)?)
+                .map(page_summaries),
+            ("movePage", 4) => confluence
+                .move_page(number(args, 1)?, number(args, 2)?, position(text(args, 3))?)
+                .map(boolean),
+            ("movePageToTopLevel", 3) => confluence
+                .move_page_to_top_level(number(args, 1)?, text(args, 2))
+                .map(boolean),
+            ("removePage", 2) => confluence.remove_page(number(args, 1)?).map(boolean),
+            ("removePageVersionByVersion", 3) => confluence
+                .remove_page_version(number(args, 1)?, parse_number(text(args, 2))?)
+                .map(boolean),
+            ("getTrashContents", 4) => confluence
+                .get_trash_contents(
+                    text(args, 1),
+                    parse_number(text(args, 2))?,
+                    parse_number(text(args, 3))?,
+                )
+                .map(trash_element),
+            ("purgeFromTrash", 3) => confluence
+                .purge_from_trash(text(args, 1), number(args, 2)?)
+                .map(boolean),
+            ("emptyTrash", 2) => confluence.empty_trash(text(args, 1)).map(boolean),
+            _ => Err(fault(&format!(
+                "org.xml.sax.SAXException: No such operation '{}' with {} arguments",
+                operation,
+                args.len()
+            ))),
+        }
+    }
+
+    fn login(&self, user: &str, pass: &str) -> Result<Element> {
+        if user != self.user || pass != self.pass {
+            return Err(fault(&format!(
+                "com.atlassian.confluence.rpc.AuthenticationFailedException: Attempt to log in user '{}' failed - incorrect username/password combination.",
+                user
+            )));
+        }
+
+        let token = format!(
+            "stub-token-{}",
+            self.issued_tokens.fetch_add(1, Ordering::SeqCst) + 1
+        );
+        lock(&self.tokens).insert(token.clone());
+
+        Ok(typed("string", token))
+    }
+
+    /// WSDL of the operations, in the layout Axis generates.
+    fn wsdl(&self) -> String {
+        let mut messages = vec![];
+        let mut port_type =
+            Element::node("wsdl:portType").with_attr("name", "ConfluenceSoapService");
+        let mut binding = Element::node("wsdl:binding")
+            .with_attr("name", "confluenceservice-v2SoapBinding")
+            .with_attr("type", "impl:ConfluenceSoapService")
+            .with_child(
+                Element::node("wsdlsoap:binding")
+                    .with_attr("style", "rpc")
+                    .with_attr("transport", "http://schemas.xmlsoap.org/soap/http"),
+            );
+
+        for (index, &(name, params, return_type)) in OPERATIONS.iter().enumerate() {
+            // overloads get numbered request messages, like getPageRequest1
+            let overload = OPERATIONS[..index]
+                .iter()
+                .filter(|&&(other, _, _)| other == name)
+                .count();
+            let request = match overload {
+                0 => format!("{}Request", name),
+                n => format!("{}Request{}", name, n),
+            };
+            let response = format!("{}Response", name);
+
+            messages.push(
+                Element::node("wsdl:message")
+                    .with_attr("name", request.as_str())
+                    .with_children(params.iter().enumerate().map(|(i, param)| {
+                        Element::node("wsdl:part")
+                            .with_attr("name", format!("in{}", i))
+                            .with_attr("type", *param)
+                    })),
+            );
+            if overload == 0 {
+                messages.push(
+                    Element::node("wsdl:message")
+                        .with_attr("name", response.as_str())
+                        .with_child(
+                            Element::node("wsdl:part")
+                                .with_attr("name", format!("{}Return", name))
+                                .with_attr("type", return_type),
+                        ),
+                );
+                binding = binding.with_child(
+                    Element::node("wsdl:operation")
+                        .with_attr("name", name)
+                        .with_child(
+                            Element::node("wsdlsoap:operation").with_attr("soapAction", ""),
+                        ),
+                );
+            }
+
+            port_type = port_type.with_child(
+                Element::node("wsdl:operation")
+                    .with_attr("name", name)
+                    .with_child(
+                        Element::node("wsdl:input")
+                            .with_attr("message", format!("impl:{}", request))
+                            .with_attr("name", request.as_str()),
+                    )
+                    .with_child(
+                        Element::node("wsdl:output")
+                            .with_attr("message", format!("impl:{}", response))
+                            .with_attr("name", response.as_str()),
+                    ),
+            );
+        }
+
+        let service = Element::node("wsdl:service")
+            .with_attr("name", "ConfluenceSoapServiceService")
+            .with_child(
+                Element::node("wsdl:port")
+                    .with_attr("binding", "impl:confluenceservice-v2SoapBinding")
+                    .with_attr("name", "confluenceservice-v2")
+                    .with_child(
+                        Element::node("wsdlsoap:address")
+                            .with_attr("location", self.endpoint.as_str()),
+                    ),
+            );
+
+        Element::node("wsdl:definitions")
+            .with_attr("targetNamespace", self.endpoint.as_str())
+            .with_attr("xmlns:impl", self.endpoint.as_str())
+            .with_attr("xmlns:soapenc", SOAP_ENC_NS)
+            .with_attr("xmlns:tns1", BEANS_NS)
+            .with_attr("xmlns:wsdl", WSDL_NS)
+            .with_attr("xmlns:wsdlsoap", WSDL_SOAP_NS)
+            .with_attr("xmlns:xsd", XSD_NS)
+            .with_children(messages)
+            .with_child(port_type)
+            .with_child(binding)
+            .with_child(service)
+            .to_string()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn envelope(body: Element) -> String {
+    Element::node("soapenv:Envelope")
+        .with_attr("xmlns:soapenv", SOAP_ENV_NS)
+        .with_attr("xmlns:xsd", XSD_NS)
+        .with_attr("xmlns:xsi", XSI_NS)
+        .with_child(Element::node("soapenv:Body").with_child(body))
+        .to_string()
+}
+
+/// `<soapenv:Fault>` element of the error, which is a fault unless something unexpected failed.
+fn fault_element(error: Error) -> Element {
+    let (fault_code, fault_string, fault_detail) = match error {
+        Error::Rpc(rpc_error) => match *rpc_error {
+            RpcError::Fault {
+                fault_code,
+                fault_string,
+                fault_detail,
+                ..
+            } => (fault_code, fault_string, *fault_detail),
+            other => (
+                "soapenv:Server.generalException".into(),
+                format!("{:?}", other),
+                Element::node("detail"),
+            ),
+        },
+        other => (
+            "soapenv:Server.generalException".into(),
+            format!("{:?}", other),
+            Element::node("detail"),
+        ),
+    };
+
+    Element::node("soapenv:Fault")
+        .with_child(Element::node("faultcode").with_text(fault_code))
+        .with_child(Element::node("faultstring").with_text(fault_string))
+        .with_child(fault_detail)
+}
+
+/// Text of the argument at the position, empty if it is missing.
+fn text(args: &[Element], position: usize) -> &str {
+    args.get(position)
+        .and_then(|arg| arg.text.as_deref())
+        .unwrap_or("")
+}
+
+/// Argument at the position parsed as a number.
+fn number(args: &[Element], position: usize) -> Result<i64> {
+    parse_number(text(args, position))
+}
+
+fn parse_number<T: ::std::str::FromStr>(text: &str) -> Result<T> {
+    text.trim().parse().map_err(|_| {
+        fault(&format!(
+            "org.xml.sax.SAXException: Bad types (class java.lang.String -> long): {}",
+            text
+        ))
+    })
+}
+
+fn update_page(element: &Element) -> Result<UpdatePage> {
+    let field = |name: &str| {
+        element
+            .get_child(name)
+            .map(|child| child.text.clone().unwrap_or_default())
+    };
+
+    Ok(UpdatePage {
+        id: field("id").map(|id| parse_number(&id)).transpose()?,
+        space: field("space").unwrap_or_default(),
+        title: field("title").unwrap_or_default(),
+        content: field("content").unwrap_or_default(),
+        version: field("version").map(|v| parse_number(&v)).transpose()?,
+        parent_id: field("parentId").map(|id| parse_number(&id)).transpose()?,
+    })
+}
+
+fn position(text: &str) -> Result<MovePosition> {
+    match text {
+        "above" => Ok(MovePosition::Above),
+        "below" => Ok(MovePosition::Below),
+        "append" => Ok(MovePosition::Append),
+        other => Err(fault(&format!(
+            "com.atlassian.confluence.rpc.RemoteException: Invalid move position: {}",
+            other
+        ))),
+    }
+}
+
+fn update_space(element: &Element) -> Result<UpdateSpace> {
+    let field = |name: &str| {
+        element
+            .get_child(name)
+            .map(|child| child.text.clone().unwrap_or_default())
+    };
+
+    Ok(UpdateSpace {
+        key: field("key").unwrap_or_default(),
+        name: field("name").unwrap_or_default(),
+        description: field("description"),
+        home_page: field("homePage").map(|id| parse_number(&id)).transpose()?,
+    })
+}
+
+fn update_options(element: &Element) -> PageUpdateOptions {
+    PageUpdateOptions {
+        version_comment: element
+            .get_child("versionComment")
+            .and_then(|comment| comment.text.clone()),
+        minor_edit: element
+            .get_child("minorEdit")
+            .and_then(|minor| minor.text.as_deref())
+            == Some("true"),
+    }
+}
+
+/// Element with a simple `xsi:type`, such as `xsd:long`; it gets its name from the caller.
+fn typed<S: Into<String>>(xsd_type: &str, text: S) -> Element {
+    Element::node("value")
+        .with_attr("xsi:type", format!("xsd:{}", xsd_type))
+        .with_text(text)
+}
+
+fn boolean(value: bool) -> Element {
+    typed("boolean", value.to_string())
+}
+
+fn field<S: Into<String>>(name: &str, xsd_type: &str, text: S) -> Element {
+    typed(xsd_type, text).with_name(name)
+}
+
+fn date_time_field(name: &str, date_time: &DateTime<Utc>) -> Element {
+    field(
+        name,
+        "dateTime",
+        date_time.to_rfc3339_opts(SecondsFormat::Millis, true),
+    )
+}
+
+fn nil_field(name: &str) -> Element {
+    Element::node(name).with_attr("xsi:nil", "true")
+}
+
+/// Element of a bean type, such as `RemotePage`.
+fn bean(type_name: &str, fields: Vec<Element>) -> Element {
+    Element::node("value")
+        .with_attr("xsi:type", format!("ns2:{}", type_name))
+        .with_attr("xmlns:ns2", BEANS_NS)
+        .with_children(fields)
+}
+
+fn array<I: Iterator<Item = Element>>(type_name: &str, items: I) -> Element {
+    let items: Vec<Element> = items.map(|item| item.with_name("item")).collect();
+
+    Element::node("value")
+        .with_attr("xsi:type", "soapenc:Array")
+        .with_attr(
+            "soapenc:arrayType",
+            format!("ns2:{}[{}]", type_name, items.len()),
+        )
+        .with_attr("xmlns:soapenc", SOAP_ENC_NS)
+        .with_attr("xmlns:ns2", BEANS_NS)
+        .with_children(items)
+}
+
+fn server_info() -> Element {
+    bean(
+        "RemoteServerInfo",
+        vec![
+            field("baseUrl", "string", FAKE_URL),
+            field("buildId", "string", "5640"),
+            field("developmentBuild", "boolean", "false"),
+            field("majorVersion", "int", "5"),
+            field("minorVersion", "int", "10"),
+            field("patchLevel", "int", "8"),
+        ],
+    )
+}
+
+fn space_element(space: Space) -> Element {
+    bean(
+        "RemoteSpace",
+        vec![
+            match space.description {
+                Some(description) => field("description", "string", description),
+                None => nil_field("description"),
+            },
+            field("homePage", "long", space.home_page.to_string()),
+            field("key", "string", space.key),
+            field("name", "string", space.name),
+            match space.space_group {
+                Some(space_group) => field("spaceGroup", "string", space_group),
+                None => nil_field("spaceGroup"),
+            },
+            field("type", "string", space.space_type),
+            field("url", "string", space.url),
+        ],
+    )
+}
+
+fn space_summary_element(summary: &SpaceSummary) -> Element {
+    bean(
+        "RemoteSpaceSummary",
+        vec![
+            field("key", "string", summary.key.as_str()),
+            field("name", "string", summary.name.as_str()),
+            field("type", "string", summary.space_type.as_str()),
+            field("url", "string", summary.url.as_str()),
+        ],
+    )
+}
+
+fn page_element(page: Page) -> Element {
+    bean(
+        "RemotePage",
+        vec![
+            field("id", "long", page.id.to_string()),
+            field("permissions", "int", "0"),
+            field("space", "string", page.space),
+            field("title", "string", page.title),
+            field("url", "string", page.url),
+            field("parentId", "long", page.parent_id.to_string()),
+            field("version", "int", page.version.to_string()),
+            field("content", "string", page.content),
+            field("contentStatus", "string", page.content_status),
+            date_time_field("created", &page.created),
+            field("creator", "string", page.creator),
+            field("current", "boolean", page.current.to_string()),
+            field("homePage", "boolean", page.home_page.to_string()),
+            date_time_field("modified", &page.modified),
+            field("modifier", "string", page.modifier),
+        ],
+    )
+}
+
+fn trash_element(trash: TrashContents) -> Element {
+    bean(
+        "RemoteContentSummaries",
+        vec![
+            array(
+                "RemoteContentSummary",
+                trash.items.iter().map(content_summary_element),
+            )
+            .with_name("content"),
+            field("offset", "int", trash.offset.to_string()),
+            field("totalAvailable", "int", trash.total_available.to_string()),
+        ],
+    )
+}
+
+fn content_summary_element(summary: &ContentSummary) -> Element {
+    bean(
+        "RemoteContentSummary",
+        vec![
+            field("id", "long", summary.id.to_string()),
+            field("type", "string", summary.content_type.as_str()),
+            field("space", "string", summary.space.as_str()),
+            field("status", "string", summary.status.as_str()),
+            field("title", "string", summary.title.as_str()),
+            date_time_field("created", &summary.created),
+            field("creator", "string", summary.creator.as_str()),
+            date_time_field("modified", &summary.modified),
+            field("modifier", "string", summary.modifier.as_str()),
+        ],
+    )
+}
+
+fn page_summaries(summaries: Vec<PageSummary>) -> Element {
+    array(
+        "RemotePageSummary",
+        summaries.iter().map(page_summary_element),
+    )
+}
+
+fn page_summary_element(summary: &PageSummary) -> Element {
+    bean(
+        "RemotePageSummary",
+        vec![
+            field("id", "long", summary.id.to_string()),
+            field("permissions", "int", "0"),
+            field("space", "string", summary.space.as_str()),
+            field("title", "string", summary.title.as_str()),
+            field("url", "string", summary.url.as_str()),
+            field("parentId", "long", summary.parent_id.to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {FaultKind, Session};
+
+    fn started() -> (StubServer, Space) {
+        let confluence = FakeConfluence::new();
+        let space = confluence.seed_space("DOC", "Documentation");
+        (StubServer::start(confluence).unwrap(), space)
+    }
+
+    #[test]
+    fn serves_session_end_to_end() {
+        let (server, space) = started();
+
+        let session = Session::login(&server.url(), FAKE_USER, STUB_PASSWORD).unwrap();
+        assert_eq!(session.get_space("DOC").unwrap().home_page, space.home_page);
+        assert!(session.get_page_by_id(space.home_page).unwrap().home_page);
+
+        let guide = session
+            .store_page(UpdatePage::with_create_fields(
+                Some(space.home_page),
+                "DOC",
+                "Guide",
+                "<p>v1</p>",
+            ))
+            .unwrap();
+        assert_eq!(session.get_page_by_title("DOC", "Guide").unwrap(), guide);
+        assert_eq!(server.confluence().get_page_by_id(guide.id).unwrap(), guide);
+        assert_eq!(
+            session.get_children(space.home_page).unwrap()[0].title,
+            "Guide"
+        );
+        let pages: Vec<Page> = session
+            .iter_pages("DOC")
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(pages[1], guide);
+
+        let guide_id = guide.id;
+        let mut outdated: UpdatePage = guide.clone().into();
+        let mut update: UpdatePage = guide.into();
+        update.content = "<p>v2</p>".into();
+        assert_eq!(
+            session
+                .update_page(update, PageUpdateOptions::new_minor_with_comment("typo"))
+                .unwrap()
+                .version,
+            2
+        );
+        let saved = server.confluence().page_history(guide_id).pop().unwrap();
+        assert!(saved.minor_edit);
+        assert_eq!(saved.version_comment, Some("typo".into()));
+        outdated.content = "<p>lost update</p>".into();
+        assert_eq!(
+            session.store_page(outdated).unwrap_err().fault_kind(),
+            Some(FaultKind::VersionConflict)
+        );
+        assert_eq!(
+            session.get_page_by_id(404).unwrap_err().fault_kind(),
+            Some(FaultKind::PageNotFound)
+        );
+
+        drop(session);
+        assert_eq!(server.operations().last().unwrap(), "logout");
+    }
+
+    #[test]
+    fn serves_space_operations() {
+        let (server, space) = started();
+        let session = Session::login(&server.url(), FAKE_USER, STUB_PASSWORD).unwrap();
+
+        let sandbox = session
+            .add_space(UpdateSpace::with_create_fields(
+                "SANDBOX",
+                "Sandbox",
+                Some("Try things out"),
+            ))
+            .unwrap();
+        assert_eq!(sandbox.description, Some("Try things out".into()));
+        let personal = session
+            .add_personal_space(
+                UpdateSpace::with_create_fields("", "Admin", None),
+                FAKE_USER,
+            )
+            .unwrap();
+        assert_eq!(personal.key, "~admin");
+        let keys: Vec<String> = session
+            .get_spaces()
+            .unwrap()
+            .into_iter()
+            .map(|space| space.key)
+            .collect();
+        assert_eq!(keys, vec!["DOC", "SANDBOX", "~admin"]);
+
+        let mut update: UpdateSpace = space.into();
+        update.name = "Manuals".into();
+        assert_eq!(session.store_space(update).unwrap().name, "Manuals");
+
+        assert!(session.archive_space("DOC").unwrap());
+        assert!(server.confluence().is_archived("DOC"));
+        assert!(session.unarchive_space("DOC").unwrap());
+        assert!(!server.confluence().is_archived("DOC"));
+
+        assert!(session.remove_space("SANDBOX").unwrap());
+        assert_eq!(
+            session.remove_space("SANDBOX").unwrap_err().fault_kind(),
+            Some(FaultKind::SpaceNotFound)
+        );
+        assert_eq!(server.confluence().pages().len(), 2);
+    }
+
+    #[test]
+    fn moves_page_trees_end_to_end() {
+        let (server, space) = started();
+        let archive = server.confluence().seed_space("ARCHIVE", "Archive");
+        let session = Session::login(&server.url(), FAKE_USER, STUB_PASSWORD).unwrap();
+        let store = |parent_id: i64, title: &str| {
+            session
+                .store_page(UpdatePage::with_create_fields(
+                    Some(parent_id),
+                    "DOC",
+                    title,
+                    "",
+                ))
+                .unwrap()
+        };
+        let guide = store(space.home_page, "Guide");
+        let setup = store(guide.id, "Setup");
+
+        assert_eq!(session.get_descendants(guide.id).unwrap()[0].id, setup.id);
+        assert_eq!(
+            session
+                .move_page_tree(guide.id, "ARCHIVE", Some(archive.home_page))
+                .unwrap(),
+            2
+        );
+        assert_eq!(session.get_page_by_id(setup.id).unwrap().space, "ARCHIVE");
+        assert!(session
+            .move_page(setup.id, guide.id, MovePosition::Below)
+            .unwrap());
+        assert_eq!(
+            session.get_page_by_id(setup.id).unwrap().parent_id,
+            archive.home_page
+        );
+        assert!(session.move_page_to_top_level(guide.id, "DOC").unwrap());
+        assert_eq!(session.get_pages("DOC").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn serves_trash_operations() {
+        let (server, space) = started();
+        let session = Session::login(&server.url(), FAKE_USER, STUB_PASSWORD).unwrap();
+        let guide = session
+            .store_page(UpdatePage::with_create_fields(
+                Some(space.home_page),
+                "DOC",
+                "Guide",
+                "<p>v1</p>",
+            ))
+            .unwrap();
+        let mut update: UpdatePage = guide.clone().into();
+        update.content = "<p>v2</p>".into();
+        session.store_page(update).unwrap();
+
+        assert!(session.remove_page_version(guide.id, 1).unwrap());
+        assert!(session.remove_page(guide.id).unwrap());
+        assert_eq!(
+            session.remove_page(guide.id).unwrap_err().fault_kind(),
+            Some(FaultKind::AlreadyDeleted)
+        );
+
+        let trash = session.get_trash_contents("DOC", 0, 10).unwrap();
+        assert_eq!(trash.total_available, 1);
+        assert_eq!(trash.items[0].id, guide.id);
+        assert!(session.purge_from_trash("DOC", guide.id).unwrap());
+        assert!(session.empty_trash("DOC").unwrap());
+        assert!(session
+            .get_trash_contents("DOC", 0, 10)
+            .unwrap()
+            .items
+            .is_empty());
+        assert_eq!(server.confluence().pages().len(), 1);
+    }
+
+    #[test]
+    fn rejects_wrong_password_and_expired_tokens() {
+        let (server, _) = started();
+
+        match Session::login(&server.url(), FAKE_USER, "wrong") {
+            Err(e) => assert_eq!(e.fault_kind(), Some(FaultKind::AuthenticationFailed)),
+            Ok(_) => panic!("expected the login to fail"),
+        }
+
+        let session = Session::builder(server.url())
+            .auto_relogin()
+            .login(FAKE_USER, STUB_PASSWORD)
+            .unwrap();
+        server.expire_tokens();
+
+        assert_eq!(session.get_space("DOC").unwrap().key, "DOC");
+        assert_eq!(
+            server
+                .operations()
+                .iter()
+                .filter(|operation| *operation == "login")
+                .count(),
+            3
+        );
+    }
+}
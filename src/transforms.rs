@@ -1,7 +1,12 @@
+use rpser::value::SoapValue;
 use rpser::xml::BuildElement;
+use xmlrpc::{FromValue, Value, XmlRpcError};
 use xmltree::Element;
 
-use {Page, PageSummary, Result, Space};
+use {
+    ContentSummary, Page, PageSummary, PageUpdateOptions, Result, Space, SpaceSummary,
+    TrashContents, UpdatePage, UpdateSpace,
+};
 
 pub trait FromElement {
     fn from_element(element: Element) -> Result<Self>
@@ -23,6 +28,23 @@ impl FromElement for Space {
     }
 }
 
+impl FromElement for SpaceSummary {
+    fn from_element(element: Element) -> Result<SpaceSummary> {
+        Ok(SpaceSummary {
+            key: element.get_at_path(&["key"])?.as_string()?,
+            name: element.get_at_path(&["name"])?.as_string()?,
+            space_type: element.get_at_path(&["type"])?.as_string()?,
+            url: element.get_at_path(&["url"])?.as_string()?,
+        })
+    }
+}
+
+impl FromElement for bool {
+    fn from_element(element: Element) -> Result<bool> {
+        Ok(element.as_boolean()?)
+    }
+}
+
 impl FromElement for Page {
     fn from_element(element: Element) -> Result<Page> {
         Ok(Page {
@@ -71,3 +93,293 @@ impl FromElement for PageSummary {
         })
     }
 }
+
+impl FromElement for ContentSummary {
+    fn from_element(element: Element) -> Result<ContentSummary> {
+        Ok(ContentSummary {
+            id: element.get_at_path(&["id"])?.as_long()?,
+            content_type: element.get_at_path(&["type"])?.as_string()?,
+            space: element.get_at_path(&["space"])?.as_string()?,
+            status: element.get_at_path(&["status"])?.as_string()?,
+            title: element.get_at_path(&["title"])?.as_string()?,
+            created: element.get_at_path(&["created"])?.as_datetime()?,
+            creator: element.get_at_path(&["creator"])?.as_string()?,
+            modified: element.get_at_path(&["modified"])?.as_datetime()?,
+            modifier: element.get_at_path(&["modifier"])?.as_string()?,
+        })
+    }
+}
+
+impl FromElement for TrashContents {
+    fn from_element(element: Element) -> Result<TrashContents> {
+        Ok(TrashContents {
+            total_available: element.get_at_path(&["totalAvailable"])?.as_int()?,
+            offset: element.get_at_path(&["offset"])?.as_int()?,
+            items: Vec::from_element(element.get_at_path(&["content"])?)?,
+        })
+    }
+}
+
+impl<T: FromElement> FromElement for Vec<T> {
+    fn from_element(element: Element) -> Result<Vec<T>> {
+        element.children.into_iter().map(T::from_element).collect()
+    }
+}
+
+impl FromValue for Space {
+    fn from_value(value: Value) -> Result<Space> {
+        Ok(Space {
+            description: value.get_optional_string("description")?,
+            home_page: value.get_long("homePage")?,
+            key: value.get_string("key")?,
+            name: value.get_string("name")?,
+            space_group: value.get_optional_string("spaceGroup")?,
+            space_type: value.get_string("type")?,
+            url: value.get_string("url")?,
+        })
+    }
+}
+
+impl FromValue for SpaceSummary {
+    fn from_value(value: Value) -> Result<SpaceSummary> {
+        Ok(SpaceSummary {
+            key: value.get_string("key")?,
+            name: value.get_string("name")?,
+            space_type: value.get_string("type")?,
+            url: value.get_string("url")?,
+        })
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: Value) -> Result<bool> {
+        match value.as_boolean() {
+            Some(b) => Ok(b),
+            None => Err(XmlRpcError::UnexpectedType {
+                name: "return".into(),
+                expected_type: "boolean".into(),
+                given: value,
+            }
+            .into()),
+        }
+    }
+}
+
+impl FromValue for Page {
+    fn from_value(value: Value) -> Result<Page> {
+        Ok(Page {
+            id: value.get_long("id")?,
+            space: value.get_string("space")?,
+            parent_id: value.get_long("parentId")?,
+            title: value.get_string("title")?,
+            url: value.get_string("url")?,
+            version: value.get_int("version")?,
+            content: value.get_string("content")?,
+            created: value.get_datetime("created")?,
+            creator: value.get_string("creator")?,
+            modified: value.get_datetime("modified")?,
+            modifier: value.get_string("modifier")?,
+            home_page: value.get_boolean("homePage")?,
+            content_status: value.get_string("contentStatus")?,
+            current: value.get_boolean("current")?,
+        })
+    }
+}
+
+impl FromValue for PageSummary {
+    fn from_value(value: Value) -> Result<PageSummary> {
+        Ok(PageSummary {
+            id: value.get_long("id")?,
+            space: value.get_string("space")?,
+            parent_id: value.get_long("parentId")?,
+            title: value.get_string("title")?,
+            url: value.get_string("url")?,
+        })
+    }
+}
+
+impl FromValue for ContentSummary {
+    fn from_value(value: Value) -> Result<ContentSummary> {
+        Ok(ContentSummary {
+            id: value.get_long("id")?,
+            content_type: value.get_string("type")?,
+            space: value.get_string("space")?,
+            status: value.get_string("status")?,
+            title: value.get_string("title")?,
+            created: value.get_datetime("created")?,
+            creator: value.get_string("creator")?,
+            modified: value.get_datetime("modified")?,
+            modifier: value.get_string("modifier")?,
+        })
+    }
+}
+
+impl FromValue for TrashContents {
+    fn from_value(value: Value) -> Result<TrashContents> {
+        let content =
+            value
+                .member("content")
+                .cloned()
+                .ok_or_else(|| XmlRpcError::MissingMember {
+                    name: "content".into(),
+                })?;
+
+        Ok(TrashContents {
+            total_available: value.get_int("totalAvailable")?,
+            offset: value.get_int("offset")?,
+            items: Vec::from_value(content)?,
+        })
+    }
+}
+
+/// Build the `space` argument element for `addSpace`, `addPersonalSpace` and `storeSpace`.
+pub fn update_space_element(space: UpdateSpace) -> Element {
+    let mut element_items = vec![space.key.to_soap("key"), space.name.to_soap("name")];
+
+    if let Some(description) = space.description {
+        element_items.push(description.to_soap("description"));
+    }
+
+    if let Some(home_page) = space.home_page {
+        element_items.push(home_page.to_soap("homePage"));
+    }
+
+    Element::node("space").with_children(element_items)
+}
+
+/// Build the `page` argument element for `storePage` and `updatePage`.
+pub fn update_page_element(page: UpdatePage) -> Element {
+    let mut element_items = vec![
+        page.space.to_soap("space"),
+        page.title.to_soap("title"),
+        page.content.to_soap("content"),
+    ];
+
+    if let Some(id) = page.id {
+        element_items.push(id.to_soap("id"));
+    }
+
+    if let Some(version) = page.version {
+        element_items.push(version.to_soap("version"));
+    }
+
+    if let Some(parent_id) = page.parent_id {
+        element_items.push(parent_id.to_soap("parentId"));
+    }
+
+    Element::node("page").with_children(element_items)
+}
+
+/// Build the `pageUpdateOptions` argument element for `updatePage`.
+pub fn page_update_options_element(options: PageUpdateOptions) -> Element {
+    let mut update_options = vec![];
+
+    if let Some(comment) = options.version_comment {
+        update_options.push(comment.to_soap("versionComment"));
+    }
+
+    update_options.push(options.minor_edit.to_soap("minorEdit"));
+
+    Element::node("pageUpdateOptions").with_children(update_options)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(xml: &str) -> Element {
+        Element::parse(xml.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn reads_space_summaries() {
+        let spaces = Vec::<SpaceSummary>::from_element(parse(
+            r#"<getSpacesReturn xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+                <getSpacesReturn>
+                    <key xsi:type="xsd:string">DOC</key>
+                    <name xsi:type="xsd:string">Documentation</name>
+                    <type xsi:type="xsd:string">global</type>
+                    <url xsi:type="xsd:string">https://confluence/display/DOC</url>
+                </getSpacesReturn>
+                <getSpacesReturn>
+                    <key xsi:type="xsd:string">~admin</key>
+                    <name xsi:type="xsd:string">Administrator</name>
+                    <type xsi:type="xsd:string">personal</type>
+                    <url xsi:type="xsd:string">https://confluence/display/~admin</url>
+                </getSpacesReturn>
+            </getSpacesReturn>"#,
+        ))
+        .unwrap();
+
+        assert_eq!(spaces.len(), 2);
+        assert_eq!(spaces[1].key, "~admin");
+        assert_eq!(spaces[1].space_type, "personal");
+    }
+
+    #[test]
+    fn reads_trash_contents() {
+        let trash = TrashContents::from_element(parse(
+            r#"<getTrashContentsReturn xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+                <content>
+                    <content>
+                        <created xsi:type="xsd:dateTime">2019-04-17T13:45:02.000Z</created>
+                        <creator xsi:type="xsd:string">admin</creator>
+                        <id xsi:type="xsd:long">7</id>
+                        <modified xsi:type="xsd:dateTime">2019-04-18T08:00:00.000Z</modified>
+                        <modifier xsi:type="xsd:string">editor</modifier>
+                        <space xsi:type="xsd:string">DOC</space>
+                        <status xsi:type="xsd:string">deleted</status>
+                        <title xsi:type="xsd:string">Orphan</title>
+                        <type xsi:type="xsd:string">page</type>
+                    </content>
+                </content>
+                <offset xsi:type="xsd:int">0</offset>
+                <totalAvailable xsi:type="xsd:int">1</totalAvailable>
+            </getTrashContentsReturn>"#,
+        ))
+        .unwrap();
+
+        assert_eq!(trash.total_available, 1);
+        assert_eq!(trash.items[0].id, 7);
+        assert_eq!(trash.items[0].status, "deleted");
+        assert_eq!(trash.items[0].modifier, "editor");
+    }
+
+    #[test]
+    fn writes_typed_page_fields() {
+        let mut page = UpdatePage::with_create_fields(Some(7), "DOC", "Guide", "<p>Hi</p>");
+        page.id = Some(42);
+        page.version = Some(3);
+        let element = update_page_element(page);
+
+        let types: Vec<(&str, &str)> = element
+            .children
+            .iter()
+            .map(|field| (field.name.as_str(), field.attributes["xsi:type"].as_str()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("space", "xsd:string"),
+                ("title", "xsd:string"),
+                ("content", "xsd:string"),
+                ("id", "xsd:long"),
+                ("version", "xsd:int"),
+                ("parentId", "xsd:long"),
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_typed_update_options() {
+        let options = page_update_options_element(PageUpdateOptions::new_minor());
+
+        let minor_edit = options.get_child("minorEdit").unwrap();
+        assert_eq!(minor_edit.text.as_deref(), Some("true"));
+        assert_eq!(
+            minor_edit.attributes.get("xsi:type").map(String::as_str),
+            Some("xsd:boolean")
+        );
+        assert!(options.get_child("versionComment").is_none());
+    }
+}
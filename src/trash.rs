@@ -0,0 +1,36 @@
+use chrono::offset::Utc;
+use chrono::DateTime;
+
+/// Summary of a page or blog post, as listed in the trash.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentSummary {
+    /// The id of the content
+    pub id: i64,
+    /// Content type (eg. page or blogpost)
+    pub content_type: String,
+    /// The key of the space that this content belongs to
+    pub space: String,
+    /// Status of the content (eg. current or deleted)
+    pub status: String,
+    /// The title of the content
+    pub title: String,
+    /// Timestamp content was created
+    pub created: DateTime<Utc>,
+    /// Username of the creator
+    pub creator: String,
+    /// Timestamp content was modified
+    pub modified: DateTime<Utc>,
+    /// Username of the content's last modifier
+    pub modifier: String,
+}
+
+/// One batch of the trash of a space, returned by `Session::get_trash_contents`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrashContents {
+    /// Number of items in the trash, in all batches
+    pub total_available: i32,
+    /// Position of the first item of this batch
+    pub offset: i32,
+    /// The items of this batch
+    pub items: Vec<ContentSummary>,
+}
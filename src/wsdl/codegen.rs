@@ -0,0 +1,770 @@
+/*!
+Generator of typed bindings for the operations described by the WSDL.
+
+The generated code is a Rust module with a struct for every complex type and a
+`ConfluenceApi` trait, implemented for `Session`, with a method for every operation.
+It uses only the public API of this crate, so it can be saved into any crate
+depending on `confluence`, `xmltree` and `chrono`.
+
+Method names start with `soap_` (`soap_get_page` for `getPage`), so they are not
+shadowed by the methods of `Session` with the same name. `login` and `logout` are
+skipped, as the session itself is opened with `login`.
+
+The token argument is filled in from the session. Other arguments are named after
+the WSDL parts. Axis names the parts `in0`, `in1`, ..., so the arguments of the
+`confluenceservice-v2` operations are named after the Confluence API instead
+(`soap_get_page(page_id)`, `soap_get_page_2(space_key, page_title)`), and other such
+arguments of a complex type after the type (`page` for `RemotePage`).
+Overloaded operations get a numbered method for each extra signature.
+
+## Example
+
+```no_run
+let wsdl = confluence::wsdl::load("confluenceservice-v2.wsdl").unwrap();
+
+std::fs::write("src/confluence_api.rs", confluence::wsdl::codegen::generate(&wsdl)).unwrap();
+```
+
+See also `examples/generate_bindings.rs`.
+*/
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::model::{Field, Part, Signature, TypeRef, TypeRegistry};
+use super::Wsdl;
+
+/// Name of the generated trait with the operation methods.
+pub const TRAIT_NAME: &str = "ConfluenceApi";
+
+/// Prefix of the generated method names.
+pub const METHOD_PREFIX: &str = "soap_";
+
+/// Operations managing the session, which get no generated method.
+const SESSION_OPERATIONS: &[&str] = &["login", "logout"];
+
+/// Generate the source of the bindings module.
+pub fn generate(wsdl: &Wsdl) -> String {
+    let mut generator = Generator {
+        types: &wsdl.types,
+        uses: Uses::default(),
+    };
+
+    let mut structs = String::new();
+    let mut names: Vec<&str> = wsdl.types.names().collect();
+    names.sort();
+    for name in names {
+        let complex = wsdl.types.get(name).unwrap();
+        if complex.array_of.is_none() {
+            generator.write_struct(&mut structs, name, &wsdl.types.all_fields(name));
+        }
+    }
+
+    let mut declarations = String::new();
+    let mut implementations = String::new();
+    let operations: BTreeMap<_, _> = wsdl.operations.iter().collect();
+    for (name, operation) in operations {
+        if SESSION_OPERATIONS.contains(&name.as_str()) {
+            continue;
+        }
+        let signatures = operation
+            .signatures
+            .iter()
+            .filter(|signature| signature.input.is_some());
+        for (index, signature) in signatures.enumerate() {
+            let method_name = match index {
+                0 => format!("{}{}", METHOD_PREFIX, snake_case(name)),
+                _ => format!("{}{}_{}", METHOD_PREFIX, snake_case(name), index + 1),
+            };
+            generator.write_method(
+                &mut declarations,
+                &mut implementations,
+                name,
+                &method_name,
+                signature,
+            );
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str("// Typed bindings for the Confluence SOAP service.\n");
+    out.push_str("//\n");
+    out.push_str("// Generated from the WSDL by `confluence::wsdl::codegen`, do not edit.\n\n");
+    generator.uses.write(&mut out);
+    out.push_str(&structs);
+
+    if !declarations.is_empty() {
+        let _ = writeln!(
+            out,
+            "/// Typed wrappers for the operations of the Confluence SOAP service."
+        );
+        let _ = writeln!(out, "pub trait {} {{", TRAIT_NAME);
+        out.push_str(declarations.trim_end());
+        out.push_str("\n}\n\n");
+        let _ = writeln!(out, "impl {} for Session {{", TRAIT_NAME);
+        out.push_str(implementations.trim_end());
+        out.push_str("\n}\n");
+    }
+
+    out
+}
+
+/// Imports needed by the generated code.
+#[derive(Default)]
+struct Uses {
+    datetime: bool,
+    element: bool,
+    array: bool,
+    field: bool,
+    soap_value: bool,
+    method: bool,
+    from_element: bool,
+}
+
+impl Uses {
+    fn write(&self, out: &mut String) {
+        if self.datetime {
+            out.push_str("use chrono::{DateTime, Utc};\n");
+        }
+
+        let mut value = vec![];
+        if self.array {
+            value.push("array");
+        }
+        if self.field {
+            value.push("field");
+        }
+        if self.soap_value {
+            value.push("SoapValue");
+        }
+        if !value.is_empty() {
+            let _ = writeln!(
+                out,
+                "use confluence::rpser::value::{{{}}};",
+                value.join(", ")
+            );
+        }
+
+        if self.element {
+            out.push_str("use confluence::rpser::xml::BuildElement;\n");
+        }
+        if self.method {
+            out.push_str("use confluence::rpser::Method;\n");
+            if self.from_element {
+                out.push_str("use confluence::{FromElement, Result, Session};\n");
+            } else {
+                out.push_str("use confluence::{Result, Session};\n");
+            }
+        } else if self.from_element {
+            out.push_str("use confluence::{FromElement, Result};\n");
+        }
+        if self.element {
+            out.push_str("use xmltree::Element;\n");
+        }
+        out.push('\n');
+    }
+}
+
+struct Generator<'a> {
+    types: &'a TypeRegistry,
+    uses: Uses,
+}
+
+impl<'a> Generator<'a> {
+    fn write_struct(&mut self, out: &mut String, name: &str, fields: &[&Field]) {
+        let ident = type_ident(name);
+        self.uses.element = true;
+        self.uses.field = true;
+        self.uses.soap_value = true;
+        self.uses.from_element = true;
+
+        let _ = writeln!(out, "/// `{}` complex type.", name);
+        let _ = writeln!(out, "#[derive(Debug, Clone, Default, PartialEq)]");
+        let _ = writeln!(out, "pub struct {} {{", ident);
+        for field in fields {
+            let _ = writeln!(
+                out,
+                "    pub {}: Option<{}>,",
+                value_ident(&field.name),
+                self.rust_type(&field.type_ref)
+            );
+        }
+        out.push_str("}\n\n");
+
+        let _ = writeln!(out, "impl SoapValue for {} {{", ident);
+        let _ = writeln!(
+            out,
+            "    fn from_soap({}: &Element) -> Result<{}> {{",
+            if fields.is_empty() {
+                "_element"
+            } else {
+                "element"
+            },
+            ident
+        );
+        let _ = writeln!(out, "        Ok({} {{", ident);
+        for field in fields {
+            let _ = writeln!(
+                out,
+                "            {}: field(element, {:?})?,",
+                value_ident(&field.name),
+                field.name
+            );
+        }
+        out.push_str("        })\n    }\n\n");
+        out.push_str("    fn to_soap(&self, name: &str) -> Element {\n");
+        if fields.is_empty() {
+            out.push_str("        Element::node(name)\n");
+        } else {
+            out.push_str("        let mut element = Element::node(name);\n");
+            for field in fields {
+                let _ = writeln!(
+                    out,
+                    "        if let Some(ref value) = self.{} {{\n            element = element.with_child(value.to_soap({:?}));\n        }}",
+                    value_ident(&field.name),
+                    field.name
+                );
+            }
+            out.push_str("        element\n");
+        }
+        out.push_str("    }\n}\n\n");
+
+        let _ = writeln!(out, "impl FromElement for {} {{", ident);
+        let _ = writeln!(
+            out,
+            "    fn from_element(element: Element) -> Result<{}> {{\n        {}::from_soap(&element)\n    }}",
+            ident, ident
+        );
+        out.push_str("}\n\n");
+    }
+
+    fn write_method(
+        &mut self,
+        declarations: &mut String,
+        implementations: &mut String,
+        operation: &str,
+        method_name: &str,
+        signature: &Signature,
+    ) {
+        self.uses.method = true;
+        self.uses.element = true;
+
+        let parts = &signature.input.as_ref().unwrap().parts;
+        let takes_token = parts
+            .first()
+            .is_some_and(|part| part.type_ref == TypeRef::Builtin("string".into()));
+
+        let skipped = if takes_token { 1 } else { 0 };
+        let names = v2_parameters(operation, parts.len() - skipped);
+
+        let mut params = vec!["&self".to_string()];
+        let mut idents: Vec<String> = vec![];
+        let mut args = vec![];
+        for (index, part) in parts.iter().enumerate() {
+            if index < skipped {
+                args.push("Element::node(\"token\").with_text(self.token())".to_string());
+                continue;
+            }
+            let name = names.map(|names| names[index - skipped]);
+            let ident = match param_ident(part, name) {
+                Some(ref ident) if !idents.contains(ident) => ident.clone(),
+                _ => value_ident(&part.name),
+            };
+            let (param, arg) = self.param(&ident, part);
+            idents.push(ident);
+            params.push(param);
+            args.push(arg);
+        }
+
+        let output = signature
+            .output
+            .as_ref()
+            .and_then(|message| message.parts.first());
+        let return_type = match output {
+            Some(part) => self.rust_type(&part.type_ref),
+            None => "()".into(),
+        };
+
+        let header = format!(
+            "    fn {}({}) -> Result<{}>",
+            method_name,
+            params.join(", "),
+            return_type
+        );
+
+        let _ = writeln!(declarations, "    /// Calls `{}`.", operation);
+        let _ = writeln!(declarations, "{};\n", header);
+
+        let _ = writeln!(implementations, "{} {{", header);
+        let _ = write!(
+            implementations,
+            "        {}self.call(\n            Method::new({:?})",
+            if output.is_some() {
+                "let response = "
+            } else {
+                ""
+            },
+            operation
+        );
+        for arg in args {
+            let _ = write!(implementations, "\n                .with({})", arg);
+        }
+        implementations.push_str(",\n        )?;\n\n");
+        match output {
+            Some(part) => {
+                self.uses.soap_value = true;
+                let _ = writeln!(
+                    implementations,
+                    "        SoapValue::from_soap(&response.body.descend(&[{:?}])?)",
+                    part.name
+                );
+            }
+            None => implementations.push_str("        Ok(())\n"),
+        }
+        implementations.push_str("    }\n\n");
+    }
+
+    /// Parameter declaration and the argument expression for the part.
+    fn param(&mut self, ident: &str, part: &Part) -> (String, String) {
+        let rust_type = self.rust_type(&part.type_ref);
+
+        if rust_type == "String" {
+            (
+                format!("{}: &str", ident),
+                format!("Element::node({:?}).with_text({})", part.name, ident),
+            )
+        } else if rust_type.starts_with("Vec<") {
+            self.uses.array = true;
+            (
+                format!("{}: &[{}]", ident, &rust_type[4..rust_type.len() - 1]),
+                format!("array({:?}, {})", part.name, ident),
+            )
+        } else {
+            self.uses.soap_value = true;
+            let by_value =
+                ["i64", "i32", "bool", "f64", "DateTime<Utc>"].contains(&rust_type.as_str());
+            (
+                format!(
+                    "{}: {}{}",
+                    ident,
+                    if by_value { "" } else { "&" },
+                    rust_type
+                ),
+                format!("{}.to_soap({:?})", ident, part.name),
+            )
+        }
+    }
+
+    fn rust_type(&mut self, type_ref: &TypeRef) -> String {
+        match *type_ref {
+            TypeRef::Builtin(ref name) => match name.as_str() {
+                "long" => "i64".into(),
+                "int" | "short" | "byte" => "i32".into(),
+                "boolean" => "bool".into(),
+                "double" | "float" | "decimal" => "f64".into(),
+                "dateTime" => {
+                    self.uses.datetime = true;
+                    "DateTime<Utc>".into()
+                }
+                _ => "String".into(),
+            },
+            TypeRef::Named(ref name) => match self.types.get(name) {
+                Some(complex) => match complex.array_of {
+                    Some(ref item) => format!("Vec<{}>", self.rust_type(item)),
+                    None => type_ident(name),
+                },
+                None => {
+                    self.uses.element = true;
+                    "Element".into()
+                }
+            },
+        }
+    }
+}
+
+/// Parameter name for the part, if Axis named it `inN` then the known `name`
+/// or after its complex type.
+fn param_ident(part: &Part, name: Option<&str>) -> Option<String> {
+    let placeholder = part
+        .name
+        .strip_prefix("in")
+        .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()));
+    if !placeholder {
+        return Some(value_ident(&part.name));
+    }
+    if let Some(name) = name {
+        return Some(value_ident(name));
+    }
+
+    match part.type_ref {
+        TypeRef::Named(ref name) => {
+            // ArrayOf_tns1_RemotePage -> pages, RemotePageUpdateOptions -> page_update_options
+            let (name, plural) = match name.strip_prefix("ArrayOf_") {
+                Some(item) => (item.rsplit('_').next().unwrap_or(item), "s"),
+                None => (name.as_str(), ""),
+            };
+            let name = name.strip_prefix("Remote").unwrap_or(name);
+            Some(value_ident(&format!("{}{}", name, plural)))
+        }
+        TypeRef::Builtin(_) => None,
+    }
+}
+
+/// Convert `camelCase` WSDL name to `snake_case`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+
+    out
+}
+
+/// Identifier for a field or parameter, escaping Rust keywords.
+fn value_ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "box", "break", "const", "continue", "crate", "do",
+        "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let",
+        "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+        "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe",
+        "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+
+    let ident = snake_case(&sanitize(name));
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident + "_"
+    } else {
+        ident
+    }
+}
+
+/// Identifier for a struct.
+fn type_ident(name: &str) -> String {
+    sanitize(name)
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Names of the parameters after the token of the `confluenceservice-v2` operations,
+/// for each signature, as documented for `ConfluenceSoapService`.
+const V2_PARAMETERS: &[(&str, &[&str])] = &[
+    ("addAnonymousPermissionToSpace", &["permission", "spaceKey"]),
+    (
+        "addAnonymousPermissionsToSpace",
+        &["permissions", "spaceKey"],
+    ),
+    (
+        "addAttachment",
+        &["contentId", "attachment", "attachmentData"],
+    ),
+    ("addAttachment", &["attachment", "attachmentData"]),
+    ("addComment", &["comment"]),
+    ("addGroup", &["group"]),
+    ("addLabelById", &["labelId", "objectId"]),
+    ("addLabelByName", &["labelName", "objectId"]),
+    ("addLabelByNameToSpace", &["labelName", "spaceKey"]),
+    ("addLabelByObject", &["labelObject", "objectId"]),
+    (
+        "addPermissionToSpace",
+        &["permission", "remoteEntityName", "spaceKey"],
+    ),
+    (
+        "addPermissionsToSpace",
+        &["permissions", "remoteEntityName", "spaceKey"],
+    ),
+    ("addPersonalSpace", &["space", "username"]),
+    (
+        "addPersonalSpaceWithDefaultPermissions",
+        &["space", "username"],
+    ),
+    (
+        "addProfilePicture",
+        &["userName", "fileName", "mimeType", "pictureData"],
+    ),
+    ("addSpace", &["space"]),
+    ("addSpaceWithDefaultPermissions", &["space"]),
+    ("addUser", &["user", "password"]),
+    ("addUser", &["user", "password", "notifyUser"]),
+    ("addUserToGroup", &["username", "groupname"]),
+    ("changeMyPassword", &["oldPassword", "newPassword"]),
+    ("changeUserPassword", &["username", "newPassword"]),
+    (
+        "convertToPersonalSpace",
+        &["userName", "spaceKey", "newSpaceName", "updateLinks"],
+    ),
+    ("deactivateUser", &["username"]),
+    ("editComment", &["comment"]),
+    ("editUser", &["user"]),
+    ("emptyTrash", &["spaceKey"]),
+    ("exportSite", &["exportAttachments"]),
+    ("exportSpace", &["spaceKey", "exportType"]),
+    ("exportSpace", &["spaceKey", "exportType", "exportAll"]),
+    ("getActiveUsers", &["viewAll"]),
+    ("getAncestors", &["pageId"]),
+    ("getAttachment", &["contentId", "fileName", "version"]),
+    ("getAttachmentData", &["contentId", "fileName", "version"]),
+    ("getAttachments", &["pageId"]),
+    ("getBlogEntries", &["spaceKey"]),
+    ("getBlogEntry", &["entryId"]),
+    (
+        "getBlogEntryByDateAndTitle",
+        &["spaceKey", "year", "month", "dayOfMonth", "postTitle"],
+    ),
+    (
+        "getBlogEntryByDayAndTitle",
+        &["spaceKey", "dayOfMonth", "postTitle"],
+    ),
+    ("getChildren", &["pageId"]),
+    ("getComment", &["commentId"]),
+    ("getComments", &["pageId"]),
+    ("getContentPermissionSet", &["contentId", "permissionType"]),
+    ("getContentPermissionSets", &["contentId"]),
+    ("getDescendents", &["pageId"]),
+    ("getLabelContentById", &["labelId"]),
+    ("getLabelContentByName", &["labelName"]),
+    ("getLabelContentByObject", &["labelObject"]),
+    (
+        "getLabelsByDetail",
+        &["labelName", "namespace", "spaceKey", "owner"],
+    ),
+    ("getLabelsById", &["objectId"]),
+    ("getMostPopularLabels", &["maxCount"]),
+    ("getMostPopularLabelsInSpace", &["spaceKey", "maxCount"]),
+    ("getPage", &["pageId"]),
+    ("getPage", &["spaceKey", "pageTitle"]),
+    ("getPageHistory", &["pageId"]),
+    ("getPagePermissions", &["pageId"]),
+    ("getPageSummary", &["pageId"]),
+    ("getPageSummary", &["spaceKey", "pageTitle"]),
+    ("getPages", &["spaceKey"]),
+    ("getPermissions", &["spaceKey"]),
+    ("getPermissionsForUser", &["spaceKey", "userName"]),
+    ("getRecentlyUsedLabels", &["maxResults"]),
+    ("getRecentlyUsedLabelsInSpace", &["spaceKey", "maxResults"]),
+    ("getRelatedLabels", &["labelName", "maxResults"]),
+    (
+        "getRelatedLabelsInSpace",
+        &["labelName", "spaceKey", "maxResults"],
+    ),
+    ("getSpace", &["spaceKey"]),
+    ("getSpacePermissionSet", &["spaceKey", "permissionType"]),
+    ("getSpacePermissionSets", &["spaceKey"]),
+    ("getSpaceStatus", &["spaceKey"]),
+    ("getSpacesContainingContentWithLabel", &["labelName"]),
+    ("getSpacesInGroup", &["spaceGroupKey"]),
+    ("getSpacesWithLabel", &["labelName"]),
+    ("getTrashContents", &["spaceKey", "offset", "count"]),
+    ("getUser", &["username"]),
+    ("getUserGroups", &["username"]),
+    ("getUserInformation", &["username"]),
+    ("getUserPreferenceBoolean", &["key"]),
+    ("getUserPreferenceLong", &["key"]),
+    ("getUserPreferenceString", &["key"]),
+    ("getWatchersForPage", &["pageId"]),
+    ("getWatchersForSpace", &["spaceKey"]),
+    ("hasGroup", &["groupname"]),
+    ("hasUser", &["username"]),
+    ("importSpace", &["importData"]),
+    ("isActiveUser", &["username"]),
+    ("isDarkFeatureEnabled", &["key"]),
+    ("isPluginEnabled", &["pluginKey"]),
+    ("isPluginInstalled", &["pluginKey"]),
+    ("isWatchingPage", &["pageId", "username"]),
+    ("isWatchingSpace", &["spaceKey", "username"]),
+    (
+        "moveAttachment",
+        &[
+            "originalContentId",
+            "originalName",
+            "newContentId",
+            "newName",
+        ],
+    ),
+    ("movePage", &["sourcePageId", "targetPageId", "position"]),
+    ("movePageToTopLevel", &["pageId", "targetSpaceKey"]),
+    ("purgeFromTrash", &["spaceKey", "contentId"]),
+    ("reactivateUser", &["username"]),
+    ("removeAllPermissionsForGroup", &["groupname"]),
+    (
+        "removeAnonymousPermissionFromSpace",
+        &["permission", "spaceKey"],
+    ),
+    ("removeAttachment", &["contentId", "fileName"]),
+    ("removeComment", &["commentId"]),
+    ("removeGroup", &["groupname", "defaultGroupName"]),
+    ("removeLabelById", &["labelId", "objectId"]),
+    ("removeLabelByName", &["labelName", "objectId"]),
+    ("removeLabelByNameFromSpace", &["labelName", "spaceKey"]),
+    ("removeLabelByObject", &["labelObject", "objectId"]),
+    ("removePage", &["pageId"]),
+    ("removePageVersionById", &["historicalPageId"]),
+    ("removePageVersionByVersion", &["pageId", "version"]),
+    ("removePageWatch", &["pageId"]),
+    ("removePageWatchForUser", &["pageId", "username"]),
+    (
+        "removePermissionFromSpace",
+        &["permission", "remoteEntityName", "spaceKey"],
+    ),
+    ("removeSpace", &["spaceKey"]),
+    ("removeSpaceWatch", &["spaceKey"]),
+    ("removeUser", &["username"]),
+    ("removeUserFromGroup", &["username", "groupname"]),
+    ("renameUser", &["oldUsername", "newUsername"]),
+    ("renameUsers", &["oldUsernamesToNewUsernames"]),
+    ("renderContent", &["spaceKey", "pageId", "content"]),
+    (
+        "renderContent",
+        &["spaceKey", "pageId", "content", "parameters"],
+    ),
+    ("search", &["query", "maxResults"]),
+    ("search", &["query", "parameters", "maxResults"]),
+    (
+        "setContentPermissions",
+        &["contentId", "permissionType", "permissions"],
+    ),
+    ("setEnableAnonymousAccess", &["value"]),
+    ("setEnableWysiwyg", &["value"]),
+    ("setSpaceStatus", &["spaceKey", "status"]),
+    ("setUserInformation", &["userInfo"]),
+    ("setUserPreferenceBoolean", &["key", "value"]),
+    ("setUserPreferenceLong", &["key", "value"]),
+    ("setUserPreferenceString", &["key", "value"]),
+    ("startActivity", &["key", "user"]),
+    ("stopActivity", &["key", "user"]),
+    ("storeBlogEntry", &["entry"]),
+    ("storePage", &["page"]),
+    ("storeSpace", &["space"]),
+    ("updatePage", &["page", "pageUpdateOptions"]),
+    ("watchPage", &["pageId"]),
+    ("watchPageForUser", &["pageId", "username"]),
+    ("watchSpace", &["spaceKey"]),
+];
+
+/// Parameter names of the `confluenceservice-v2` operation taking `count` arguments.
+fn v2_parameters(operation: &str, count: usize) -> Option<&'static [&'static str]> {
+    V2_PARAMETERS
+        .iter()
+        .find(|&&(name, params)| name == operation && params.len() == count)
+        .map(|&(_, params)| params)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::parse;
+    use super::super::test::FULL_WSDL;
+    use super::*;
+
+    /// All operations of `confluenceservice-v2`, with the part names generated by Axis.
+    const V2_WSDL: &str = include_str!("../../tests/fixtures/confluenceservice-v2-full.wsdl");
+
+    #[test]
+    fn converts_names() {
+        assert_eq!(snake_case("getPageSummaryByUrl"), "get_page_summary_by_url");
+        assert_eq!(snake_case("getRSSFeed"), "get_rss_feed");
+        assert_eq!(value_ident("type"), "type_");
+        assert_eq!(value_ident("in1"), "in1");
+
+        let part = |name: &str, type_name: &str| Part {
+            name: name.into(),
+            type_ref: TypeRef::Named(type_name.into()),
+        };
+        assert_eq!(
+            param_ident(&part("in2", "RemotePageUpdateOptions"), None),
+            Some("page_update_options".into())
+        );
+        assert_eq!(
+            param_ident(&part("in1", "ArrayOf_tns1_RemotePage"), None),
+            Some("pages".into())
+        );
+        assert_eq!(
+            param_ident(
+                &part("in2", "RemotePageUpdateOptions"),
+                Some("pageUpdateOptions")
+            ),
+            Some("page_update_options".into())
+        );
+        assert_eq!(
+            param_ident(&part("pageId", "long"), Some("id")),
+            Some("page_id".into())
+        );
+    }
+
+    #[test]
+    fn generated_fixture_is_up_to_date() {
+        // tests/generated_bindings.rs compiles the fixture against this crate
+        assert_eq!(
+            generate(&parse(V2_WSDL).unwrap()),
+            include_str!("../../tests/fixtures/confluence_api.rs"),
+            "regenerate tests/fixtures/confluence_api.rs with examples/generate_bindings.rs"
+        );
+    }
+
+    #[test]
+    fn generates_structs_and_methods() {
+        let code = generate(&parse(FULL_WSDL).unwrap());
+
+        assert!(code.contains(
+            "pub struct RemotePage {\n    pub id: Option<i64>,\n    pub space: Option<String>,"
+        ));
+        assert!(code.contains("    pub version: Option<i32>,\n}"));
+        assert!(!code.contains("struct ArrayOf_tns1_RemotePage"));
+
+        assert!(code.contains("    fn soap_get_page(&self, page_id: i64) -> Result<RemotePage>;"));
+        assert!(code.contains(
+            "    fn soap_get_page_2(&self, space_key: &str, page_title: &str) -> Result<RemotePage>;"
+        ));
+        assert!(
+            code.contains("    fn soap_store_page(&self, page: &RemotePage) -> Result<RemotePage>")
+        );
+        assert!(code.contains(
+            "            Method::new(\"getPage\")\n                .with(Element::node(\"token\").with_text(self.token()))\n                .with(Element::node(\"in1\").with_text(space_key))\n                .with(Element::node(\"in2\").with_text(page_title)),"
+        ));
+        assert!(code.contains(
+            "            Method::new(\"storePage\")\n                .with(Element::node(\"token\").with_text(self.token()))\n                .with(page.to_soap(\"in1\")),"
+        ));
+        assert!(code.contains("impl ConfluenceApi for Session {"));
+        assert!(!code.contains("login"));
+    }
+
+    #[test]
+    fn names_all_v2_parameters() {
+        let wsdl = parse(V2_WSDL).unwrap();
+        let code = generate(&wsdl);
+
+        for name in wsdl.operations.keys() {
+            if !SESSION_OPERATIONS.contains(&name.as_str()) {
+                let method = format!("fn {}{}(", METHOD_PREFIX, snake_case(name));
+                assert!(code.contains(&method), "{}", method);
+            }
+        }
+        for number in 1..6 {
+            let param = format!(" in{}: ", number);
+            assert!(
+                !code.contains(&param),
+                "parameter named after the Axis part"
+            );
+        }
+        assert!(code.contains(
+            "    fn soap_update_page(&self, page: &RemotePage, page_update_options: &RemotePageUpdateOptions) -> Result<RemotePage>;"
+        ));
+        assert!(code.contains(
+            "    fn soap_search_2(&self, query: &str, parameters: &Element, max_results: i32) -> Result<Vec<RemoteSearchResult>>;"
+        ));
+    }
+}
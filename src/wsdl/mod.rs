@@ -0,0 +1,550 @@
+//! WSDL inspection helpers.
+
+use futures::Future;
+use http;
+use reqwest::async::Client as AsyncClient;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+use wsdl_url;
+
+pub mod codegen;
+mod model;
+mod parse;
+mod validate;
+
+pub use self::model::{
+    Binding, BindingOperation, ComplexType, Field, Message, Part, Signature, TypeRef, TypeRegistry,
+};
+pub use self::parse::{parse, ParseError};
+pub use self::validate::ValidationError;
+
+/// WSDL operation info.
+#[derive(Debug, Clone)]
+pub struct Operation {
+    pub url: String,
+    /// Signatures of the operation, more than one if it is overloaded.
+    ///
+    /// Empty if the operation was not read from a full WSDL document.
+    pub signatures: Vec<Signature>,
+}
+
+impl Operation {
+    /// Operation at the url, with no known signatures.
+    pub fn new<S: Into<String>>(url: S) -> Operation {
+        Operation {
+            url: url.into(),
+            signatures: vec![],
+        }
+    }
+}
+
+/// WSDL document.
+#[derive(Debug, Clone, Default)]
+pub struct Wsdl {
+    pub operations: HashMap<String, Operation>,
+    /// Messages, by name.
+    pub messages: HashMap<String, Message>,
+    /// Complex types from the schema.
+    pub types: TypeRegistry,
+    /// SOAP bindings, by name.
+    pub bindings: HashMap<String, Binding>,
+}
+
+impl Wsdl {
+    /**
+    Operations of the Confluence v2 SOAP service, bundled with this library.
+
+    Lets a session start without fetching the WSDL. Operations added by plugins
+    or newer Confluence versions are missing, and calls to them fail with
+    `Error::MethodNotFoundInWsdl`.
+
+    ## Example
+
+    ```
+    let wsdl = confluence::wsdl::Wsdl::bundled_v2("https://confluence");
+
+    assert_eq!(
+        wsdl.operations["getPage"].url,
+        "https://confluence/rpc/soap-axis/confluenceservice-v2"
+    );
+    ```
+    */
+    pub fn bundled_v2(base_url: &str) -> Wsdl {
+        let wsdl_url = wsdl_url(base_url);
+        let url = wsdl_url.trim_end_matches("?wsdl");
+
+        Wsdl {
+            operations: V2_OPERATIONS
+                .iter()
+                .map(|name| (name.to_string(), Operation::new(url)))
+                .collect(),
+            ..Wsdl::default()
+        }
+    }
+}
+
+/// Fetch WSDL from specified URL and store results in `Wsdl` structure.
+pub fn fetch(url: &str) -> http::Result<Wsdl> {
+    fetch_with(&http::ReqwestTransport::new(), url)
+}
+
+/// Fetch WSDL from specified URL over the given transport.
+pub fn fetch_with<T: http::Transport + ?Sized>(transport: &T, url: &str) -> http::Result<Wsdl> {
+    let response = transport.get(url)?;
+
+    from_response(&response)
+}
+
+/// Fetch WSDL from specified URL, keeping a copy in the `cache` file.
+///
+/// The cached copy is used instead of fetching while it is younger than `ttl`.
+/// Failure to read, parse or write the cache is logged, and the WSDL is fetched instead.
+/// The cache is replaced atomically, so an interrupted write leaves the previous copy.
+pub fn fetch_cached<T, P>(transport: &T, url: &str, cache: P, ttl: Duration) -> http::Result<Wsdl>
+where
+    T: http::Transport + ?Sized,
+    P: AsRef<Path>,
+{
+    let cache = cache.as_ref();
+
+    match read_fresh(cache, ttl) {
+        Ok(Some(xml)) => match parse(&xml) {
+            Ok(wsdl) => {
+                debug!("using cached wsdl from {:?}", cache);
+                return Ok(wsdl);
+            }
+            Err(e) => warn!("ignoring cached wsdl at {:?}: {}", cache, e),
+        },
+        Ok(None) => debug!("cached wsdl at {:?} is missing or expired", cache),
+        Err(e) => warn!("failed to read cached wsdl from {:?}: {}", cache, e),
+    }
+
+    let response = transport.get(url)?;
+    let wsdl = from_response(&response)?;
+
+    if let Err(e) = write_atomically(cache, &response.body) {
+        warn!("failed to write wsdl cache to {:?}: {}", cache, e);
+    }
+
+    Ok(wsdl)
+}
+
+/// Write the file next to the cache, and rename it over the cache.
+fn write_atomically(cache: &Path, contents: &str) -> io::Result<()> {
+    let mut file_name = cache
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "cache path has no file name"))?
+        .to_os_string();
+    file_name.push(format!(".{}.tmp", process::id()));
+    let temporary = cache.with_file_name(file_name);
+
+    fs::write(&temporary, contents)?;
+    let renamed = fs::rename(&temporary, cache);
+    if renamed.is_err() {
+        let _ = fs::remove_file(&temporary);
+    }
+    renamed
+}
+
+/// Read the cache file, unless it does not exist or is older than `ttl`.
+fn read_fresh(cache: &Path, ttl: Duration) -> io::Result<Option<String>> {
+    let modified = match fs::metadata(cache) {
+        Ok(metadata) => metadata.modified()?,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    match modified.elapsed() {
+        Ok(age) if age < ttl => fs::read_to_string(cache).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Load WSDL from a local file.
+///
+/// A file which is not a WSDL document fails with `io::ErrorKind::InvalidData`.
+pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Wsdl> {
+    parse(&fs::read_to_string(path)?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Fetch WSDL from specified URL without blocking, using the given client.
+pub fn fetch_async(
+    client: &AsyncClient,
+    url: &str,
+) -> Box<dyn Future<Item = Wsdl, Error = http::HttpError> + Send> {
+    Box::new(http::get_async(client, url).and_then(|response| from_response(&response)))
+}
+
+fn from_response(response: &http::Response) -> http::Result<Wsdl> {
+    if !response.status.is_success() {
+        return Err(http::HttpError::Status {
+            status: response.status,
+            body_excerpt: response.body_excerpt(),
+        });
+    }
+
+    parse(&response.body).map_err(|e| http::HttpError::Body {
+        status: response.status,
+        error: io::Error::new(io::ErrorKind::InvalidData, e),
+    })
+}
+
+/// Operations of `confluenceservice-v2`, as listed in the WSDL of Confluence 5.x.
+const V2_OPERATIONS: &[&str] = &[
+    "addAnonymousPermissionToSpace",
+    "addAnonymousPermissionsToSpace",
+    "addAttachment",
+    "addComment",
+    "addGroup",
+    "addLabelById",
+    "addLabelByName",
+    "addLabelByNameToSpace",
+    "addLabelByObject",
+    "addPermissionToSpace",
+    "addPermissionsToSpace",
+    "addPersonalSpace",
+    "addPersonalSpaceWithDefaultPermissions",
+    "addProfilePicture",
+    "addSpace",
+    "addSpaceWithDefaultPermissions",
+    "addUser",
+    "addUserToGroup",
+    "changeMyPassword",
+    "changeUserPassword",
+    "clearIndexQueue",
+    "convertToPersonalSpace",
+    "deactivateUser",
+    "editComment",
+    "editUser",
+    "emptyTrash",
+    "exportSite",
+    "exportSpace",
+    "flushIndexQueue",
+    "getActiveUsers",
+    "getAncestors",
+    "getAttachment",
+    "getAttachmentData",
+    "getAttachments",
+    "getBlogEntries",
+    "getBlogEntry",
+    "getBlogEntryByDateAndTitle",
+    "getBlogEntryByDayAndTitle",
+    "getChildren",
+    "getClusterInformation",
+    "getClusterNodeStatuses",
+    "getComment",
+    "getComments",
+    "getContentPermissionSet",
+    "getContentPermissionSets",
+    "getDescendents",
+    "getGroups",
+    "getLabelContentById",
+    "getLabelContentByName",
+    "getLabelContentByObject",
+    "getLabelsByDetail",
+    "getLabelsById",
+    "getMostPopularLabels",
+    "getMostPopularLabelsInSpace",
+    "getPage",
+    "getPageHistory",
+    "getPagePermissions",
+    "getPageSummary",
+    "getPages",
+    "getPermissions",
+    "getPermissionsForUser",
+    "getRecentlyUsedLabels",
+    "getRecentlyUsedLabelsInSpace",
+    "getRelatedLabels",
+    "getRelatedLabelsInSpace",
+    "getServerInfo",
+    "getSpace",
+    "getSpaceLevelPermissions",
+    "getSpacePermissionSet",
+    "getSpacePermissionSets",
+    "getSpaceStatus",
+    "getSpaces",
+    "getSpacesContainingContentWithLabel",
+    "getSpacesInGroup",
+    "getSpacesWithLabel",
+    "getTrashContents",
+    "getUser",
+    "getUserGroups",
+    "getUserInformation",
+    "getUserPreferenceBoolean",
+    "getUserPreferenceLong",
+    "getUserPreferenceString",
+    "getWatchersForPage",
+    "getWatchersForSpace",
+    "hasGroup",
+    "hasUser",
+    "importSpace",
+    "isActiveUser",
+    "isDarkFeatureEnabled",
+    "isPluginEnabled",
+    "isPluginInstalled",
+    "isWatchingPage",
+    "isWatchingSpace",
+    "login",
+    "logout",
+    "moveAttachment",
+    "movePage",
+    "movePageToTopLevel",
+    "purgeFromTrash",
+    "reactivateUser",
+    "removeAllPermissionsForGroup",
+    "removeAnonymousPermissionFromSpace",
+    "removeAttachment",
+    "removeComment",
+    "removeGroup",
+    "removeLabelById",
+    "removeLabelByName",
+    "removeLabelByNameFromSpace",
+    "removeLabelByObject",
+    "removePage",
+    "removePageVersionById",
+    "removePageVersionByVersion",
+    "removePageWatch",
+    "removePageWatchForUser",
+    "removePermissionFromSpace",
+    "removeSpace",
+    "removeSpaceWatch",
+    "removeUser",
+    "removeUserFromGroup",
+    "renameUser",
+    "renameUsers",
+    "renderContent",
+    "search",
+    "setContentPermissions",
+    "setEnableAnonymousAccess",
+    "setEnableWysiwyg",
+    "setSpaceStatus",
+    "setUserInformation",
+    "setUserPreferenceBoolean",
+    "setUserPreferenceLong",
+    "setUserPreferenceString",
+    "startActivity",
+    "stopActivity",
+    "storeBlogEntry",
+    "storePage",
+    "storeSpace",
+    "updatePage",
+    "watchPage",
+    "watchPageForUser",
+    "watchSpace",
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fixtures::CannedTransport;
+    use std::env;
+
+    const WSDL: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <wsdl:definitions xmlns:impl="https://confluence/rpc/soap-axis/confluenceservice-v2" xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/">
+            <wsdl:portType name="ConfluenceSoapService">
+                <wsdl:operation name="login"/>
+                <wsdl:operation name="getPage"/>
+            </wsdl:portType>
+        </wsdl:definitions>
+    "#;
+
+    /// Excerpt of the Confluence WSDL, as generated by Axis.
+    pub(super) const FULL_WSDL: &str =
+        include_str!("../../tests/fixtures/confluenceservice-v2.wsdl");
+
+    /// Transport which serves the WSDL and counts the requests.
+    fn transport() -> CannedTransport {
+        CannedTransport::new(&[]).with_get("/rpc/soap-axis/confluenceservice-v2", WSDL)
+    }
+
+    #[test]
+    fn parses_operations_from_string() {
+        let wsdl = parse(WSDL).unwrap();
+
+        assert_eq!(wsdl.operations.len(), 2);
+        assert_eq!(
+            wsdl.operations["getPage"].url,
+            "https://confluence/rpc/soap-axis/confluenceservice-v2"
+        );
+    }
+
+    #[test]
+    fn uses_cache_until_it_expires() {
+        let cache = env::temp_dir().join(format!("confluence-wsdl-{}.xml", std::process::id()));
+        let _ = fs::remove_file(&cache);
+        let transport = transport();
+        let url = "https://confluence/rpc/soap-axis/confluenceservice-v2?wsdl";
+
+        let ttl = Duration::from_secs(60);
+        assert_eq!(
+            fetch_cached(&transport, url, &cache, ttl)
+                .unwrap()
+                .operations
+                .len(),
+            2
+        );
+        assert_eq!(
+            fetch_cached(&transport, url, &cache, ttl)
+                .unwrap()
+                .operations
+                .len(),
+            2
+        );
+        assert_eq!(transport.wsdl_requests(), 1);
+
+        assert_eq!(load(&cache).unwrap().operations.len(), 2);
+
+        fetch_cached(&transport, url, &cache, Duration::from_secs(0)).unwrap();
+        assert_eq!(transport.wsdl_requests(), 2);
+
+        fs::remove_file(&cache).unwrap();
+    }
+
+    #[test]
+    fn fetches_again_when_cache_is_corrupt() {
+        let cache = env::temp_dir().join(format!(
+            "confluence-wsdl-corrupt-{}.xml",
+            std::process::id()
+        ));
+        fs::write(&cache, &WSDL[..WSDL.len() / 2]).unwrap();
+        let transport = transport();
+        let url = "https://confluence/rpc/soap-axis/confluenceservice-v2?wsdl";
+
+        match load(&cache) {
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData => {}
+            other => panic!("expected invalid data, received {:?}", other),
+        }
+
+        let ttl = Duration::from_secs(60);
+        let wsdl = fetch_cached(&transport, url, &cache, ttl).unwrap();
+        assert_eq!(wsdl.operations.len(), 2);
+        assert_eq!(transport.wsdl_requests(), 1);
+
+        assert_eq!(load(&cache).unwrap().operations.len(), 2);
+        fetch_cached(&transport, url, &cache, ttl).unwrap();
+        assert_eq!(transport.wsdl_requests(), 1);
+
+        fs::remove_file(&cache).unwrap();
+    }
+
+    #[test]
+    fn rejects_documents_which_are_not_wsdl() {
+        match parse("<html><body>Login</body></html>") {
+            Err(ParseError::NotWsdl { root }) => assert_eq!(root, "html"),
+            other => panic!("expected not wsdl, received {:?}", other),
+        }
+        match parse("<wsdl:definitions xmlns:wsdl=\"http://schemas.xmlsoap.org/wsdl/\">") {
+            Err(ParseError::MalformedXml(_)) => {}
+            other => panic!("expected malformed xml, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn bundled_operations_point_to_the_service() {
+        let wsdl = Wsdl::bundled_v2("https://confluence/");
+
+        assert_eq!(
+            wsdl.operations["login"].url,
+            "https://confluence/rpc/soap-axis/confluenceservice-v2"
+        );
+        assert!(wsdl.operations.contains_key("storePage"));
+    }
+
+    #[test]
+    fn parses_messages_types_and_bindings() {
+        let wsdl = parse(FULL_WSDL).unwrap();
+
+        let get_page = &wsdl.operations["getPage"];
+        assert_eq!(get_page.signatures.len(), 2);
+        let input = get_page.signatures[0].input.as_ref().unwrap();
+        assert_eq!(input.parts[1].name, "in1");
+        assert_eq!(input.parts[1].type_ref, TypeRef::Builtin("long".into()));
+        assert_eq!(
+            get_page.signatures[0].output.as_ref().unwrap().parts[0].type_ref,
+            TypeRef::Named("RemotePage".into())
+        );
+        assert_eq!(get_page.signatures[0].faults, vec!["RemoteException"]);
+
+        let fields: Vec<_> = wsdl
+            .types
+            .all_fields("RemotePage")
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect();
+        assert_eq!(fields, vec!["id", "space", "title", "content", "version"]);
+        assert_eq!(
+            wsdl.types.get("ArrayOf_tns1_RemotePage").unwrap().array_of,
+            Some(TypeRef::Named("RemotePage".into()))
+        );
+
+        let binding = &wsdl.bindings["confluenceservice-v2SoapBinding"];
+        assert_eq!(binding.port_type, "ConfluenceSoapService");
+        assert_eq!(binding.style, Some("rpc".into()));
+        assert_eq!(
+            binding.address,
+            Some("https://confluence/rpc/soap-axis/confluenceservice-v2".into())
+        );
+        assert_eq!(
+            binding.operations["getPage"].body_use,
+            Some("encoded".into())
+        );
+    }
+
+    #[test]
+    fn validates_method_arguments() {
+        use rpser::xml::BuildElement;
+        use rpser::Method;
+        use xmltree::Element;
+
+        let wsdl = parse(FULL_WSDL).unwrap();
+        let token = || Element::node("token").with_text("a3a8ecc6d5");
+
+        let by_id = Method::new("getPage")
+            .with(token())
+            .with(Element::node("pageId").with_text("42"));
+        assert_eq!(wsdl.validate(&by_id), Ok(()));
+
+        let by_title = Method::new("getPage")
+            .with(token())
+            .with(Element::node("spaceKey").with_text("DOC"))
+            .with(Element::node("pageTitle").with_text("Home"));
+        assert_eq!(wsdl.validate(&by_title), Ok(()));
+
+        match wsdl.validate(&Method::new("getPage").with(token())) {
+            Err(ValidationError::ArgumentCount {
+                expected, given, ..
+            }) => {
+                assert_eq!(expected, vec![2, 3]);
+                assert_eq!(given, 1);
+            }
+            other => panic!("expected argument count error, received {:?}", other),
+        }
+
+        let bad_id = Method::new("getPage")
+            .with(token())
+            .with(Element::node("pageId").with_text("Home"));
+        match wsdl.validate(&bad_id) {
+            Err(ValidationError::InvalidArgument { position, .. }) => assert_eq!(position, 1),
+            other => panic!("expected invalid argument, received {:?}", other),
+        }
+
+        let page = |field: &str| {
+            Method::new("storePage").with(token()).with(
+                Element::node("page")
+                    .with_child(Element::node("space").with_text("DOC"))
+                    .with_child(Element::node(field).with_text("Home")),
+            )
+        };
+        assert_eq!(wsdl.validate(&page("title")), Ok(()));
+        match wsdl.validate(&page("titel")) {
+            Err(ValidationError::InvalidArgument { reason, .. }) => {
+                assert_eq!(reason, "RemotePage has no field titel")
+            }
+            other => panic!("expected invalid argument, received {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Messages, schema types and bindings described by the WSDL.
+
+use std::collections::HashMap;
+
+/// Reference to the type of a message part or a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeRef {
+    /// Built-in XML Schema or SOAP encoding type, such as `string` or `long`.
+    Builtin(String),
+    /// Complex type defined in the WSDL schema, such as `RemotePage`.
+    Named(String),
+}
+
+impl TypeRef {
+    /// Local name of the type, without namespace.
+    pub fn name(&self) -> &str {
+        match *self {
+            TypeRef::Builtin(ref name) | TypeRef::Named(ref name) => name,
+        }
+    }
+}
+
+/// Parameter or return value of an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Part {
+    pub name: String,
+    pub type_ref: TypeRef,
+}
+
+/// Input or output message of an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub name: String,
+    pub parts: Vec<Part>,
+}
+
+/// One signature of an operation.
+///
+/// Confluence overloads some operations (such as `getPage` by id and by title),
+/// so an operation can have more than one signature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    /// Parameters.
+    pub input: Option<Message>,
+    /// Return value.
+    pub output: Option<Message>,
+    /// Names of the fault messages, such as `RemoteException`.
+    pub faults: Vec<String>,
+}
+
+/// Field of a complex type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub type_ref: TypeRef,
+    pub nillable: bool,
+}
+
+/// Complex type defined in the WSDL schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplexType {
+    pub name: String,
+    /// Target namespace of the schema the type is defined in.
+    pub namespace: Option<String>,
+    /// Type this one extends, such as `AbstractRemotePageSummary` for `RemotePage`.
+    pub base: Option<String>,
+    /// Fields declared by this type, without the inherited ones.
+    pub fields: Vec<Field>,
+    /// Item type, if this is a SOAP array type such as `ArrayOf_tns1_RemotePage`.
+    pub array_of: Option<TypeRef>,
+}
+
+/// Complex types from the WSDL schema, by name.
+#[derive(Debug, Clone, Default)]
+pub struct TypeRegistry {
+    types: HashMap<String, ComplexType>,
+}
+
+impl TypeRegistry {
+    /// Find the type by its local name.
+    pub fn get(&self, name: &str) -> Option<&ComplexType> {
+        self.types.get(name)
+    }
+
+    /// Names of all registered types.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.types.keys().map(|name| name.as_str())
+    }
+
+    /// Number of registered types.
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Check if there are no registered types.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Fields of the type including the inherited ones, base type fields first.
+    pub fn all_fields(&self, name: &str) -> Vec<&Field> {
+        let mut chain = vec![];
+        let mut next = self.types.get(name);
+        while let Some(complex) = next {
+            // guard against cycles in malformed schemas
+            if chain.len() > self.types.len() {
+                break;
+            }
+            chain.push(complex);
+            next = complex.base.as_ref().and_then(|base| self.types.get(base));
+        }
+
+        chain
+            .iter()
+            .rev()
+            .flat_map(|complex| complex.fields.iter())
+            .collect()
+    }
+
+    pub(crate) fn insert(&mut self, complex: ComplexType) {
+        self.types.insert(complex.name.clone(), complex);
+    }
+}
+
+/// SOAP binding of a port type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding {
+    pub name: String,
+    /// Port type the binding implements.
+    pub port_type: String,
+    /// SOAP style, `rpc` for Confluence.
+    pub style: Option<String>,
+    /// Transport URI, HTTP for Confluence.
+    pub transport: Option<String>,
+    /// Endpoint address of the service port using this binding.
+    pub address: Option<String>,
+    pub operations: HashMap<String, BindingOperation>,
+}
+
+/// SOAP details of a bound operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindingOperation {
+    /// Value of the `SOAPAction` header.
+    pub soap_action: Option<String>,
+    /// Namespace of the operation element in the SOAP body.
+    pub namespace: Option<String>,
+    /// Body encoding, `encoded` for Confluence.
+    pub body_use: Option<String>,
+}
@@ -0,0 +1,292 @@
+//! Parser building the `Wsdl` model from the WSDL document.
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+use xmltree::Element;
+
+use super::model::{
+    Binding, BindingOperation, ComplexType, Field, Message, Part, Signature, TypeRef, TypeRegistry,
+};
+use super::{Operation, Wsdl};
+
+const WSDL_NS: &str = "http://schemas.xmlsoap.org/wsdl/";
+const WSDL_SOAP_NS: &str = "http://schemas.xmlsoap.org/wsdl/soap/";
+const XSD_NS: &str = "http://www.w3.org/2001/XMLSchema";
+const SOAP_ENC_NS: &str = "http://schemas.xmlsoap.org/soap/encoding/";
+
+/// Reason why a document could not be parsed as WSDL.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The document is not well-formed XML, for example because it was truncated.
+    MalformedXml(String),
+    /// The root element is not `wsdl:definitions`.
+    NotWsdl { root: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::MalformedXml(ref message) => write!(f, "malformed WSDL: {}", message),
+            ParseError::NotWsdl { ref root } => {
+                write!(f, "expected wsdl:definitions, found <{}>", root)
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parse WSDL document, such as one saved from `<confluence>/rpc/soap-axis/confluenceservice-v2?wsdl`.
+///
+/// The document must be well-formed XML with `wsdl:definitions` at the root.
+/// Parts of the document which can not be understood are skipped.
+pub fn parse(xml: &str) -> Result<Wsdl, ParseError> {
+    let definitions =
+        Element::parse(xml.as_bytes()).map_err(|e| ParseError::MalformedXml(e.to_string()))?;
+
+    if definitions.name != "definitions" || definitions.namespace.as_deref() != Some(WSDL_NS) {
+        return Err(ParseError::NotWsdl {
+            root: definitions.name,
+        });
+    }
+
+    Ok(from_definitions(&definitions))
+}
+
+fn from_definitions(definitions: &Element) -> Wsdl {
+    let target_namespace = definitions.attributes.get("targetNamespace");
+
+    let mut types = TypeRegistry::default();
+    let mut messages = HashMap::new();
+
+    for types_element in children(definitions, WSDL_NS, "types") {
+        for schema in children(types_element, XSD_NS, "schema") {
+            let namespace = schema.attributes.get("targetNamespace");
+            for complex in children(schema, XSD_NS, "complexType") {
+                if let Some(complex) = complex_type(complex, namespace) {
+                    types.insert(complex);
+                }
+            }
+        }
+    }
+
+    for message in children(definitions, WSDL_NS, "message") {
+        if let Some(name) = message.attributes.get("name") {
+            let parts = children(message, WSDL_NS, "part")
+                .filter_map(|part| {
+                    let name = part.attributes.get("name")?;
+                    let type_name = part
+                        .attributes
+                        .get("type")
+                        .or_else(|| part.attributes.get("element"))?;
+                    Some(Part {
+                        name: name.clone(),
+                        type_ref: type_ref(part, type_name),
+                    })
+                })
+                .collect();
+
+            messages.insert(
+                name.clone(),
+                Message {
+                    name: name.clone(),
+                    parts,
+                },
+            );
+        }
+    }
+
+    let mut operations: HashMap<String, Operation> = HashMap::new();
+
+    for port_type in children(definitions, WSDL_NS, "portType") {
+        for operation in children(port_type, WSDL_NS, "operation") {
+            let name = match operation.attributes.get("name") {
+                Some(name) => name,
+                None => continue,
+            };
+            let url =
+                match namespace_uri(operation, "impl").or(target_namespace.map(|s| s.as_str())) {
+                    Some(url) => url,
+                    None => continue,
+                };
+
+            let message = |kind: &str| {
+                children(operation, WSDL_NS, kind)
+                    .next()
+                    .and_then(|io| io.attributes.get("message"))
+                    .and_then(|message| messages.get(local_name(message)))
+                    .cloned()
+            };
+            let signature = Signature {
+                input: message("input"),
+                output: message("output"),
+                faults: children(operation, WSDL_NS, "fault")
+                    .filter_map(|fault| fault.attributes.get("message"))
+                    .map(|message| local_name(message).to_string())
+                    .collect(),
+            };
+
+            operations
+                .entry(name.clone())
+                .or_insert_with(|| Operation::new(url))
+                .signatures
+                .push(signature);
+        }
+    }
+
+    let mut bindings: HashMap<String, Binding> = children(definitions, WSDL_NS, "binding")
+        .filter_map(binding)
+        .map(|binding| (binding.name.clone(), binding))
+        .collect();
+
+    for service in children(definitions, WSDL_NS, "service") {
+        for port in children(service, WSDL_NS, "port") {
+            let binding = port
+                .attributes
+                .get("binding")
+                .and_then(|name| bindings.get_mut(local_name(name)));
+            let address = children(port, WSDL_SOAP_NS, "address")
+                .next()
+                .and_then(|address| address.attributes.get("location"));
+
+            if let (Some(binding), Some(address)) = (binding, address) {
+                binding.address = Some(address.clone());
+            }
+        }
+    }
+
+    Wsdl {
+        operations,
+        messages,
+        types,
+        bindings,
+    }
+}
+
+fn complex_type(element: &Element, namespace: Option<&String>) -> Option<ComplexType> {
+    let mut complex = ComplexType {
+        name: element.attributes.get("name")?.clone(),
+        namespace: namespace.cloned(),
+        base: None,
+        fields: vec![],
+        array_of: None,
+    };
+
+    let mut content = element;
+    if let Some(complex_content) = children(element, XSD_NS, "complexContent").next() {
+        if let Some(extension) = children(complex_content, XSD_NS, "extension").next() {
+            complex.base = extension
+                .attributes
+                .get("base")
+                .map(|base| local_name(base).to_string());
+            content = extension;
+        } else if let Some(restriction) = children(complex_content, XSD_NS, "restriction").next() {
+            // SOAP encoded array: <attribute ref="soapenc:arrayType" wsdl:arrayType="tns1:RemotePage[]"/>
+            complex.array_of = children(restriction, XSD_NS, "attribute")
+                .filter_map(|attribute| {
+                    let array_type = attribute.attributes.get("arrayType")?;
+                    Some(type_ref(attribute, array_type.trim_end_matches("[]")))
+                })
+                .next();
+            content = restriction;
+        }
+    }
+
+    for sequence in children(content, XSD_NS, "sequence").chain(children(content, XSD_NS, "all")) {
+        for field in children(sequence, XSD_NS, "element") {
+            if let (Some(name), Some(type_name)) =
+                (field.attributes.get("name"), field.attributes.get("type"))
+            {
+                complex.fields.push(Field {
+                    name: name.clone(),
+                    type_ref: type_ref(field, type_name),
+                    nillable: field.attributes.get("nillable").map(String::as_str) == Some("true"),
+                });
+            }
+        }
+    }
+
+    Some(complex)
+}
+
+fn binding(element: &Element) -> Option<Binding> {
+    let soap_binding = children(element, WSDL_SOAP_NS, "binding").next();
+
+    let operations = children(element, WSDL_NS, "operation")
+        .filter_map(|operation| {
+            let name = operation.attributes.get("name")?;
+            let body = children(operation, WSDL_NS, "input")
+                .next()
+                .and_then(|input| children(input, WSDL_SOAP_NS, "body").next());
+
+            Some((
+                name.clone(),
+                BindingOperation {
+                    soap_action: children(operation, WSDL_SOAP_NS, "operation")
+                        .next()
+                        .and_then(|soap| soap.attributes.get("soapAction"))
+                        .cloned(),
+                    namespace: body
+                        .and_then(|body| body.attributes.get("namespace"))
+                        .cloned(),
+                    body_use: body.and_then(|body| body.attributes.get("use")).cloned(),
+                },
+            ))
+        })
+        .collect();
+
+    Some(Binding {
+        name: element.attributes.get("name")?.clone(),
+        port_type: local_name(element.attributes.get("type")?).to_string(),
+        style: soap_binding.and_then(|binding| binding.attributes.get("style").cloned()),
+        transport: soap_binding.and_then(|binding| binding.attributes.get("transport").cloned()),
+        address: None,
+        operations,
+    })
+}
+
+/// Child elements with the given namespace and local name.
+fn children<'a>(
+    parent: &'a Element,
+    namespace: &'a str,
+    name: &'a str,
+) -> impl Iterator<Item = &'a Element> {
+    parent
+        .children
+        .iter()
+        .filter(move |child| child.name == name && child.namespace.as_deref() == Some(namespace))
+}
+
+/// Resolve the qualified type name used in an attribute of the element.
+fn type_ref(element: &Element, qualified_name: &str) -> TypeRef {
+    let name = local_name(qualified_name).to_string();
+
+    let builtin = match qualified_name.find(':') {
+        Some(colon) => {
+            let prefix = &qualified_name[..colon];
+            match namespace_uri(element, prefix) {
+                Some(uri) => uri == XSD_NS || uri == SOAP_ENC_NS,
+                None => prefix == "xsd" || prefix == "xs" || prefix == "soapenc",
+            }
+        }
+        None => false,
+    };
+
+    if builtin {
+        TypeRef::Builtin(name)
+    } else {
+        TypeRef::Named(name)
+    }
+}
+
+fn namespace_uri<'a>(element: &'a Element, prefix: &str) -> Option<&'a str> {
+    element.namespaces.as_ref()?.get(prefix)
+}
+
+fn local_name(qualified_name: &str) -> &str {
+    match qualified_name.rfind(':') {
+        Some(colon) => &qualified_name[colon + 1..],
+        None => qualified_name,
+    }
+}
@@ -0,0 +1,226 @@
+//! Checking `Method` arguments against the operation signatures.
+
+use chrono::DateTime;
+use std::fmt;
+use xmltree::Element;
+
+use super::model::{Signature, TypeRef, TypeRegistry};
+use super::Wsdl;
+use rpser::Method;
+
+/// Reason why the arguments of a method do not match the WSDL.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// The operation is not in the WSDL.
+    UnknownOperation(String),
+    /// No signature of the operation takes the given number of arguments.
+    ArgumentCount {
+        operation: String,
+        expected: Vec<usize>,
+        given: usize,
+    },
+    /// The argument does not match the type of the parameter.
+    InvalidArgument {
+        operation: String,
+        /// Position of the argument, starting from 0.
+        position: usize,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ValidationError::UnknownOperation(ref operation) => {
+                write!(f, "operation {} is not in the WSDL", operation)
+            }
+            ValidationError::ArgumentCount {
+                ref operation,
+                ref expected,
+                given,
+            } => write!(
+                f,
+                "{} takes {:?} arguments, {} given",
+                operation, expected, given
+            ),
+            ValidationError::InvalidArgument {
+                ref operation,
+                position,
+                ref reason,
+            } => write!(f, "argument {} of {}: {}", position, operation, reason),
+        }
+    }
+}
+
+impl Wsdl {
+    /**
+    Check that the method matches one of the signatures of its operation.
+
+    Arguments are matched by position, because Axis names the parameters
+    `in0`, `in1`, ... in the WSDL. Simple values are checked to parse as their
+    type, and the fields of complex values are checked against the schema.
+    Operations without known signatures (such as the bundled ones) are not checked.
+
+    ## Example
+
+    ```
+    extern crate confluence;
+    extern crate xmltree;
+
+    use confluence::rpser::xml::BuildElement;
+    use confluence::rpser::Method;
+    use xmltree::Element;
+
+    # fn main() {
+    let wsdl = confluence::wsdl::parse(r#"
+        <wsdl:definitions xmlns:wsdl="http://schemas.xmlsoap.org/wsdl/" xmlns:xsd="http://www.w3.org/2001/XMLSchema" xmlns:impl="https://confluence/rpc/soap-axis/confluenceservice-v2">
+            <wsdl:message name="getChildrenRequest">
+                <wsdl:part name="in0" type="xsd:string"/>
+                <wsdl:part name="in1" type="xsd:long"/>
+            </wsdl:message>
+            <wsdl:portType name="ConfluenceSoapService">
+                <wsdl:operation name="getChildren">
+                    <wsdl:input message="impl:getChildrenRequest"/>
+                </wsdl:operation>
+            </wsdl:portType>
+        </wsdl:definitions>
+    "#).unwrap();
+
+    let method = Method::new("getChildren")
+        .with(Element::node("token").with_text("a3a8ecc6d5"))
+        .with(Element::node("pageId").with_text("not a number"));
+
+    assert!(wsdl.validate(&method).is_err());
+    # }
+    ```
+    */
+    pub fn validate(&self, method: &Method) -> Result<(), ValidationError> {
+        let operation = match self.operations.get(&method.name) {
+            Some(operation) => operation,
+            None => return Err(ValidationError::UnknownOperation(method.name.clone())),
+        };
+
+        let known: Vec<&Signature> = operation
+            .signatures
+            .iter()
+            .filter(|signature| signature.input.is_some())
+            .collect();
+        if known.is_empty() {
+            return Ok(());
+        }
+
+        let mut first_error = None;
+        for signature in &known {
+            let parts = &signature.input.as_ref().unwrap().parts;
+            if parts.len() != method.args.len() {
+                continue;
+            }
+
+            let mismatch = parts
+                .iter()
+                .zip(&method.args)
+                .enumerate()
+                .filter_map(|(position, (part, arg))| {
+                    check_value(&self.types, &part.type_ref, arg)
+                        .err()
+                        .map(|reason| (position, reason))
+                })
+                .next();
+
+            match mismatch {
+                None => return Ok(()),
+                Some((position, reason)) => {
+                    first_error.get_or_insert(ValidationError::InvalidArgument {
+                        operation: method.name.clone(),
+                        position,
+                        reason,
+                    });
+                }
+            }
+        }
+
+        Err(first_error.unwrap_or_else(|| {
+            let mut expected: Vec<usize> = known
+                .iter()
+                .map(|signature| signature.input.as_ref().unwrap().parts.len())
+                .collect();
+            expected.sort();
+            expected.dedup();
+
+            ValidationError::ArgumentCount {
+                operation: method.name.clone(),
+                expected,
+                given: method.args.len(),
+            }
+        }))
+    }
+}
+
+/// Check the element against the type, returning the reason of the mismatch.
+fn check_value(types: &TypeRegistry, type_ref: &TypeRef, element: &Element) -> Result<(), String> {
+    match *type_ref {
+        TypeRef::Builtin(ref name) => {
+            if !element.children.is_empty() {
+                return Err(format!(
+                    "{} should be {}, found nested elements",
+                    element.name, name
+                ));
+            }
+            check_builtin(name, element.text.as_deref())
+                .map_err(|expected| format!("{} should be {}", element.name, expected))
+        }
+        TypeRef::Named(ref name) => {
+            let complex = match types.get(name) {
+                Some(complex) => complex,
+                None => return Ok(()),
+            };
+
+            if element.children.is_empty() && element.text.as_ref().is_some_and(|t| !t.is_empty()) {
+                return Err(format!(
+                    "{} should be {}, found text",
+                    element.name, complex.name
+                ));
+            }
+
+            if let Some(ref item) = complex.array_of {
+                return element
+                    .children
+                    .iter()
+                    .try_for_each(|child| check_value(types, item, child));
+            }
+
+            let fields = types.all_fields(name);
+            for child in &element.children {
+                match fields.iter().find(|field| field.name == child.name) {
+                    Some(field) => check_value(types, &field.type_ref, child)?,
+                    None => return Err(format!("{} has no field {}", complex.name, child.name)),
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Check the text of a built-in type, returning the description of the expected value.
+fn check_builtin(name: &str, text: Option<&str>) -> Result<(), &'static str> {
+    match name {
+        "long" | "int" | "short" | "byte" | "integer" => match text.map(str::parse::<i64>) {
+            Some(Ok(_)) => Ok(()),
+            _ => Err("an integer"),
+        },
+        "boolean" => match text {
+            Some("true") | Some("false") | Some("1") | Some("0") => Ok(()),
+            _ => Err("true or false"),
+        },
+        "double" | "float" => match text.map(str::parse::<f64>) {
+            Some(Ok(_)) => Ok(()),
+            _ => Err("a number"),
+        },
+        "dateTime" => match text.map(DateTime::parse_from_rfc3339) {
+            None | Some(Ok(_)) => Ok(()),
+            Some(Err(_)) => Err("an RFC 3339 date and time"),
+        },
+        _ => Ok(()),
+    }
+}
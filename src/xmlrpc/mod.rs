@@ -0,0 +1,390 @@
+//! XML-RPC serialization, for the API Confluence exposes at `/rpc/xmlrpc`.
+//!
+//! The XML-RPC API has the same methods as the SOAP one, prefixed with
+//! the API name, such as `confluence2.getPage`.
+
+pub mod value;
+
+use std::fmt;
+use std::result;
+use xmltree::Element;
+
+pub use self::value::{FromValue, Value};
+use rpser::xml::BuildElement;
+use rpser::{FaultKind, Method};
+use wsdl::Wsdl;
+
+/// Path of the XML-RPC endpoint, relative to the confluence base url.
+pub const XML_RPC_PATH: &str = "/rpc/xmlrpc";
+
+/// Prefix of the v2 API methods.
+pub const V2_API_PREFIX: &str = "confluence2";
+
+/// XML-RPC method call.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodCall {
+    pub name: String,
+    pub params: Vec<Value>,
+}
+
+impl MethodCall {
+    /// Create new method call with name, such as `confluence2.getPage`.
+    pub fn new<S: Into<String>>(name: S) -> MethodCall {
+        MethodCall {
+            name: name.into(),
+            params: vec![],
+        }
+    }
+
+    /// Convert the SOAP method to a call of the v2 API, see `Value::from_argument`.
+    pub fn from_method(method: &Method) -> MethodCall {
+        MethodCall::from_method_in(method, &Wsdl::default())
+    }
+
+    /// Convert the SOAP method to a call of the v2 API, typing the arguments by the
+    /// parts of the operation signature in the WSDL, see `Value::from_typed_argument`.
+    ///
+    /// Arguments of operations without a signature with as many parts are converted
+    /// as in `from_method`.
+    pub fn from_method_in(method: &Method, wsdl: &Wsdl) -> MethodCall {
+        let parts = wsdl
+            .operations
+            .get(&method.name)
+            .and_then(|operation| {
+                operation
+                    .signatures
+                    .iter()
+                    .filter_map(|signature| signature.input.as_ref())
+                    .find(|input| input.parts.len() == method.args.len())
+            })
+            .map(|input| &input.parts[..])
+            .unwrap_or(&[]);
+
+        MethodCall {
+            name: format!("{}.{}", V2_API_PREFIX, method.name),
+            params: method
+                .args
+                .iter()
+                .enumerate()
+                .map(|(position, arg)| {
+                    let type_ref = parts.get(position).map(|part| &part.type_ref);
+                    Value::from_typed_argument(arg, type_ref, &wsdl.types)
+                })
+                .collect(),
+        }
+    }
+
+    /// Add parameter to method call.
+    pub fn with(mut self, param: Value) -> Self {
+        self.params.push(param);
+        self
+    }
+
+    /// Convert the call to `<methodCall>` XML.
+    pub fn as_xml(&self) -> String {
+        Element::node("methodCall")
+            .with_child(Element::node("methodName").with_text(self.name.as_str()))
+            .with_child(
+                Element::node("params").with_children(
+                    self.params
+                        .iter()
+                        .map(|param| Element::node("param").with_child(param.to_element())),
+                ),
+            )
+            .to_string()
+    }
+}
+
+impl fmt::Display for MethodCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:?}", self.name, self.params)
+    }
+}
+
+/// Parse `<methodResponse>` XML, returning the value or the fault.
+pub fn parse_response(xml: &str) -> Result<Value> {
+    let response = match Element::parse(xml.as_bytes()) {
+        Ok(element) => element,
+        Err(e) => {
+            return Err(XmlRpcError::MalformedXml {
+                message: format!("{:?}", e),
+            })
+        }
+    };
+
+    if response.name != "methodResponse" {
+        return Err(XmlRpcError::UnexpectedElement { tag: response.name });
+    }
+
+    if let Some(fault) = response.get_child("fault") {
+        let value = match fault.get_child("value") {
+            Some(value) => Value::from_element(value)?,
+            None => {
+                return Err(XmlRpcError::UnexpectedElement {
+                    tag: "fault".into(),
+                })
+            }
+        };
+
+        let code = value
+            .member("faultCode")
+            .and_then(Value::as_long)
+            .unwrap_or(0);
+        let message = value
+            .member("faultString")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .to_string();
+
+        // the remote exception is wrapped: "java.lang.Exception: com.atlassian.confluence.rpc..."
+        let kind = FaultKind::classify(
+            message.trim_start_matches("java.lang.Exception: "),
+            &Element::node("detail"),
+        );
+
+        return Err(XmlRpcError::Fault {
+            code,
+            message,
+            kind,
+        });
+    }
+
+    match response
+        .get_child("params")
+        .and_then(|params| params.get_child("param"))
+        .and_then(|param| param.get_child("value"))
+    {
+        Some(value) => Value::from_element(value),
+        None => Err(XmlRpcError::UnexpectedElement {
+            tag: "methodResponse".into(),
+        }),
+    }
+}
+
+/// XML-RPC response parsing or decoding error.
+#[derive(Debug, PartialEq)]
+pub enum XmlRpcError {
+    /// The server responded with a fault.
+    Fault {
+        code: i64,
+        message: String,
+        kind: FaultKind,
+    },
+    MalformedXml {
+        message: String,
+    },
+    UnexpectedElement {
+        tag: String,
+    },
+    /// The text of a typed value could not be parsed.
+    InvalidValue {
+        tag: String,
+        text: String,
+    },
+    /// The struct has no member with this name.
+    MissingMember {
+        name: String,
+    },
+    /// The value does not have the expected type.
+    UnexpectedType {
+        name: String,
+        expected_type: String,
+        given: Value,
+    },
+}
+
+impl XmlRpcError {
+    /// Kind of the remote exception, if this error is a fault.
+    pub fn fault_kind(&self) -> Option<FaultKind> {
+        match *self {
+            XmlRpcError::Fault { kind, .. } => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+pub type Result<T> = result::Result<T, XmlRpcError>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use fixtures::CannedTransport;
+    use std::sync::Arc;
+    use {Backend, Session};
+
+    #[test]
+    fn writes_method_call() {
+        let method = Method::new("getPage")
+            .with(Element::node("token").with_text("a3a8ecc6d5"))
+            .with(Element::node("pageId").with_text("42"));
+
+        let xml = MethodCall::from_method(&method).as_xml();
+
+        assert!(xml.contains("<methodName>confluence2.getPage</methodName>"));
+        assert!(xml.contains(
+            "<params><param><value><string>a3a8ecc6d5</string></value></param><param><value><string>42</string></value></param></params>"
+        ));
+    }
+
+    #[test]
+    fn writes_typed_page_update_options() {
+        let method = Method::new("updatePage")
+            .with(Element::node("token").with_text("a3a8ecc6d5"))
+            .with(::transforms::update_page_element(::UpdatePage {
+                id: Some(42),
+                space: "DOC".into(),
+                title: "Home".into(),
+                content: "<p>Hi</p>".into(),
+                version: Some(3),
+                parent_id: None,
+            }))
+            .with(::transforms::page_update_options_element(
+                ::PageUpdateOptions {
+                    version_comment: Some("typo".into()),
+                    minor_edit: true,
+                },
+            ));
+
+        let xml = MethodCall::from_method(&method).as_xml();
+
+        assert!(xml.contains("<member><name>id</name><value><string>42</string></value></member>"));
+        assert!(xml.contains("<member><name>version</name><value><int>3</int></value></member>"));
+        assert!(xml.contains(
+            "<param><value><struct><member><name>minorEdit</name><value><boolean>1</boolean></value></member><member><name>versionComment</name><value><string>typo</string></value></member></struct></value></param>"
+        ));
+    }
+
+    #[test]
+    fn writes_empty_arrays_and_structs_of_wsdl_types() {
+        let wsdl = ::wsdl::parse(include_str!(
+            "../../tests/fixtures/confluenceservice-v2.wsdl"
+        ))
+        .unwrap();
+        let method = Method::new("storePage")
+            .with(Element::node("token").with_text("a3a8ecc6d5"))
+            .with(Element::node("page"));
+
+        let call = MethodCall::from_method_in(&method, &wsdl);
+        assert_eq!(call.params[1], Value::Struct(Default::default()));
+        assert!(call
+            .as_xml()
+            .contains("<param><value><struct /></value></param>"));
+
+        let labels = Method::new("addLabels")
+            .with(Element::node("labels").with_attr("xsi:type", "soapenc:Array"));
+        assert!(MethodCall::from_method(&labels)
+            .as_xml()
+            .contains("<param><value><array><data /></array></value></param>"));
+    }
+
+    #[test]
+    fn returns_response_value() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <methodResponse><params><param><value><boolean>1</boolean></value></param></params></methodResponse>"#;
+
+        assert_eq!(parse_response(xml), Ok(Value::Boolean(true)));
+    }
+
+    #[test]
+    fn can_deal_with_fault() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <methodResponse><fault><value><struct>
+                <member><name>faultString</name><value>java.lang.Exception: com.atlassian.confluence.rpc.InvalidSessionException: User not authenticated or session expired. Call login() to open a new session</value></member>
+                <member><name>faultCode</name><value><int>0</int></value></member>
+            </struct></value></fault></methodResponse>"#;
+
+        match parse_response(xml) {
+            Err(XmlRpcError::Fault {
+                code,
+                message,
+                kind,
+            }) => {
+                assert_eq!(code, 0);
+                assert_eq!(kind, FaultKind::InvalidSession);
+                assert!(message.starts_with("java.lang.Exception: "));
+            }
+            other => panic!("expected fault, received {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reports_non_xml_rpc_response() {
+        match parse_response("<html><body>Please log in</body></html>") {
+            Err(XmlRpcError::UnexpectedElement { tag }) => assert_eq!(tag, "html"),
+            other => panic!("expected unexpected element error, received {:?}", other),
+        }
+    }
+
+    /// Transport which answers the XML-RPC methods only, so no WSDL can be used.
+    fn transport() -> Arc<CannedTransport> {
+        Arc::new(CannedTransport::new(&[]).with_xml_rpc(&[
+            ("confluence2.login", "<value>a3a8ecc6d5</value>"),
+            ("confluence2.logout", "<value><boolean>1</boolean></value>"),
+            (
+                "confluence2.getPage",
+                r#"<value><struct>
+                    <member><name>id</name><value>42</value></member>
+                    <member><name>space</name><value>DOC</value></member>
+                    <member><name>parentId</name><value>0</value></member>
+                    <member><name>title</name><value>Home</value></member>
+                    <member><name>url</name><value>https://confluence/display/DOC/Home</value></member>
+                    <member><name>version</name><value>3</value></member>
+                    <member><name>content</name><value>&lt;p&gt;Hello&lt;/p&gt;</value></member>
+                    <member><name>created</name><value><dateTime.iso8601>20190417T13:45:02</dateTime.iso8601></value></member>
+                    <member><name>creator</name><value>admin</value></member>
+                    <member><name>modified</name><value><dateTime.iso8601>20190418T08:00:00</dateTime.iso8601></value></member>
+                    <member><name>modifier</name><value>admin</value></member>
+                    <member><name>homePage</name><value>true</value></member>
+                    <member><name>contentStatus</name><value>current</value></member>
+                    <member><name>current</name><value>true</value></member>
+                </struct></value>"#,
+            ),
+            (
+                "confluence2.getChildren",
+                r#"<value><array><data><value><struct>
+                    <member><name>id</name><value>43</value></member>
+                    <member><name>space</name><value>DOC</value></member>
+                    <member><name>parentId</name><value>42</value></member>
+                    <member><name>title</name><value>Child</value></member>
+                    <member><name>url</name><value>https://confluence/display/DOC/Child</value></member>
+                </struct></value></data></array></value>"#,
+            ),
+        ]))
+    }
+
+    #[test]
+    fn calls_methods_over_xml_rpc() {
+        let transport = transport();
+
+        {
+            let session = Session::builder("https://confluence/")
+                .transport(transport.clone())
+                .backend(Backend::XmlRpc)
+                .login("user", "pass")
+                .unwrap();
+            assert_eq!(session.token(), "a3a8ecc6d5");
+
+            let page = session.get_page_by_id(42).unwrap();
+            assert_eq!(page.id, 42);
+            assert_eq!(page.version, 3);
+            assert_eq!(page.content, "<p>Hello</p>");
+            assert!(page.home_page);
+            assert_eq!(page.modified.to_rfc3339(), "2019-04-18T08:00:00+00:00");
+
+            let children = session.get_children(42).unwrap();
+            assert_eq!(children.len(), 1);
+            assert_eq!(children[0].parent_id, 42);
+        }
+
+        assert_eq!(transport.wsdl_requests(), 0);
+        assert_eq!(
+            transport.actions(),
+            vec![
+                "confluence2.login",
+                "confluence2.getPage",
+                "confluence2.getChildren",
+                "confluence2.logout"
+            ]
+        );
+    }
+}
@@ -0,0 +1,544 @@
+//! XML-RPC values and their conversion to and from `<value>` elements.
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::result;
+use xmltree::Element;
+
+use super::XmlRpcError;
+use rpser::xml::BuildElement;
+use wsdl::{TypeRef, TypeRegistry};
+use Result;
+
+/// Format of `dateTime.iso8601` values, such as `20190417T13:45:02`.
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H:%M:%S";
+
+/// XML-RPC value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Int(i32),
+    Boolean(bool),
+    Double(f64),
+    DateTime(DateTime<Utc>),
+    /// Base64 encoded data, kept encoded.
+    Base64(String),
+    Struct(BTreeMap<String, Value>),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    /// Read the value from a `<value>` element.
+    ///
+    /// A value without a type element is a string.
+    pub fn from_element(element: &Element) -> result::Result<Value, XmlRpcError> {
+        let typed = match element.children.first() {
+            Some(typed) => typed,
+            None => return Ok(Value::String(element.text.clone().unwrap_or_default())),
+        };
+        let text = typed.text.as_deref().unwrap_or("").trim();
+
+        match typed.name.as_str() {
+            "string" => Ok(Value::String(typed.text.clone().unwrap_or_default())),
+            "int" | "i4" => text
+                .parse()
+                .map(Value::Int)
+                .map_err(|_| invalid_value(typed)),
+            "boolean" => match text {
+                "1" => Ok(Value::Boolean(true)),
+                "0" => Ok(Value::Boolean(false)),
+                _ => Err(invalid_value(typed)),
+            },
+            "double" => text
+                .parse()
+                .map(Value::Double)
+                .map_err(|_| invalid_value(typed)),
+            "dateTime.iso8601" => parse_date_time(text)
+                .map(Value::DateTime)
+                .ok_or_else(|| invalid_value(typed)),
+            "base64" => Ok(Value::Base64(text.to_string())),
+            "struct" => {
+                let mut members = BTreeMap::new();
+                for member in typed.children.iter().filter(|c| c.name == "member") {
+                    let name = member
+                        .get_child("name")
+                        .and_then(|name| name.text.clone())
+                        .unwrap_or_default();
+                    let value = match member.get_child("value") {
+                        Some(value) => Value::from_element(value)?,
+                        None => return Err(invalid_value(member)),
+                    };
+                    members.insert(name, value);
+                }
+                Ok(Value::Struct(members))
+            }
+            "array" => {
+                let data = match typed.get_child("data") {
+                    Some(data) => data,
+                    None => return Ok(Value::Array(vec![])),
+                };
+                data.children
+                    .iter()
+                    .filter(|c| c.name == "value")
+                    .map(Value::from_element)
+                    .collect::<result::Result<_, _>>()
+                    .map(Value::Array)
+            }
+            _ => Err(XmlRpcError::UnexpectedElement {
+                tag: typed.name.clone(),
+            }),
+        }
+    }
+
+    /// Write the value as a `<value>` element.
+    pub fn to_element(&self) -> Element {
+        let typed = match *self {
+            Value::String(ref s) => Element::node("string").with_text(s.as_str()),
+            Value::Int(i) => Element::node("int").with_text(i.to_string()),
+            Value::Boolean(b) => Element::node("boolean").with_text(if b { "1" } else { "0" }),
+            Value::Double(d) => Element::node("double").with_text(d.to_string()),
+            Value::DateTime(ref d) => {
+                Element::node("dateTime.iso8601").with_text(d.format(DATE_TIME_FORMAT).to_string())
+            }
+            Value::Base64(ref data) => Element::node("base64").with_text(data.as_str()),
+            Value::Struct(ref members) => {
+                Element::node("struct").with_children(members.iter().map(|(name, value)| {
+                    Element::node("member")
+                        .with_child(Element::node("name").with_text(name.as_str()))
+                        .with_child(value.to_element())
+                }))
+            }
+            Value::Array(ref items) => Element::node("array").with_child(
+                Element::node("data").with_children(items.iter().map(Value::to_element)),
+            ),
+        };
+
+        Element::node("value").with_child(typed)
+    }
+
+    /**
+    Convert a SOAP method argument to a value.
+
+    The type is taken from the `xsi:type` attribute of the element, so
+    `xsd:boolean`, `xsd:int`, `xsd:double` and `xsd:dateTime` become the
+    matching XML-RPC types and `soapenc:Array` becomes an array, even an empty one.
+    Other simple types, including `xsd:long`, become strings, which is how the
+    Confluence XML-RPC API expects ids.
+
+    Without the attribute, elements with text become strings, elements with
+    children named `item` become arrays, and other elements with children
+    become structs.
+    */
+    pub fn from_argument(element: &Element) -> Value {
+        Value::from_typed_argument(element, None, &TypeRegistry::default())
+    }
+
+    /**
+    Convert a SOAP method argument to a value of the WSDL part type.
+
+    Like `from_argument`, but elements without `xsi:type` are converted according
+    to the type, looking up the fields and array items of complex types in the
+    registry. An empty element of a complex type becomes an empty struct or array.
+    */
+    pub fn from_typed_argument(
+        element: &Element,
+        type_ref: Option<&TypeRef>,
+        types: &TypeRegistry,
+    ) -> Value {
+        let declared = element
+            .attributes
+            .get("type")
+            .or_else(|| element.attributes.get("xsi:type"))
+            .map(|name| declared_type(name));
+
+        match declared.as_ref().or(type_ref) {
+            Some(TypeRef::Builtin(name)) => builtin_argument(element, name, types),
+            Some(TypeRef::Named(name)) => named_argument(element, name, types),
+            None => untyped_argument(element, types),
+        }
+    }
+
+    /// Member of a struct value, `None` if it is missing or this is not a struct.
+    pub fn member(&self, name: &str) -> Option<&Value> {
+        match *self {
+            Value::Struct(ref members) => members.get(name),
+            _ => None,
+        }
+    }
+
+    /// Text of a string value.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            Value::String(ref s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Integer value, also parsed from a string.
+    ///
+    /// Confluence sends ids and versions as strings, because XML-RPC has no 64 bit integers.
+    pub fn as_long(&self) -> Option<i64> {
+        match *self {
+            Value::Int(i) => Some(i64::from(i)),
+            Value::String(ref s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Boolean value, also parsed from a `true` or `false` string.
+    pub fn as_boolean(&self) -> Option<bool> {
+        match *self {
+            Value::Boolean(b) => Some(b),
+            Value::String(ref s) => match s.trim() {
+                "true" => Some(true),
+                "false" => Some(false),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Date and time value, also parsed from a string.
+    pub fn as_datetime(&self) -> Option<DateTime<Utc>> {
+        match *self {
+            Value::DateTime(d) => Some(d),
+            Value::String(ref s) => parse_date_time(s.trim()),
+            _ => None,
+        }
+    }
+
+    /// Required string member of a struct value.
+    pub fn get_string(&self, name: &str) -> Result<String> {
+        self.get(name, "string", |v| v.as_str().map(String::from))
+    }
+
+    /// Optional string member of a struct value.
+    pub fn get_optional_string(&self, name: &str) -> Result<Option<String>> {
+        match self.member(name) {
+            None => Ok(None),
+            Some(_) => self.get_string(name).map(Some),
+        }
+    }
+
+    /// Required integer member of a struct value.
+    pub fn get_long(&self, name: &str) -> Result<i64> {
+        self.get(name, "long", Value::as_long)
+    }
+
+    /// Required 32 bit integer member of a struct value.
+    pub fn get_int(&self, name: &str) -> Result<i32> {
+        self.get(name, "int", |v| {
+            v.as_long().and_then(|l| i32::try_from(l).ok())
+        })
+    }
+
+    /// Required boolean member of a struct value.
+    pub fn get_boolean(&self, name: &str) -> Result<bool> {
+        self.get(name, "boolean", Value::as_boolean)
+    }
+
+    /// Required date and time member of a struct value.
+    pub fn get_datetime(&self, name: &str) -> Result<DateTime<Utc>> {
+        self.get(name, "dateTime.iso8601", Value::as_datetime)
+    }
+
+    fn get<T, F>(&self, name: &str, expected_type: &str, convert: F) -> Result<T>
+    where
+        F: Fn(&Value) -> Option<T>,
+    {
+        let member = self
+            .member(name)
+            .ok_or_else(|| XmlRpcError::MissingMember { name: name.into() })?;
+
+        convert(member).ok_or_else(|| {
+            XmlRpcError::UnexpectedType {
+                name: name.into(),
+                expected_type: expected_type.into(),
+                given: member.clone(),
+            }
+            .into()
+        })
+    }
+}
+
+/// Type which can be decoded from an XML-RPC value, such as `Page`.
+pub trait FromValue {
+    fn from_value(value: Value) -> Result<Self>
+    where
+        Self: Sized;
+}
+
+impl FromValue for Value {
+    fn from_value(value: Value) -> Result<Value> {
+        Ok(value)
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: Value) -> Result<Vec<T>> {
+        match value {
+            Value::Array(items) => items.into_iter().map(T::from_value).collect(),
+            other => Err(XmlRpcError::UnexpectedType {
+                name: "array".into(),
+                expected_type: "array".into(),
+                given: other,
+            }
+            .into()),
+        }
+    }
+}
+
+/// Parse `dateTime.iso8601`, accepting the compact form used by Confluence and RFC 3339.
+fn parse_date_time(text: &str) -> Option<DateTime<Utc>> {
+    if let Ok(naive) = NaiveDateTime::parse_from_str(text, DATE_TIME_FORMAT) {
+        return Some(Utc.from_utc_datetime(&naive));
+    }
+
+    DateTime::parse_from_rfc3339(text)
+        .ok()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+/// Type of an `xsi:type` attribute, such as `xsd:int` or `tns1:RemotePage`.
+fn declared_type(name: &str) -> TypeRef {
+    match name.split_once(':') {
+        Some(("xsd", local)) | Some(("soapenc", local)) => TypeRef::Builtin(local.into()),
+        Some((_, local)) => TypeRef::Named(local.into()),
+        None => TypeRef::Named(name.into()),
+    }
+}
+
+/// Convert an argument of a built-in type, falling back to a string if the text does not parse.
+fn builtin_argument(element: &Element, name: &str, types: &TypeRegistry) -> Value {
+    let text = element.text.as_deref().unwrap_or("").trim();
+
+    let value = match name {
+        "Array" => return array_argument(element, None, types),
+        "boolean" => match text {
+            "true" | "1" => Some(Value::Boolean(true)),
+            "false" | "0" => Some(Value::Boolean(false)),
+            _ => None,
+        },
+        "int" | "short" | "byte" => text.parse().ok().map(Value::Int),
+        "double" | "float" => text.parse().ok().map(Value::Double),
+        "dateTime" => parse_date_time(text).map(Value::DateTime),
+        _ => None,
+    };
+
+    value.unwrap_or_else(|| Value::String(element.text.clone().unwrap_or_default()))
+}
+
+/// Convert an argument of a complex type, a struct unless the type is an array.
+fn named_argument(element: &Element, name: &str, types: &TypeRegistry) -> Value {
+    let complex = types.get(name);
+
+    if let Some(item) = complex.and_then(|complex| complex.array_of.as_ref()) {
+        return array_argument(element, Some(item), types);
+    }
+    // Axis names array types `ArrayOf_<item type>`
+    if complex.is_none() && name.starts_with("ArrayOf") {
+        return array_argument(element, None, types);
+    }
+
+    let fields = types.all_fields(name);
+    Value::Struct(
+        element
+            .children
+            .iter()
+            .map(|child| {
+                let field = fields.iter().find(|field| field.name == child.name);
+                let value =
+                    Value::from_typed_argument(child, field.map(|field| &field.type_ref), types);
+                (child.name.clone(), value)
+            })
+            .collect(),
+    )
+}
+
+fn array_argument(element: &Element, item: Option<&TypeRef>, types: &TypeRegistry) -> Value {
+    Value::Array(
+        element
+            .children
+            .iter()
+            .map(|child| Value::from_typed_argument(child, item, types))
+            .collect(),
+    )
+}
+
+/// Convert an argument without a known type by its shape.
+fn untyped_argument(element: &Element, types: &TypeRegistry) -> Value {
+    if element.children.is_empty() {
+        return Value::String(element.text.clone().unwrap_or_default());
+    }
+
+    if element.children.iter().all(|child| child.name == "item") {
+        return array_argument(element, None, types);
+    }
+
+    Value::Struct(
+        element
+            .children
+            .iter()
+            .map(|child| {
+                (
+                    child.name.clone(),
+                    Value::from_typed_argument(child, None, types),
+                )
+            })
+            .collect(),
+    )
+}
+
+fn invalid_value(element: &Element) -> XmlRpcError {
+    XmlRpcError::InvalidValue {
+        tag: element.name.clone(),
+        text: element.text.clone().unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(xml: &str) -> Value {
+        Value::from_element(&Element::parse(xml.as_bytes()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn reads_typed_values() {
+        let value = parse(
+            r#"<value><struct>
+                <member><name>id</name><value>42</value></member>
+                <member><name>version</name><value><int>3</int></value></member>
+                <member><name>current</name><value><boolean>1</boolean></value></member>
+                <member><name>homePage</name><value><string>false</string></value></member>
+                <member><name>created</name><value><dateTime.iso8601>20190417T13:45:02</dateTime.iso8601></value></member>
+                <member><name>labels</name><value><array><data><value>a</value><value><string>b</string></value></data></array></value></member>
+            </struct></value>"#,
+        );
+
+        assert_eq!(value.get_long("id").unwrap(), 42);
+        assert_eq!(value.get_int("version").unwrap(), 3);
+        assert!(value.get_boolean("current").unwrap());
+        assert!(!value.get_boolean("homePage").unwrap());
+        assert_eq!(
+            value.get_datetime("created").unwrap().to_rfc3339(),
+            "2019-04-17T13:45:02+00:00"
+        );
+        assert_eq!(
+            value.member("labels"),
+            Some(&Value::Array(vec![
+                Value::String("a".into()),
+                Value::String("b".into())
+            ]))
+        );
+        assert!(value.get_long("title").is_err());
+        assert!(value.get_long("current").is_err());
+    }
+
+    #[test]
+    fn writes_values_which_read_back() {
+        let value = Value::Struct(
+            vec![
+                ("title".to_string(), Value::String("Home".into())),
+                ("minorEdit".to_string(), Value::Boolean(true)),
+                ("count".to_string(), Value::Int(-2)),
+                ("ratio".to_string(), Value::Double(0.5)),
+                (
+                    "created".to_string(),
+                    Value::DateTime(Utc.with_ymd_and_hms(2019, 4, 17, 13, 45, 2).unwrap()),
+                ),
+                ("ids".to_string(), Value::Array(vec![Value::Int(1)])),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let xml = value.to_element().to_string();
+        assert!(xml.contains("<dateTime.iso8601>20190417T13:45:02</dateTime.iso8601>"));
+        assert_eq!(parse(&xml), value);
+    }
+
+    #[test]
+    fn converts_method_arguments() {
+        let page = Element::node("page")
+            .with_child(Element::node("id").with_text("42"))
+            .with_child(Element::node("title").with_text("Home"));
+        let ids = Element::node("pageIds")
+            .with_child(Element::node("item").with_text("1"))
+            .with_child(Element::node("item").with_text("2"));
+
+        let page = Value::from_argument(&page);
+        assert_eq!(page.get_string("id").unwrap(), "42");
+        assert_eq!(page.get_string("title").unwrap(), "Home");
+        assert_eq!(
+            Value::from_argument(&ids),
+            Value::Array(vec![Value::String("1".into()), Value::String("2".into())])
+        );
+    }
+
+    #[test]
+    fn converts_arguments_by_declared_type() {
+        let options = Element::node("options")
+            .with_child(
+                Element::node("minorEdit")
+                    .with_attr("xsi:type", "xsd:boolean")
+                    .with_text("true"),
+            )
+            .with_child(
+                Element::node("version")
+                    .with_attr("xsi:type", "xsd:int")
+                    .with_text("3"),
+            )
+            .with_child(
+                Element::node("pageId")
+                    .with_attr("xsi:type", "xsd:long")
+                    .with_text("42"),
+            )
+            .with_child(Element::node("labels").with_attr("xsi:type", "soapenc:Array"));
+
+        let options = Value::from_argument(&options);
+        assert_eq!(options.member("minorEdit"), Some(&Value::Boolean(true)));
+        assert_eq!(options.member("version"), Some(&Value::Int(3)));
+        assert_eq!(options.member("pageId"), Some(&Value::String("42".into())));
+        assert_eq!(options.member("labels"), Some(&Value::Array(vec![])));
+    }
+
+    #[test]
+    fn converts_arguments_by_wsdl_type() {
+        let wsdl = ::wsdl::parse(include_str!(
+            "../../tests/fixtures/confluenceservice-v2.wsdl"
+        ))
+        .unwrap();
+        let page = Element::node("page")
+            .with_child(Element::node("id").with_text("42"))
+            .with_child(Element::node("version").with_text("3"));
+
+        let page = Value::from_typed_argument(
+            &page,
+            Some(&TypeRef::Named("RemotePage".into())),
+            &wsdl.types,
+        );
+        assert_eq!(page.member("id"), Some(&Value::String("42".into())));
+        assert_eq!(page.member("version"), Some(&Value::Int(3)));
+
+        let empty = Element::node("pages");
+        assert_eq!(
+            Value::from_typed_argument(
+                &empty,
+                Some(&TypeRef::Named("ArrayOf_tns1_RemotePage".into())),
+                &wsdl.types,
+            ),
+            Value::Array(vec![])
+        );
+        assert_eq!(
+            Value::from_typed_argument(
+                &empty,
+                Some(&TypeRef::Named("RemotePage".into())),
+                &wsdl.types,
+            ),
+            Value::Struct(BTreeMap::new())
+        );
+    }
+}
@@ -0,0 +1,3239 @@
+// Typed bindings for the Confluence SOAP service.
+//
+// Generated from the WSDL by `confluence::wsdl::codegen`, do not edit.
+
+use chrono::{DateTime, Utc};
+use confluence::rpser::value::{array, field, SoapValue};
+use confluence::rpser::xml::BuildElement;
+use confluence::rpser::Method;
+use confluence::{FromElement, Result, Session};
+use xmltree::Element;
+
+/// `AbstractRemotePageSummary` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AbstractRemotePageSummary {
+    pub id: Option<i64>,
+    pub permissions: Option<i32>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
+impl SoapValue for AbstractRemotePageSummary {
+    fn from_soap(element: &Element) -> Result<AbstractRemotePageSummary> {
+        Ok(AbstractRemotePageSummary {
+            id: field(element, "id")?,
+            permissions: field(element, "permissions")?,
+            space: field(element, "space")?,
+            title: field(element, "title")?,
+            url: field(element, "url")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.permissions {
+            element = element.with_child(value.to_soap("permissions"));
+        }
+        if let Some(ref value) = self.space {
+            element = element.with_child(value.to_soap("space"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        element
+    }
+}
+
+impl FromElement for AbstractRemotePageSummary {
+    fn from_element(element: Element) -> Result<AbstractRemotePageSummary> {
+        AbstractRemotePageSummary::from_soap(&element)
+    }
+}
+
+/// `RemoteAttachment` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteAttachment {
+    pub comment: Option<String>,
+    pub content_type: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub file_name: Option<String>,
+    pub file_size: Option<i64>,
+    pub id: Option<i64>,
+    pub page_id: Option<i64>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
+impl SoapValue for RemoteAttachment {
+    fn from_soap(element: &Element) -> Result<RemoteAttachment> {
+        Ok(RemoteAttachment {
+            comment: field(element, "comment")?,
+            content_type: field(element, "contentType")?,
+            created: field(element, "created")?,
+            creator: field(element, "creator")?,
+            file_name: field(element, "fileName")?,
+            file_size: field(element, "fileSize")?,
+            id: field(element, "id")?,
+            page_id: field(element, "pageId")?,
+            title: field(element, "title")?,
+            url: field(element, "url")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.comment {
+            element = element.with_child(value.to_soap("comment"));
+        }
+        if let Some(ref value) = self.content_type {
+            element = element.with_child(value.to_soap("contentType"));
+        }
+        if let Some(ref value) = self.created {
+            element = element.with_child(value.to_soap("created"));
+        }
+        if let Some(ref value) = self.creator {
+            element = element.with_child(value.to_soap("creator"));
+        }
+        if let Some(ref value) = self.file_name {
+            element = element.with_child(value.to_soap("fileName"));
+        }
+        if let Some(ref value) = self.file_size {
+            element = element.with_child(value.to_soap("fileSize"));
+        }
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.page_id {
+            element = element.with_child(value.to_soap("pageId"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteAttachment {
+    fn from_element(element: Element) -> Result<RemoteAttachment> {
+        RemoteAttachment::from_soap(&element)
+    }
+}
+
+/// `RemoteBlogEntry` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteBlogEntry {
+    pub id: Option<i64>,
+    pub permissions: Option<i32>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub author: Option<String>,
+    pub content: Option<String>,
+    pub publish_date: Option<DateTime<Utc>>,
+    pub version: Option<i32>,
+}
+
+impl SoapValue for RemoteBlogEntry {
+    fn from_soap(element: &Element) -> Result<RemoteBlogEntry> {
+        Ok(RemoteBlogEntry {
+            id: field(element, "id")?,
+            permissions: field(element, "permissions")?,
+            space: field(element, "space")?,
+            title: field(element, "title")?,
+            url: field(element, "url")?,
+            author: field(element, "author")?,
+            content: field(element, "content")?,
+            publish_date: field(element, "publishDate")?,
+            version: field(element, "version")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.permissions {
+            element = element.with_child(value.to_soap("permissions"));
+        }
+        if let Some(ref value) = self.space {
+            element = element.with_child(value.to_soap("space"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        if let Some(ref value) = self.author {
+            element = element.with_child(value.to_soap("author"));
+        }
+        if let Some(ref value) = self.content {
+            element = element.with_child(value.to_soap("content"));
+        }
+        if let Some(ref value) = self.publish_date {
+            element = element.with_child(value.to_soap("publishDate"));
+        }
+        if let Some(ref value) = self.version {
+            element = element.with_child(value.to_soap("version"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteBlogEntry {
+    fn from_element(element: Element) -> Result<RemoteBlogEntry> {
+        RemoteBlogEntry::from_soap(&element)
+    }
+}
+
+/// `RemoteBlogEntrySummary` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteBlogEntrySummary {
+    pub id: Option<i64>,
+    pub permissions: Option<i32>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub author: Option<String>,
+    pub publish_date: Option<DateTime<Utc>>,
+}
+
+impl SoapValue for RemoteBlogEntrySummary {
+    fn from_soap(element: &Element) -> Result<RemoteBlogEntrySummary> {
+        Ok(RemoteBlogEntrySummary {
+            id: field(element, "id")?,
+            permissions: field(element, "permissions")?,
+            space: field(element, "space")?,
+            title: field(element, "title")?,
+            url: field(element, "url")?,
+            author: field(element, "author")?,
+            publish_date: field(element, "publishDate")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.permissions {
+            element = element.with_child(value.to_soap("permissions"));
+        }
+        if let Some(ref value) = self.space {
+            element = element.with_child(value.to_soap("space"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        if let Some(ref value) = self.author {
+            element = element.with_child(value.to_soap("author"));
+        }
+        if let Some(ref value) = self.publish_date {
+            element = element.with_child(value.to_soap("publishDate"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteBlogEntrySummary {
+    fn from_element(element: Element) -> Result<RemoteBlogEntrySummary> {
+        RemoteBlogEntrySummary::from_soap(&element)
+    }
+}
+
+/// `RemoteClusterInformation` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteClusterInformation {
+    pub description: Option<String>,
+    pub is_running: Option<bool>,
+    pub member_count: Option<i32>,
+    pub members: Option<Vec<String>>,
+    pub multicast_address: Option<String>,
+    pub multicast_port: Option<String>,
+    pub name: Option<String>,
+}
+
+impl SoapValue for RemoteClusterInformation {
+    fn from_soap(element: &Element) -> Result<RemoteClusterInformation> {
+        Ok(RemoteClusterInformation {
+            description: field(element, "description")?,
+            is_running: field(element, "isRunning")?,
+            member_count: field(element, "memberCount")?,
+            members: field(element, "members")?,
+            multicast_address: field(element, "multicastAddress")?,
+            multicast_port: field(element, "multicastPort")?,
+            name: field(element, "name")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.description {
+            element = element.with_child(value.to_soap("description"));
+        }
+        if let Some(ref value) = self.is_running {
+            element = element.with_child(value.to_soap("isRunning"));
+        }
+        if let Some(ref value) = self.member_count {
+            element = element.with_child(value.to_soap("memberCount"));
+        }
+        if let Some(ref value) = self.members {
+            element = element.with_child(value.to_soap("members"));
+        }
+        if let Some(ref value) = self.multicast_address {
+            element = element.with_child(value.to_soap("multicastAddress"));
+        }
+        if let Some(ref value) = self.multicast_port {
+            element = element.with_child(value.to_soap("multicastPort"));
+        }
+        if let Some(ref value) = self.name {
+            element = element.with_child(value.to_soap("name"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteClusterInformation {
+    fn from_element(element: Element) -> Result<RemoteClusterInformation> {
+        RemoteClusterInformation::from_soap(&element)
+    }
+}
+
+/// `RemoteComment` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteComment {
+    pub content: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub id: Option<i64>,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub page_id: Option<i64>,
+    pub parent_id: Option<i64>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+}
+
+impl SoapValue for RemoteComment {
+    fn from_soap(element: &Element) -> Result<RemoteComment> {
+        Ok(RemoteComment {
+            content: field(element, "content")?,
+            created: field(element, "created")?,
+            creator: field(element, "creator")?,
+            id: field(element, "id")?,
+            modified: field(element, "modified")?,
+            modifier: field(element, "modifier")?,
+            page_id: field(element, "pageId")?,
+            parent_id: field(element, "parentId")?,
+            title: field(element, "title")?,
+            url: field(element, "url")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.content {
+            element = element.with_child(value.to_soap("content"));
+        }
+        if let Some(ref value) = self.created {
+            element = element.with_child(value.to_soap("created"));
+        }
+        if let Some(ref value) = self.creator {
+            element = element.with_child(value.to_soap("creator"));
+        }
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.modified {
+            element = element.with_child(value.to_soap("modified"));
+        }
+        if let Some(ref value) = self.modifier {
+            element = element.with_child(value.to_soap("modifier"));
+        }
+        if let Some(ref value) = self.page_id {
+            element = element.with_child(value.to_soap("pageId"));
+        }
+        if let Some(ref value) = self.parent_id {
+            element = element.with_child(value.to_soap("parentId"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteComment {
+    fn from_element(element: Element) -> Result<RemoteComment> {
+        RemoteComment::from_soap(&element)
+    }
+}
+
+/// `RemoteContentPermission` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteContentPermission {
+    pub group_name: Option<String>,
+    pub type_: Option<String>,
+    pub user_name: Option<String>,
+}
+
+impl SoapValue for RemoteContentPermission {
+    fn from_soap(element: &Element) -> Result<RemoteContentPermission> {
+        Ok(RemoteContentPermission {
+            group_name: field(element, "groupName")?,
+            type_: field(element, "type")?,
+            user_name: field(element, "userName")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.group_name {
+            element = element.with_child(value.to_soap("groupName"));
+        }
+        if let Some(ref value) = self.type_ {
+            element = element.with_child(value.to_soap("type"));
+        }
+        if let Some(ref value) = self.user_name {
+            element = element.with_child(value.to_soap("userName"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteContentPermission {
+    fn from_element(element: Element) -> Result<RemoteContentPermission> {
+        RemoteContentPermission::from_soap(&element)
+    }
+}
+
+/// `RemoteContentPermissionSet` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteContentPermissionSet {
+    pub content_permissions: Option<Vec<RemoteContentPermission>>,
+    pub type_: Option<String>,
+}
+
+impl SoapValue for RemoteContentPermissionSet {
+    fn from_soap(element: &Element) -> Result<RemoteContentPermissionSet> {
+        Ok(RemoteContentPermissionSet {
+            content_permissions: field(element, "contentPermissions")?,
+            type_: field(element, "type")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.content_permissions {
+            element = element.with_child(value.to_soap("contentPermissions"));
+        }
+        if let Some(ref value) = self.type_ {
+            element = element.with_child(value.to_soap("type"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteContentPermissionSet {
+    fn from_element(element: Element) -> Result<RemoteContentPermissionSet> {
+        RemoteContentPermissionSet::from_soap(&element)
+    }
+}
+
+/// `RemoteContentSummaries` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteContentSummaries {
+    pub content: Option<Vec<RemoteContentSummary>>,
+    pub offset: Option<i32>,
+    pub total_available: Option<i32>,
+}
+
+impl SoapValue for RemoteContentSummaries {
+    fn from_soap(element: &Element) -> Result<RemoteContentSummaries> {
+        Ok(RemoteContentSummaries {
+            content: field(element, "content")?,
+            offset: field(element, "offset")?,
+            total_available: field(element, "totalAvailable")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.content {
+            element = element.with_child(value.to_soap("content"));
+        }
+        if let Some(ref value) = self.offset {
+            element = element.with_child(value.to_soap("offset"));
+        }
+        if let Some(ref value) = self.total_available {
+            element = element.with_child(value.to_soap("totalAvailable"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteContentSummaries {
+    fn from_element(element: Element) -> Result<RemoteContentSummaries> {
+        RemoteContentSummaries::from_soap(&element)
+    }
+}
+
+/// `RemoteContentSummary` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteContentSummary {
+    pub content_status: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub id: Option<i64>,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub type_: Option<String>,
+    pub version: Option<i32>,
+}
+
+impl SoapValue for RemoteContentSummary {
+    fn from_soap(element: &Element) -> Result<RemoteContentSummary> {
+        Ok(RemoteContentSummary {
+            content_status: field(element, "contentStatus")?,
+            created: field(element, "created")?,
+            creator: field(element, "creator")?,
+            id: field(element, "id")?,
+            modified: field(element, "modified")?,
+            modifier: field(element, "modifier")?,
+            space: field(element, "space")?,
+            title: field(element, "title")?,
+            type_: field(element, "type")?,
+            version: field(element, "version")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.content_status {
+            element = element.with_child(value.to_soap("contentStatus"));
+        }
+        if let Some(ref value) = self.created {
+            element = element.with_child(value.to_soap("created"));
+        }
+        if let Some(ref value) = self.creator {
+            element = element.with_child(value.to_soap("creator"));
+        }
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.modified {
+            element = element.with_child(value.to_soap("modified"));
+        }
+        if let Some(ref value) = self.modifier {
+            element = element.with_child(value.to_soap("modifier"));
+        }
+        if let Some(ref value) = self.space {
+            element = element.with_child(value.to_soap("space"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.type_ {
+            element = element.with_child(value.to_soap("type"));
+        }
+        if let Some(ref value) = self.version {
+            element = element.with_child(value.to_soap("version"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteContentSummary {
+    fn from_element(element: Element) -> Result<RemoteContentSummary> {
+        RemoteContentSummary::from_soap(&element)
+    }
+}
+
+/// `RemoteLabel` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteLabel {
+    pub id: Option<i64>,
+    pub name: Option<String>,
+    pub namespace: Option<String>,
+    pub owner: Option<String>,
+}
+
+impl SoapValue for RemoteLabel {
+    fn from_soap(element: &Element) -> Result<RemoteLabel> {
+        Ok(RemoteLabel {
+            id: field(element, "id")?,
+            name: field(element, "name")?,
+            namespace: field(element, "namespace")?,
+            owner: field(element, "owner")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.name {
+            element = element.with_child(value.to_soap("name"));
+        }
+        if let Some(ref value) = self.namespace {
+            element = element.with_child(value.to_soap("namespace"));
+        }
+        if let Some(ref value) = self.owner {
+            element = element.with_child(value.to_soap("owner"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteLabel {
+    fn from_element(element: Element) -> Result<RemoteLabel> {
+        RemoteLabel::from_soap(&element)
+    }
+}
+
+/// `RemoteNodeStatus` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteNodeStatus {
+    pub jv_mstats: Option<Element>,
+    pub application_stats: Option<Element>,
+    pub build_stats: Option<Element>,
+    pub node_id: Option<i32>,
+    pub props: Option<Element>,
+}
+
+impl SoapValue for RemoteNodeStatus {
+    fn from_soap(element: &Element) -> Result<RemoteNodeStatus> {
+        Ok(RemoteNodeStatus {
+            jv_mstats: field(element, "JVMstats")?,
+            application_stats: field(element, "applicationStats")?,
+            build_stats: field(element, "buildStats")?,
+            node_id: field(element, "nodeId")?,
+            props: field(element, "props")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.jv_mstats {
+            element = element.with_child(value.to_soap("JVMstats"));
+        }
+        if let Some(ref value) = self.application_stats {
+            element = element.with_child(value.to_soap("applicationStats"));
+        }
+        if let Some(ref value) = self.build_stats {
+            element = element.with_child(value.to_soap("buildStats"));
+        }
+        if let Some(ref value) = self.node_id {
+            element = element.with_child(value.to_soap("nodeId"));
+        }
+        if let Some(ref value) = self.props {
+            element = element.with_child(value.to_soap("props"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteNodeStatus {
+    fn from_element(element: Element) -> Result<RemoteNodeStatus> {
+        RemoteNodeStatus::from_soap(&element)
+    }
+}
+
+/// `RemotePage` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePage {
+    pub id: Option<i64>,
+    pub permissions: Option<i32>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub content: Option<String>,
+    pub content_status: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+    pub current: Option<bool>,
+    pub home_page: Option<bool>,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub parent_id: Option<i64>,
+    pub version: Option<i32>,
+}
+
+impl SoapValue for RemotePage {
+    fn from_soap(element: &Element) -> Result<RemotePage> {
+        Ok(RemotePage {
+            id: field(element, "id")?,
+            permissions: field(element, "permissions")?,
+            space: field(element, "space")?,
+            title: field(element, "title")?,
+            url: field(element, "url")?,
+            content: field(element, "content")?,
+            content_status: field(element, "contentStatus")?,
+            created: field(element, "created")?,
+            creator: field(element, "creator")?,
+            current: field(element, "current")?,
+            home_page: field(element, "homePage")?,
+            modified: field(element, "modified")?,
+            modifier: field(element, "modifier")?,
+            parent_id: field(element, "parentId")?,
+            version: field(element, "version")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.permissions {
+            element = element.with_child(value.to_soap("permissions"));
+        }
+        if let Some(ref value) = self.space {
+            element = element.with_child(value.to_soap("space"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        if let Some(ref value) = self.content {
+            element = element.with_child(value.to_soap("content"));
+        }
+        if let Some(ref value) = self.content_status {
+            element = element.with_child(value.to_soap("contentStatus"));
+        }
+        if let Some(ref value) = self.created {
+            element = element.with_child(value.to_soap("created"));
+        }
+        if let Some(ref value) = self.creator {
+            element = element.with_child(value.to_soap("creator"));
+        }
+        if let Some(ref value) = self.current {
+            element = element.with_child(value.to_soap("current"));
+        }
+        if let Some(ref value) = self.home_page {
+            element = element.with_child(value.to_soap("homePage"));
+        }
+        if let Some(ref value) = self.modified {
+            element = element.with_child(value.to_soap("modified"));
+        }
+        if let Some(ref value) = self.modifier {
+            element = element.with_child(value.to_soap("modifier"));
+        }
+        if let Some(ref value) = self.parent_id {
+            element = element.with_child(value.to_soap("parentId"));
+        }
+        if let Some(ref value) = self.version {
+            element = element.with_child(value.to_soap("version"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemotePage {
+    fn from_element(element: Element) -> Result<RemotePage> {
+        RemotePage::from_soap(&element)
+    }
+}
+
+/// `RemotePageHistory` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePageHistory {
+    pub id: Option<i64>,
+    pub modified: Option<DateTime<Utc>>,
+    pub modifier: Option<String>,
+    pub version: Option<i32>,
+}
+
+impl SoapValue for RemotePageHistory {
+    fn from_soap(element: &Element) -> Result<RemotePageHistory> {
+        Ok(RemotePageHistory {
+            id: field(element, "id")?,
+            modified: field(element, "modified")?,
+            modifier: field(element, "modifier")?,
+            version: field(element, "version")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.modified {
+            element = element.with_child(value.to_soap("modified"));
+        }
+        if let Some(ref value) = self.modifier {
+            element = element.with_child(value.to_soap("modifier"));
+        }
+        if let Some(ref value) = self.version {
+            element = element.with_child(value.to_soap("version"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemotePageHistory {
+    fn from_element(element: Element) -> Result<RemotePageHistory> {
+        RemotePageHistory::from_soap(&element)
+    }
+}
+
+/// `RemotePageSummary` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePageSummary {
+    pub id: Option<i64>,
+    pub permissions: Option<i32>,
+    pub space: Option<String>,
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub parent_id: Option<i64>,
+}
+
+impl SoapValue for RemotePageSummary {
+    fn from_soap(element: &Element) -> Result<RemotePageSummary> {
+        Ok(RemotePageSummary {
+            id: field(element, "id")?,
+            permissions: field(element, "permissions")?,
+            space: field(element, "space")?,
+            title: field(element, "title")?,
+            url: field(element, "url")?,
+            parent_id: field(element, "parentId")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.permissions {
+            element = element.with_child(value.to_soap("permissions"));
+        }
+        if let Some(ref value) = self.space {
+            element = element.with_child(value.to_soap("space"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        if let Some(ref value) = self.parent_id {
+            element = element.with_child(value.to_soap("parentId"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemotePageSummary {
+    fn from_element(element: Element) -> Result<RemotePageSummary> {
+        RemotePageSummary::from_soap(&element)
+    }
+}
+
+/// `RemotePageUpdateOptions` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePageUpdateOptions {
+    pub minor_edit: Option<bool>,
+    pub version_comment: Option<String>,
+}
+
+impl SoapValue for RemotePageUpdateOptions {
+    fn from_soap(element: &Element) -> Result<RemotePageUpdateOptions> {
+        Ok(RemotePageUpdateOptions {
+            minor_edit: field(element, "minorEdit")?,
+            version_comment: field(element, "versionComment")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.minor_edit {
+            element = element.with_child(value.to_soap("minorEdit"));
+        }
+        if let Some(ref value) = self.version_comment {
+            element = element.with_child(value.to_soap("versionComment"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemotePageUpdateOptions {
+    fn from_element(element: Element) -> Result<RemotePageUpdateOptions> {
+        RemotePageUpdateOptions::from_soap(&element)
+    }
+}
+
+/// `RemotePermission` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemotePermission {
+    pub lock_type: Option<String>,
+    pub locked_by: Option<String>,
+}
+
+impl SoapValue for RemotePermission {
+    fn from_soap(element: &Element) -> Result<RemotePermission> {
+        Ok(RemotePermission {
+            lock_type: field(element, "lockType")?,
+            locked_by: field(element, "lockedBy")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.lock_type {
+            element = element.with_child(value.to_soap("lockType"));
+        }
+        if let Some(ref value) = self.locked_by {
+            element = element.with_child(value.to_soap("lockedBy"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemotePermission {
+    fn from_element(element: Element) -> Result<RemotePermission> {
+        RemotePermission::from_soap(&element)
+    }
+}
+
+/// `RemoteSearchResult` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteSearchResult {
+    pub excerpt: Option<String>,
+    pub id: Option<i64>,
+    pub title: Option<String>,
+    pub type_: Option<String>,
+    pub url: Option<String>,
+}
+
+impl SoapValue for RemoteSearchResult {
+    fn from_soap(element: &Element) -> Result<RemoteSearchResult> {
+        Ok(RemoteSearchResult {
+            excerpt: field(element, "excerpt")?,
+            id: field(element, "id")?,
+            title: field(element, "title")?,
+            type_: field(element, "type")?,
+            url: field(element, "url")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.excerpt {
+            element = element.with_child(value.to_soap("excerpt"));
+        }
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.title {
+            element = element.with_child(value.to_soap("title"));
+        }
+        if let Some(ref value) = self.type_ {
+            element = element.with_child(value.to_soap("type"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteSearchResult {
+    fn from_element(element: Element) -> Result<RemoteSearchResult> {
+        RemoteSearchResult::from_soap(&element)
+    }
+}
+
+/// `RemoteServerInfo` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteServerInfo {
+    pub base_url: Option<String>,
+    pub build_id: Option<String>,
+    pub development_build: Option<bool>,
+    pub major_version: Option<i32>,
+    pub minor_version: Option<i32>,
+    pub patch_level: Option<i32>,
+}
+
+impl SoapValue for RemoteServerInfo {
+    fn from_soap(element: &Element) -> Result<RemoteServerInfo> {
+        Ok(RemoteServerInfo {
+            base_url: field(element, "baseUrl")?,
+            build_id: field(element, "buildId")?,
+            development_build: field(element, "developmentBuild")?,
+            major_version: field(element, "majorVersion")?,
+            minor_version: field(element, "minorVersion")?,
+            patch_level: field(element, "patchLevel")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.base_url {
+            element = element.with_child(value.to_soap("baseUrl"));
+        }
+        if let Some(ref value) = self.build_id {
+            element = element.with_child(value.to_soap("buildId"));
+        }
+        if let Some(ref value) = self.development_build {
+            element = element.with_child(value.to_soap("developmentBuild"));
+        }
+        if let Some(ref value) = self.major_version {
+            element = element.with_child(value.to_soap("majorVersion"));
+        }
+        if let Some(ref value) = self.minor_version {
+            element = element.with_child(value.to_soap("minorVersion"));
+        }
+        if let Some(ref value) = self.patch_level {
+            element = element.with_child(value.to_soap("patchLevel"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteServerInfo {
+    fn from_element(element: Element) -> Result<RemoteServerInfo> {
+        RemoteServerInfo::from_soap(&element)
+    }
+}
+
+/// `RemoteSpace` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteSpace {
+    pub key: Option<String>,
+    pub name: Option<String>,
+    pub type_: Option<String>,
+    pub url: Option<String>,
+    pub description: Option<String>,
+    pub home_page: Option<i64>,
+    pub space_group: Option<String>,
+}
+
+impl SoapValue for RemoteSpace {
+    fn from_soap(element: &Element) -> Result<RemoteSpace> {
+        Ok(RemoteSpace {
+            key: field(element, "key")?,
+            name: field(element, "name")?,
+            type_: field(element, "type")?,
+            url: field(element, "url")?,
+            description: field(element, "description")?,
+            home_page: field(element, "homePage")?,
+            space_group: field(element, "spaceGroup")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.key {
+            element = element.with_child(value.to_soap("key"));
+        }
+        if let Some(ref value) = self.name {
+            element = element.with_child(value.to_soap("name"));
+        }
+        if let Some(ref value) = self.type_ {
+            element = element.with_child(value.to_soap("type"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        if let Some(ref value) = self.description {
+            element = element.with_child(value.to_soap("description"));
+        }
+        if let Some(ref value) = self.home_page {
+            element = element.with_child(value.to_soap("homePage"));
+        }
+        if let Some(ref value) = self.space_group {
+            element = element.with_child(value.to_soap("spaceGroup"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteSpace {
+    fn from_element(element: Element) -> Result<RemoteSpace> {
+        RemoteSpace::from_soap(&element)
+    }
+}
+
+/// `RemoteSpacePermissionSet` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteSpacePermissionSet {
+    pub space_permissions: Option<Vec<RemoteContentPermission>>,
+    pub type_: Option<String>,
+}
+
+impl SoapValue for RemoteSpacePermissionSet {
+    fn from_soap(element: &Element) -> Result<RemoteSpacePermissionSet> {
+        Ok(RemoteSpacePermissionSet {
+            space_permissions: field(element, "spacePermissions")?,
+            type_: field(element, "type")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.space_permissions {
+            element = element.with_child(value.to_soap("spacePermissions"));
+        }
+        if let Some(ref value) = self.type_ {
+            element = element.with_child(value.to_soap("type"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteSpacePermissionSet {
+    fn from_element(element: Element) -> Result<RemoteSpacePermissionSet> {
+        RemoteSpacePermissionSet::from_soap(&element)
+    }
+}
+
+/// `RemoteSpaceSummary` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteSpaceSummary {
+    pub key: Option<String>,
+    pub name: Option<String>,
+    pub type_: Option<String>,
+    pub url: Option<String>,
+}
+
+impl SoapValue for RemoteSpaceSummary {
+    fn from_soap(element: &Element) -> Result<RemoteSpaceSummary> {
+        Ok(RemoteSpaceSummary {
+            key: field(element, "key")?,
+            name: field(element, "name")?,
+            type_: field(element, "type")?,
+            url: field(element, "url")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.key {
+            element = element.with_child(value.to_soap("key"));
+        }
+        if let Some(ref value) = self.name {
+            element = element.with_child(value.to_soap("name"));
+        }
+        if let Some(ref value) = self.type_ {
+            element = element.with_child(value.to_soap("type"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteSpaceSummary {
+    fn from_element(element: Element) -> Result<RemoteSpaceSummary> {
+        RemoteSpaceSummary::from_soap(&element)
+    }
+}
+
+/// `RemoteUser` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteUser {
+    pub email: Option<String>,
+    pub fullname: Option<String>,
+    pub name: Option<String>,
+    pub url: Option<String>,
+}
+
+impl SoapValue for RemoteUser {
+    fn from_soap(element: &Element) -> Result<RemoteUser> {
+        Ok(RemoteUser {
+            email: field(element, "email")?,
+            fullname: field(element, "fullname")?,
+            name: field(element, "name")?,
+            url: field(element, "url")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.email {
+            element = element.with_child(value.to_soap("email"));
+        }
+        if let Some(ref value) = self.fullname {
+            element = element.with_child(value.to_soap("fullname"));
+        }
+        if let Some(ref value) = self.name {
+            element = element.with_child(value.to_soap("name"));
+        }
+        if let Some(ref value) = self.url {
+            element = element.with_child(value.to_soap("url"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteUser {
+    fn from_element(element: Element) -> Result<RemoteUser> {
+        RemoteUser::from_soap(&element)
+    }
+}
+
+/// `RemoteUserInformation` complex type.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RemoteUserInformation {
+    pub content: Option<String>,
+    pub creation_date: Option<DateTime<Utc>>,
+    pub creator_name: Option<String>,
+    pub id: Option<i64>,
+    pub last_modification_date: Option<DateTime<Utc>>,
+    pub last_modifier_name: Option<String>,
+    pub username: Option<String>,
+    pub version: Option<i32>,
+}
+
+impl SoapValue for RemoteUserInformation {
+    fn from_soap(element: &Element) -> Result<RemoteUserInformation> {
+        Ok(RemoteUserInformation {
+            content: field(element, "content")?,
+            creation_date: field(element, "creationDate")?,
+            creator_name: field(element, "creatorName")?,
+            id: field(element, "id")?,
+            last_modification_date: field(element, "lastModificationDate")?,
+            last_modifier_name: field(element, "lastModifierName")?,
+            username: field(element, "username")?,
+            version: field(element, "version")?,
+        })
+    }
+
+    fn to_soap(&self, name: &str) -> Element {
+        let mut element = Element::node(name);
+        if let Some(ref value) = self.content {
+            element = element.with_child(value.to_soap("content"));
+        }
+        if let Some(ref value) = self.creation_date {
+            element = element.with_child(value.to_soap("creationDate"));
+        }
+        if let Some(ref value) = self.creator_name {
+            element = element.with_child(value.to_soap("creatorName"));
+        }
+        if let Some(ref value) = self.id {
+            element = element.with_child(value.to_soap("id"));
+        }
+        if let Some(ref value) = self.last_modification_date {
+            element = element.with_child(value.to_soap("lastModificationDate"));
+        }
+        if let Some(ref value) = self.last_modifier_name {
+            element = element.with_child(value.to_soap("lastModifierName"));
+        }
+        if let Some(ref value) = self.username {
+            element = element.with_child(value.to_soap("username"));
+        }
+        if let Some(ref value) = self.version {
+            element = element.with_child(value.to_soap("version"));
+        }
+        element
+    }
+}
+
+impl FromElement for RemoteUserInformation {
+    fn from_element(element: Element) -> Result<RemoteUserInformation> {
+        RemoteUserInformation::from_soap(&element)
+    }
+}
+
+/// Typed wrappers for the operations of the Confluence SOAP service.
+pub trait ConfluenceApi {
+    /// Calls `addAnonymousPermissionToSpace`.
+    fn soap_add_anonymous_permission_to_space(&self, permission: &str, space_key: &str) -> Result<bool>;
+
+    /// Calls `addAnonymousPermissionsToSpace`.
+    fn soap_add_anonymous_permissions_to_space(&self, permissions: &[String], space_key: &str) -> Result<bool>;
+
+    /// Calls `addAttachment`.
+    fn soap_add_attachment(&self, content_id: i64, attachment: &RemoteAttachment, attachment_data: &str) -> Result<RemoteAttachment>;
+
+    /// Calls `addAttachment`.
+    fn soap_add_attachment_2(&self, attachment: &RemoteAttachment, attachment_data: &str) -> Result<RemoteAttachment>;
+
+    /// Calls `addComment`.
+    fn soap_add_comment(&self, comment: &RemoteComment) -> Result<RemoteComment>;
+
+    /// Calls `addGroup`.
+    fn soap_add_group(&self, group: &str) -> Result<()>;
+
+    /// Calls `addLabelById`.
+    fn soap_add_label_by_id(&self, label_id: i64, object_id: i64) -> Result<bool>;
+
+    /// Calls `addLabelByName`.
+    fn soap_add_label_by_name(&self, label_name: &str, object_id: i64) -> Result<bool>;
+
+    /// Calls `addLabelByNameToSpace`.
+    fn soap_add_label_by_name_to_space(&self, label_name: &str, space_key: &str) -> Result<bool>;
+
+    /// Calls `addLabelByObject`.
+    fn soap_add_label_by_object(&self, label_object: &RemoteLabel, object_id: i64) -> Result<bool>;
+
+    /// Calls `addPermissionToSpace`.
+    fn soap_add_permission_to_space(&self, permission: &str, remote_entity_name: &str, space_key: &str) -> Result<bool>;
+
+    /// Calls `addPermissionsToSpace`.
+    fn soap_add_permissions_to_space(&self, permissions: &[String], remote_entity_name: &str, space_key: &str) -> Result<bool>;
+
+    /// Calls `addPersonalSpace`.
+    fn soap_add_personal_space(&self, space: &RemoteSpace, username: &str) -> Result<RemoteSpace>;
+
+    /// Calls `addPersonalSpaceWithDefaultPermissions`.
+    fn soap_add_personal_space_with_default_permissions(&self, space: &RemoteSpace, username: &str) -> Result<RemoteSpace>;
+
+    /// Calls `addProfilePicture`.
+    fn soap_add_profile_picture(&self, user_name: &str, file_name: &str, mime_type: &str, picture_data: &str) -> Result<bool>;
+
+    /// Calls `addSpace`.
+    fn soap_add_space(&self, space: &RemoteSpace) -> Result<RemoteSpace>;
+
+    /// Calls `addSpaceWithDefaultPermissions`.
+    fn soap_add_space_with_default_permissions(&self, space: &RemoteSpace) -> Result<RemoteSpace>;
+
+    /// Calls `addUser`.
+    fn soap_add_user(&self, user: &RemoteUser, password: &str) -> Result<()>;
+
+    /// Calls `addUser`.
+    fn soap_add_user_2(&self, user: &RemoteUser, password: &str, notify_user: bool) -> Result<()>;
+
+    /// Calls `addUserToGroup`.
+    fn soap_add_user_to_group(&self, username: &str, groupname: &str) -> Result<bool>;
+
+    /// Calls `changeMyPassword`.
+    fn soap_change_my_password(&self, old_password: &str, new_password: &str) -> Result<bool>;
+
+    /// Calls `changeUserPassword`.
+    fn soap_change_user_password(&self, username: &str, new_password: &str) -> Result<bool>;
+
+    /// Calls `clearIndexQueue`.
+    fn soap_clear_index_queue(&self) -> Result<bool>;
+
+    /// Calls `convertToPersonalSpace`.
+    fn soap_convert_to_personal_space(&self, user_name: &str, space_key: &str, new_space_name: &str, update_links: bool) -> Result<bool>;
+
+    /// Calls `deactivateUser`.
+    fn soap_deactivate_user(&self, username: &str) -> Result<bool>;
+
+    /// Calls `editComment`.
+    fn soap_edit_comment(&self, comment: &RemoteComment) -> Result<RemoteComment>;
+
+    /// Calls `editUser`.
+    fn soap_edit_user(&self, user: &RemoteUser) -> Result<bool>;
+
+    /// Calls `emptyTrash`.
+    fn soap_empty_trash(&self, space_key: &str) -> Result<bool>;
+
+    /// Calls `exportSite`.
+    fn soap_export_site(&self, export_attachments: bool) -> Result<String>;
+
+    /// Calls `exportSpace`.
+    fn soap_export_space(&self, space_key: &str, export_type: &str) -> Result<String>;
+
+    /// Calls `exportSpace`.
+    fn soap_export_space_2(&self, space_key: &str, export_type: &str, export_all: bool) -> Result<String>;
+
+    /// Calls `flushIndexQueue`.
+    fn soap_flush_index_queue(&self) -> Result<bool>;
+
+    /// Calls `getActiveUsers`.
+    fn soap_get_active_users(&self, view_all: bool) -> Result<Vec<String>>;
+
+    /// Calls `getAncestors`.
+    fn soap_get_ancestors(&self, page_id: i64) -> Result<Vec<RemotePageSummary>>;
+
+    /// Calls `getAttachment`.
+    fn soap_get_attachment(&self, content_id: i64, file_name: &str, version: i32) -> Result<RemoteAttachment>;
+
+    /// Calls `getAttachmentData`.
+    fn soap_get_attachment_data(&self, content_id: i64, file_name: &str, version: i32) -> Result<String>;
+
+    /// Calls `getAttachments`.
+    fn soap_get_attachments(&self, page_id: i64) -> Result<Vec<RemoteAttachment>>;
+
+    /// Calls `getBlogEntries`.
+    fn soap_get_blog_entries(&self, space_key: &str) -> Result<Vec<RemoteBlogEntrySummary>>;
+
+    /// Calls `getBlogEntry`.
+    fn soap_get_blog_entry(&self, entry_id: i64) -> Result<RemoteBlogEntry>;
+
+    /// Calls `getBlogEntryByDateAndTitle`.
+    fn soap_get_blog_entry_by_date_and_title(&self, space_key: &str, year: i32, month: i32, day_of_month: i32, post_title: &str) -> Result<RemoteBlogEntry>;
+
+    /// Calls `getBlogEntryByDayAndTitle`.
+    fn soap_get_blog_entry_by_day_and_title(&self, space_key: &str, day_of_month: i32, post_title: &str) -> Result<RemoteBlogEntry>;
+
+    /// Calls `getChildren`.
+    fn soap_get_children(&self, page_id: i64) -> Result<Vec<RemotePageSummary>>;
+
+    /// Calls `getClusterInformation`.
+    fn soap_get_cluster_information(&self) -> Result<RemoteClusterInformation>;
+
+    /// Calls `getClusterNodeStatuses`.
+    fn soap_get_cluster_node_statuses(&self) -> Result<Vec<RemoteNodeStatus>>;
+
+    /// Calls `getComment`.
+    fn soap_get_comment(&self, comment_id: i64) -> Result<RemoteComment>;
+
+    /// Calls `getComments`.
+    fn soap_get_comments(&self, page_id: i64) -> Result<Vec<RemoteComment>>;
+
+    /// Calls `getContentPermissionSet`.
+    fn soap_get_content_permission_set(&self, content_id: i64, permission_type: &str) -> Result<RemoteContentPermissionSet>;
+
+    /// Calls `getContentPermissionSets`.
+    fn soap_get_content_permission_sets(&self, content_id: i64) -> Result<Vec<RemoteContentPermissionSet>>;
+
+    /// Calls `getDescendents`.
+    fn soap_get_descendents(&self, page_id: i64) -> Result<Vec<RemotePageSummary>>;
+
+    /// Calls `getGroups`.
+    fn soap_get_groups(&self) -> Result<Vec<String>>;
+
+    /// Calls `getLabelContentById`.
+    fn soap_get_label_content_by_id(&self, label_id: i64) -> Result<Vec<RemoteSearchResult>>;
+
+    /// Calls `getLabelContentByName`.
+    fn soap_get_label_content_by_name(&self, label_name: &str) -> Result<Vec<RemoteSearchResult>>;
+
+    /// Calls `getLabelContentByObject`.
+    fn soap_get_label_content_by_object(&self, label_object: &RemoteLabel) -> Result<Vec<RemoteSearchResult>>;
+
+    /// Calls `getLabelsByDetail`.
+    fn soap_get_labels_by_detail(&self, label_name: &str, namespace: &str, space_key: &str, owner: &str) -> Result<Vec<RemoteLabel>>;
+
+    /// Calls `getLabelsById`.
+    fn soap_get_labels_by_id(&self, object_id: i64) -> Result<Vec<RemoteLabel>>;
+
+    /// Calls `getMostPopularLabels`.
+    fn soap_get_most_popular_labels(&self, max_count: i32) -> Result<Vec<RemoteLabel>>;
+
+    /// Calls `getMostPopularLabelsInSpace`.
+    fn soap_get_most_popular_labels_in_space(&self, space_key: &str, max_count: i32) -> Result<Vec<RemoteLabel>>;
+
+    /// Calls `getPage`.
+    fn soap_get_page(&self, page_id: i64) -> Result<RemotePage>;
+
+    /// Calls `getPage`.
+    fn soap_get_page_2(&self, space_key: &str, page_title: &str) -> Result<RemotePage>;
+
+    /// Calls `getPageHistory`.
+    fn soap_get_page_history(&self, page_id: i64) -> Result<Vec<RemotePageHistory>>;
+
+    /// Calls `getPagePermissions`.
+    fn soap_get_page_permissions(&self, page_id: i64) -> Result<Vec<RemotePermission>>;
+
+    /// Calls `getPageSummary`.
+    fn soap_get_page_summary(&self, page_id: i64) -> Result<RemotePageSummary>;
+
+    /// Calls `getPageSummary`.
+    fn soap_get_page_summary_2(&self, space_key: &str, page_title: &str) -> Result<RemotePageSummary>;
+
+    /// Calls `getPages`.
+    fn soap_get_pages(&self, space_key: &str) -> Result<Vec<RemotePageSummary>>;
+
+    /// Calls `getPermissions`.
+    fn soap_get_permissions(&self, space_key: &str) -> Result<Vec<String>>;
+
+    /// Calls `getPermissionsForUser`.
+    fn soap_get_permissions_for_user(&self, space_key: &str, user_name: &str) -> Result<Vec<String>>;
+
+    /// Calls `getRecentlyUsedLabels`.
+    fn soap_get_recently_used_labels(&self, max_results: i32) -> Result<Vec<RemoteLabel>>;
+
+    /// Calls `getRecentlyUsedLabelsInSpace`.
+    fn soap_get_recently_used_labels_in_space(&self, space_key: &str, max_results: i32) -> Result<Vec<RemoteLabel>>;
+
+    /// Calls `getRelatedLabels`.
+    fn soap_get_related_labels(&self, label_name: &str, max_results: i32) -> Result<Vec<RemoteLabel>>;
+
+    /// Calls `getRelatedLabelsInSpace`.
+    fn soap_get_related_labels_in_space(&self, label_name: &str, space_key: &str, max_results: i32) -> Result<Vec<RemoteLabel>>;
+
+    /// Calls `getServerInfo`.
+    fn soap_get_server_info(&self) -> Result<RemoteServerInfo>;
+
+    /// Calls `getSpace`.
+    fn soap_get_space(&self, space_key: &str) -> Result<RemoteSpace>;
+
+    /// Calls `getSpaceLevelPermissions`.
+    fn soap_get_space_level_permissions(&self) -> Result<Vec<String>>;
+
+    /// Calls `getSpacePermissionSet`.
+    fn soap_get_space_permission_set(&self, space_key: &str, permission_type: &str) -> Result<RemoteSpacePermissionSet>;
+
+    /// Calls `getSpacePermissionSets`.
+    fn soap_get_space_permission_sets(&self, space_key: &str) -> Result<Vec<RemoteSpacePermissionSet>>;
+
+    /// Calls `getSpaceStatus`.
+    fn soap_get_space_status(&self, space_key: &str) -> Result<String>;
+
+    /// Calls `getSpaces`.
+    fn soap_get_spaces(&self) -> Result<Vec<RemoteSpaceSummary>>;
+
+    /// Calls `getSpacesContainingContentWithLabel`.
+    fn soap_get_spaces_containing_content_with_label(&self, label_name: &str) -> Result<Vec<RemoteSpace>>;
+
+    /// Calls `getSpacesInGroup`.
+    fn soap_get_spaces_in_group(&self, space_group_key: &str) -> Result<Vec<RemoteSpaceSummary>>;
+
+    /// Calls `getSpacesWithLabel`.
+    fn soap_get_spaces_with_label(&self, label_name: &str) -> Result<Vec<RemoteSpace>>;
+
+    /// Calls `getTrashContents`.
+    fn soap_get_trash_contents(&self, space_key: &str, offset: i32, count: i32) -> Result<RemoteContentSummaries>;
+
+    /// Calls `getUser`.
+    fn soap_get_user(&self, username: &str) -> Result<RemoteUser>;
+
+    /// Calls `getUserGroups`.
+    fn soap_get_user_groups(&self, username: &str) -> Result<Vec<String>>;
+
+    /// Calls `getUserInformation`.
+    fn soap_get_user_information(&self, username: &str) -> Result<RemoteUserInformation>;
+
+    /// Calls `getUserPreferenceBoolean`.
+    fn soap_get_user_preference_boolean(&self, key: &str) -> Result<bool>;
+
+    /// Calls `getUserPreferenceLong`.
+    fn soap_get_user_preference_long(&self, key: &str) -> Result<i64>;
+
+    /// Calls `getUserPreferenceString`.
+    fn soap_get_user_preference_string(&self, key: &str) -> Result<String>;
+
+    /// Calls `getWatchersForPage`.
+    fn soap_get_watchers_for_page(&self, page_id: i64) -> Result<Vec<RemoteUser>>;
+
+    /// Calls `getWatchersForSpace`.
+    fn soap_get_watchers_for_space(&self, space_key: &str) -> Result<Vec<RemoteUser>>;
+
+    /// Calls `hasGroup`.
+    fn soap_has_group(&self, groupname: &str) -> Result<bool>;
+
+    /// Calls `hasUser`.
+    fn soap_has_user(&self, username: &str) -> Result<bool>;
+
+    /// Calls `importSpace`.
+    fn soap_import_space(&self, import_data: &str) -> Result<bool>;
+
+    /// Calls `isActiveUser`.
+    fn soap_is_active_user(&self, username: &str) -> Result<bool>;
+
+    /// Calls `isDarkFeatureEnabled`.
+    fn soap_is_dark_feature_enabled(&self, key: &str) -> Result<bool>;
+
+    /// Calls `isPluginEnabled`.
+    fn soap_is_plugin_enabled(&self, plugin_key: &str) -> Result<bool>;
+
+    /// Calls `isPluginInstalled`.
+    fn soap_is_plugin_installed(&self, plugin_key: &str) -> Result<bool>;
+
+    /// Calls `isWatchingPage`.
+    fn soap_is_watching_page(&self, page_id: i64, username: &str) -> Result<bool>;
+
+    /// Calls `isWatchingSpace`.
+    fn soap_is_watching_space(&self, space_key: &str, username: &str) -> Result<bool>;
+
+    /// Calls `moveAttachment`.
+    fn soap_move_attachment(&self, original_content_id: i64, original_name: &str, new_content_id: i64, new_name: &str) -> Result<bool>;
+
+    /// Calls `movePage`.
+    fn soap_move_page(&self, source_page_id: i64, target_page_id: i64, position: &str) -> Result<bool>;
+
+    /// Calls `movePageToTopLevel`.
+    fn soap_move_page_to_top_level(&self, page_id: i64, target_space_key: &str) -> Result<bool>;
+
+    /// Calls `purgeFromTrash`.
+    fn soap_purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool>;
+
+    /// Calls `reactivateUser`.
+    fn soap_reactivate_user(&self, username: &str) -> Result<bool>;
+
+    /// Calls `removeAllPermissionsForGroup`.
+    fn soap_remove_all_permissions_for_group(&self, groupname: &str) -> Result<bool>;
+
+    /// Calls `removeAnonymousPermissionFromSpace`.
+    fn soap_remove_anonymous_permission_from_space(&self, permission: &str, space_key: &str) -> Result<bool>;
+
+    /// Calls `removeAttachment`.
+    fn soap_remove_attachment(&self, content_id: i64, file_name: &str) -> Result<bool>;
+
+    /// Calls `removeComment`.
+    fn soap_remove_comment(&self, comment_id: i64) -> Result<bool>;
+
+    /// Calls `removeGroup`.
+    fn soap_remove_group(&self, groupname: &str, default_group_name: &str) -> Result<bool>;
+
+    /// Calls `removeLabelById`.
+    fn soap_remove_label_by_id(&self, label_id: i64, object_id: i64) -> Result<bool>;
+
+    /// Calls `removeLabelByName`.
+    fn soap_remove_label_by_name(&self, label_name: &str, object_id: i64) -> Result<bool>;
+
+    /// Calls `removeLabelByNameFromSpace`.
+    fn soap_remove_label_by_name_from_space(&self, label_name: &str, space_key: &str) -> Result<bool>;
+
+    /// Calls `removeLabelByObject`.
+    fn soap_remove_label_by_object(&self, label_object: &RemoteLabel, object_id: i64) -> Result<bool>;
+
+    /// Calls `removePage`.
+    fn soap_remove_page(&self, page_id: i64) -> Result<bool>;
+
+    /// Calls `removePageVersionById`.
+    fn soap_remove_page_version_by_id(&self, historical_page_id: i64) -> Result<bool>;
+
+    /// Calls `removePageVersionByVersion`.
+    fn soap_remove_page_version_by_version(&self, page_id: i64, version: i32) -> Result<bool>;
+
+    /// Calls `removePageWatch`.
+    fn soap_remove_page_watch(&self, page_id: i64) -> Result<bool>;
+
+    /// Calls `removePageWatchForUser`.
+    fn soap_remove_page_watch_for_user(&self, page_id: i64, username: &str) -> Result<bool>;
+
+    /// Calls `removePermissionFromSpace`.
+    fn soap_remove_permission_from_space(&self, permission: &str, remote_entity_name: &str, space_key: &str) -> Result<bool>;
+
+    /// Calls `removeSpace`.
+    fn soap_remove_space(&self, space_key: &str) -> Result<bool>;
+
+    /// Calls `removeSpaceWatch`.
+    fn soap_remove_space_watch(&self, space_key: &str) -> Result<bool>;
+
+    /// Calls `removeUser`.
+    fn soap_remove_user(&self, username: &str) -> Result<bool>;
+
+    /// Calls `removeUserFromGroup`.
+    fn soap_remove_user_from_group(&self, username: &str, groupname: &str) -> Result<bool>;
+
+    /// Calls `renameUser`.
+    fn soap_rename_user(&self, old_username: &str, new_username: &str) -> Result<bool>;
+
+    /// Calls `renameUsers`.
+    fn soap_rename_users(&self, old_usernames_to_new_usernames: &Element) -> Result<Vec<String>>;
+
+    /// Calls `renderContent`.
+    fn soap_render_content(&self, space_key: &str, page_id: i64, content: &str) -> Result<String>;
+
+    /// Calls `renderContent`.
+    fn soap_render_content_2(&self, space_key: &str, page_id: i64, content: &str, parameters: &Element) -> Result<String>;
+
+    /// Calls `search`.
+    fn soap_search(&self, query: &str, max_results: i32) -> Result<Vec<RemoteSearchResult>>;
+
+    /// Calls `search`.
+    fn soap_search_2(&self, query: &str, parameters: &Element, max_results: i32) -> Result<Vec<RemoteSearchResult>>;
+
+    /// Calls `setContentPermissions`.
+    fn soap_set_content_permissions(&self, content_id: i64, permission_type: &str, permissions: &[RemoteContentPermission]) -> Result<bool>;
+
+    /// Calls `setEnableAnonymousAccess`.
+    fn soap_set_enable_anonymous_access(&self, value: bool) -> Result<bool>;
+
+    /// Calls `setEnableWysiwyg`.
+    fn soap_set_enable_wysiwyg(&self, value: bool) -> Result<bool>;
+
+    /// Calls `setSpaceStatus`.
+    fn soap_set_space_status(&self, space_key: &str, status: &str) -> Result<bool>;
+
+    /// Calls `setUserInformation`.
+    fn soap_set_user_information(&self, user_info: &RemoteUserInformation) -> Result<bool>;
+
+    /// Calls `setUserPreferenceBoolean`.
+    fn soap_set_user_preference_boolean(&self, key: &str, value: bool) -> Result<bool>;
+
+    /// Calls `setUserPreferenceLong`.
+    fn soap_set_user_preference_long(&self, key: &str, value: i64) -> Result<bool>;
+
+    /// Calls `setUserPreferenceString`.
+    fn soap_set_user_preference_string(&self, key: &str, value: &str) -> Result<bool>;
+
+    /// Calls `startActivity`.
+    fn soap_start_activity(&self, key: &str, user: &str) -> Result<bool>;
+
+    /// Calls `stopActivity`.
+    fn soap_stop_activity(&self, key: &str, user: &str) -> Result<bool>;
+
+    /// Calls `storeBlogEntry`.
+    fn soap_store_blog_entry(&self, entry: &RemoteBlogEntry) -> Result<RemoteBlogEntry>;
+
+    /// Calls `storePage`.
+    fn soap_store_page(&self, page: &RemotePage) -> Result<RemotePage>;
+
+    /// Calls `storeSpace`.
+    fn soap_store_space(&self, space: &RemoteSpace) -> Result<RemoteSpace>;
+
+    /// Calls `updatePage`.
+    fn soap_update_page(&self, page: &RemotePage, page_update_options: &RemotePageUpdateOptions) -> Result<RemotePage>;
+
+    /// Calls `watchPage`.
+    fn soap_watch_page(&self, page_id: i64) -> Result<bool>;
+
+    /// Calls `watchPageForUser`.
+    fn soap_watch_page_for_user(&self, page_id: i64, username: &str) -> Result<bool>;
+
+    /// Calls `watchSpace`.
+    fn soap_watch_space(&self, space_key: &str) -> Result<bool>;
+}
+
+impl ConfluenceApi for Session {
+    fn soap_add_anonymous_permission_to_space(&self, permission: &str, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addAnonymousPermissionToSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(permission))
+                .with(Element::node("in2").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addAnonymousPermissionToSpaceReturn"])?)
+    }
+
+    fn soap_add_anonymous_permissions_to_space(&self, permissions: &[String], space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addAnonymousPermissionsToSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(array("in1", permissions))
+                .with(Element::node("in2").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addAnonymousPermissionsToSpaceReturn"])?)
+    }
+
+    fn soap_add_attachment(&self, content_id: i64, attachment: &RemoteAttachment, attachment_data: &str) -> Result<RemoteAttachment> {
+        let response = self.call(
+            Method::new("addAttachment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(content_id.to_soap("in1"))
+                .with(attachment.to_soap("in2"))
+                .with(Element::node("in3").with_text(attachment_data)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addAttachmentReturn"])?)
+    }
+
+    fn soap_add_attachment_2(&self, attachment: &RemoteAttachment, attachment_data: &str) -> Result<RemoteAttachment> {
+        let response = self.call(
+            Method::new("addAttachment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(attachment.to_soap("in1"))
+                .with(Element::node("in2").with_text(attachment_data)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addAttachmentReturn"])?)
+    }
+
+    fn soap_add_comment(&self, comment: &RemoteComment) -> Result<RemoteComment> {
+        let response = self.call(
+            Method::new("addComment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(comment.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addCommentReturn"])?)
+    }
+
+    fn soap_add_group(&self, group: &str) -> Result<()> {
+        self.call(
+            Method::new("addGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(group)),
+        )?;
+
+        Ok(())
+    }
+
+    fn soap_add_label_by_id(&self, label_id: i64, object_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("addLabelById")
+                .with(Element::node("token").with_text(self.token()))
+                .with(label_id.to_soap("in1"))
+                .with(object_id.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addLabelByIdReturn"])?)
+    }
+
+    fn soap_add_label_by_name(&self, label_name: &str, object_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("addLabelByName")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name))
+                .with(object_id.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addLabelByNameReturn"])?)
+    }
+
+    fn soap_add_label_by_name_to_space(&self, label_name: &str, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addLabelByNameToSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name))
+                .with(Element::node("in2").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addLabelByNameToSpaceReturn"])?)
+    }
+
+    fn soap_add_label_by_object(&self, label_object: &RemoteLabel, object_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("addLabelByObject")
+                .with(Element::node("token").with_text(self.token()))
+                .with(label_object.to_soap("in1"))
+                .with(object_id.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addLabelByObjectReturn"])?)
+    }
+
+    fn soap_add_permission_to_space(&self, permission: &str, remote_entity_name: &str, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addPermissionToSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(permission))
+                .with(Element::node("in2").with_text(remote_entity_name))
+                .with(Element::node("in3").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addPermissionToSpaceReturn"])?)
+    }
+
+    fn soap_add_permissions_to_space(&self, permissions: &[String], remote_entity_name: &str, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addPermissionsToSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(array("in1", permissions))
+                .with(Element::node("in2").with_text(remote_entity_name))
+                .with(Element::node("in3").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addPermissionsToSpaceReturn"])?)
+    }
+
+    fn soap_add_personal_space(&self, space: &RemoteSpace, username: &str) -> Result<RemoteSpace> {
+        let response = self.call(
+            Method::new("addPersonalSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(space.to_soap("in1"))
+                .with(Element::node("in2").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addPersonalSpaceReturn"])?)
+    }
+
+    fn soap_add_personal_space_with_default_permissions(&self, space: &RemoteSpace, username: &str) -> Result<RemoteSpace> {
+        let response = self.call(
+            Method::new("addPersonalSpaceWithDefaultPermissions")
+                .with(Element::node("token").with_text(self.token()))
+                .with(space.to_soap("in1"))
+                .with(Element::node("in2").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addPersonalSpaceWithDefaultPermissionsReturn"])?)
+    }
+
+    fn soap_add_profile_picture(&self, user_name: &str, file_name: &str, mime_type: &str, picture_data: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addProfilePicture")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(user_name))
+                .with(Element::node("in2").with_text(file_name))
+                .with(Element::node("in3").with_text(mime_type))
+                .with(Element::node("in4").with_text(picture_data)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addProfilePictureReturn"])?)
+    }
+
+    fn soap_add_space(&self, space: &RemoteSpace) -> Result<RemoteSpace> {
+        let response = self.call(
+            Method::new("addSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(space.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addSpaceReturn"])?)
+    }
+
+    fn soap_add_space_with_default_permissions(&self, space: &RemoteSpace) -> Result<RemoteSpace> {
+        let response = self.call(
+            Method::new("addSpaceWithDefaultPermissions")
+                .with(Element::node("token").with_text(self.token()))
+                .with(space.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addSpaceWithDefaultPermissionsReturn"])?)
+    }
+
+    fn soap_add_user(&self, user: &RemoteUser, password: &str) -> Result<()> {
+        self.call(
+            Method::new("addUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(user.to_soap("in1"))
+                .with(Element::node("in2").with_text(password)),
+        )?;
+
+        Ok(())
+    }
+
+    fn soap_add_user_2(&self, user: &RemoteUser, password: &str, notify_user: bool) -> Result<()> {
+        self.call(
+            Method::new("addUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(user.to_soap("in1"))
+                .with(Element::node("in2").with_text(password))
+                .with(notify_user.to_soap("in3")),
+        )?;
+
+        Ok(())
+    }
+
+    fn soap_add_user_to_group(&self, username: &str, groupname: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("addUserToGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username))
+                .with(Element::node("in2").with_text(groupname)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["addUserToGroupReturn"])?)
+    }
+
+    fn soap_change_my_password(&self, old_password: &str, new_password: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("changeMyPassword")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(old_password))
+                .with(Element::node("in2").with_text(new_password)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["changeMyPasswordReturn"])?)
+    }
+
+    fn soap_change_user_password(&self, username: &str, new_password: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("changeUserPassword")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username))
+                .with(Element::node("in2").with_text(new_password)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["changeUserPasswordReturn"])?)
+    }
+
+    fn soap_clear_index_queue(&self) -> Result<bool> {
+        let response = self.call(
+            Method::new("clearIndexQueue")
+                .with(Element::node("token").with_text(self.token())),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["clearIndexQueueReturn"])?)
+    }
+
+    fn soap_convert_to_personal_space(&self, user_name: &str, space_key: &str, new_space_name: &str, update_links: bool) -> Result<bool> {
+        let response = self.call(
+            Method::new("convertToPersonalSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(user_name))
+                .with(Element::node("in2").with_text(space_key))
+                .with(Element::node("in3").with_text(new_space_name))
+                .with(update_links.to_soap("in4")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["convertToPersonalSpaceReturn"])?)
+    }
+
+    fn soap_deactivate_user(&self, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("deactivateUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["deactivateUserReturn"])?)
+    }
+
+    fn soap_edit_comment(&self, comment: &RemoteComment) -> Result<RemoteComment> {
+        let response = self.call(
+            Method::new("editComment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(comment.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["editCommentReturn"])?)
+    }
+
+    fn soap_edit_user(&self, user: &RemoteUser) -> Result<bool> {
+        let response = self.call(
+            Method::new("editUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(user.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["editUserReturn"])?)
+    }
+
+    fn soap_empty_trash(&self, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("emptyTrash")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["emptyTrashReturn"])?)
+    }
+
+    fn soap_export_site(&self, export_attachments: bool) -> Result<String> {
+        let response = self.call(
+            Method::new("exportSite")
+                .with(Element::node("token").with_text(self.token()))
+                .with(export_attachments.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["exportSiteReturn"])?)
+    }
+
+    fn soap_export_space(&self, space_key: &str, export_type: &str) -> Result<String> {
+        let response = self.call(
+            Method::new("exportSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(Element::node("in2").with_text(export_type)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["exportSpaceReturn"])?)
+    }
+
+    fn soap_export_space_2(&self, space_key: &str, export_type: &str, export_all: bool) -> Result<String> {
+        let response = self.call(
+            Method::new("exportSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(Element::node("in2").with_text(export_type))
+                .with(export_all.to_soap("in3")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["exportSpaceReturn"])?)
+    }
+
+    fn soap_flush_index_queue(&self) -> Result<bool> {
+        let response = self.call(
+            Method::new("flushIndexQueue")
+                .with(Element::node("token").with_text(self.token())),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["flushIndexQueueReturn"])?)
+    }
+
+    fn soap_get_active_users(&self, view_all: bool) -> Result<Vec<String>> {
+        let response = self.call(
+            Method::new("getActiveUsers")
+                .with(Element::node("token").with_text(self.token()))
+                .with(view_all.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getActiveUsersReturn"])?)
+    }
+
+    fn soap_get_ancestors(&self, page_id: i64) -> Result<Vec<RemotePageSummary>> {
+        let response = self.call(
+            Method::new("getAncestors")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getAncestorsReturn"])?)
+    }
+
+    fn soap_get_attachment(&self, content_id: i64, file_name: &str, version: i32) -> Result<RemoteAttachment> {
+        let response = self.call(
+            Method::new("getAttachment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(content_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(file_name))
+                .with(version.to_soap("in3")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getAttachmentReturn"])?)
+    }
+
+    fn soap_get_attachment_data(&self, content_id: i64, file_name: &str, version: i32) -> Result<String> {
+        let response = self.call(
+            Method::new("getAttachmentData")
+                .with(Element::node("token").with_text(self.token()))
+                .with(content_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(file_name))
+                .with(version.to_soap("in3")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getAttachmentDataReturn"])?)
+    }
+
+    fn soap_get_attachments(&self, page_id: i64) -> Result<Vec<RemoteAttachment>> {
+        let response = self.call(
+            Method::new("getAttachments")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getAttachmentsReturn"])?)
+    }
+
+    fn soap_get_blog_entries(&self, space_key: &str) -> Result<Vec<RemoteBlogEntrySummary>> {
+        let response = self.call(
+            Method::new("getBlogEntries")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getBlogEntriesReturn"])?)
+    }
+
+    fn soap_get_blog_entry(&self, entry_id: i64) -> Result<RemoteBlogEntry> {
+        let response = self.call(
+            Method::new("getBlogEntry")
+                .with(Element::node("token").with_text(self.token()))
+                .with(entry_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getBlogEntryReturn"])?)
+    }
+
+    fn soap_get_blog_entry_by_date_and_title(&self, space_key: &str, year: i32, month: i32, day_of_month: i32, post_title: &str) -> Result<RemoteBlogEntry> {
+        let response = self.call(
+            Method::new("getBlogEntryByDateAndTitle")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(year.to_soap("in2"))
+                .with(month.to_soap("in3"))
+                .with(day_of_month.to_soap("in4"))
+                .with(Element::node("in5").with_text(post_title)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getBlogEntryByDateAndTitleReturn"])?)
+    }
+
+    fn soap_get_blog_entry_by_day_and_title(&self, space_key: &str, day_of_month: i32, post_title: &str) -> Result<RemoteBlogEntry> {
+        let response = self.call(
+            Method::new("getBlogEntryByDayAndTitle")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(day_of_month.to_soap("in2"))
+                .with(Element::node("in3").with_text(post_title)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getBlogEntryByDayAndTitleReturn"])?)
+    }
+
+    fn soap_get_children(&self, page_id: i64) -> Result<Vec<RemotePageSummary>> {
+        let response = self.call(
+            Method::new("getChildren")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getChildrenReturn"])?)
+    }
+
+    fn soap_get_cluster_information(&self) -> Result<RemoteClusterInformation> {
+        let response = self.call(
+            Method::new("getClusterInformation")
+                .with(Element::node("token").with_text(self.token())),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getClusterInformationReturn"])?)
+    }
+
+    fn soap_get_cluster_node_statuses(&self) -> Result<Vec<RemoteNodeStatus>> {
+        let response = self.call(
+            Method::new("getClusterNodeStatuses")
+                .with(Element::node("token").with_text(self.token())),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getClusterNodeStatusesReturn"])?)
+    }
+
+    fn soap_get_comment(&self, comment_id: i64) -> Result<RemoteComment> {
+        let response = self.call(
+            Method::new("getComment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(comment_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getCommentReturn"])?)
+    }
+
+    fn soap_get_comments(&self, page_id: i64) -> Result<Vec<RemoteComment>> {
+        let response = self.call(
+            Method::new("getComments")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getCommentsReturn"])?)
+    }
+
+    fn soap_get_content_permission_set(&self, content_id: i64, permission_type: &str) -> Result<RemoteContentPermissionSet> {
+        let response = self.call(
+            Method::new("getContentPermissionSet")
+                .with(Element::node("token").with_text(self.token()))
+                .with(content_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(permission_type)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getContentPermissionSetReturn"])?)
+    }
+
+    fn soap_get_content_permission_sets(&self, content_id: i64) -> Result<Vec<RemoteContentPermissionSet>> {
+        let response = self.call(
+            Method::new("getContentPermissionSets")
+                .with(Element::node("token").with_text(self.token()))
+                .with(content_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getContentPermissionSetsReturn"])?)
+    }
+
+    fn soap_get_descendents(&self, page_id: i64) -> Result<Vec<RemotePageSummary>> {
+        let response = self.call(
+            Method::new("getDescendents")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getDescendentsReturn"])?)
+    }
+
+    fn soap_get_groups(&self) -> Result<Vec<String>> {
+        let response = self.call(
+            Method::new("getGroups")
+                .with(Element::node("token").with_text(self.token())),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getGroupsReturn"])?)
+    }
+
+    fn soap_get_label_content_by_id(&self, label_id: i64) -> Result<Vec<RemoteSearchResult>> {
+        let response = self.call(
+            Method::new("getLabelContentById")
+                .with(Element::node("token").with_text(self.token()))
+                .with(label_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getLabelContentByIdReturn"])?)
+    }
+
+    fn soap_get_label_content_by_name(&self, label_name: &str) -> Result<Vec<RemoteSearchResult>> {
+        let response = self.call(
+            Method::new("getLabelContentByName")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getLabelContentByNameReturn"])?)
+    }
+
+    fn soap_get_label_content_by_object(&self, label_object: &RemoteLabel) -> Result<Vec<RemoteSearchResult>> {
+        let response = self.call(
+            Method::new("getLabelContentByObject")
+                .with(Element::node("token").with_text(self.token()))
+                .with(label_object.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getLabelContentByObjectReturn"])?)
+    }
+
+    fn soap_get_labels_by_detail(&self, label_name: &str, namespace: &str, space_key: &str, owner: &str) -> Result<Vec<RemoteLabel>> {
+        let response = self.call(
+            Method::new("getLabelsByDetail")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name))
+                .with(Element::node("in2").with_text(namespace))
+                .with(Element::node("in3").with_text(space_key))
+                .with(Element::node("in4").with_text(owner)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getLabelsByDetailReturn"])?)
+    }
+
+    fn soap_get_labels_by_id(&self, object_id: i64) -> Result<Vec<RemoteLabel>> {
+        let response = self.call(
+            Method::new("getLabelsById")
+                .with(Element::node("token").with_text(self.token()))
+                .with(object_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getLabelsByIdReturn"])?)
+    }
+
+    fn soap_get_most_popular_labels(&self, max_count: i32) -> Result<Vec<RemoteLabel>> {
+        let response = self.call(
+            Method::new("getMostPopularLabels")
+                .with(Element::node("token").with_text(self.token()))
+                .with(max_count.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getMostPopularLabelsReturn"])?)
+    }
+
+    fn soap_get_most_popular_labels_in_space(&self, space_key: &str, max_count: i32) -> Result<Vec<RemoteLabel>> {
+        let response = self.call(
+            Method::new("getMostPopularLabelsInSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(max_count.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getMostPopularLabelsInSpaceReturn"])?)
+    }
+
+    fn soap_get_page(&self, page_id: i64) -> Result<RemotePage> {
+        let response = self.call(
+            Method::new("getPage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPageReturn"])?)
+    }
+
+    fn soap_get_page_2(&self, space_key: &str, page_title: &str) -> Result<RemotePage> {
+        let response = self.call(
+            Method::new("getPage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(Element::node("in2").with_text(page_title)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPageReturn"])?)
+    }
+
+    fn soap_get_page_history(&self, page_id: i64) -> Result<Vec<RemotePageHistory>> {
+        let response = self.call(
+            Method::new("getPageHistory")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPageHistoryReturn"])?)
+    }
+
+    fn soap_get_page_permissions(&self, page_id: i64) -> Result<Vec<RemotePermission>> {
+        let response = self.call(
+            Method::new("getPagePermissions")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPagePermissionsReturn"])?)
+    }
+
+    fn soap_get_page_summary(&self, page_id: i64) -> Result<RemotePageSummary> {
+        let response = self.call(
+            Method::new("getPageSummary")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPageSummaryReturn"])?)
+    }
+
+    fn soap_get_page_summary_2(&self, space_key: &str, page_title: &str) -> Result<RemotePageSummary> {
+        let response = self.call(
+            Method::new("getPageSummary")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(Element::node("in2").with_text(page_title)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPageSummaryReturn"])?)
+    }
+
+    fn soap_get_pages(&self, space_key: &str) -> Result<Vec<RemotePageSummary>> {
+        let response = self.call(
+            Method::new("getPages")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPagesReturn"])?)
+    }
+
+    fn soap_get_permissions(&self, space_key: &str) -> Result<Vec<String>> {
+        let response = self.call(
+            Method::new("getPermissions")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPermissionsReturn"])?)
+    }
+
+    fn soap_get_permissions_for_user(&self, space_key: &str, user_name: &str) -> Result<Vec<String>> {
+        let response = self.call(
+            Method::new("getPermissionsForUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(Element::node("in2").with_text(user_name)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getPermissionsForUserReturn"])?)
+    }
+
+    fn soap_get_recently_used_labels(&self, max_results: i32) -> Result<Vec<RemoteLabel>> {
+        let response = self.call(
+            Method::new("getRecentlyUsedLabels")
+                .with(Element::node("token").with_text(self.token()))
+                .with(max_results.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getRecentlyUsedLabelsReturn"])?)
+    }
+
+    fn soap_get_recently_used_labels_in_space(&self, space_key: &str, max_results: i32) -> Result<Vec<RemoteLabel>> {
+        let response = self.call(
+            Method::new("getRecentlyUsedLabelsInSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(max_results.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getRecentlyUsedLabelsInSpaceReturn"])?)
+    }
+
+    fn soap_get_related_labels(&self, label_name: &str, max_results: i32) -> Result<Vec<RemoteLabel>> {
+        let response = self.call(
+            Method::new("getRelatedLabels")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name))
+                .with(max_results.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getRelatedLabelsReturn"])?)
+    }
+
+    fn soap_get_related_labels_in_space(&self, label_name: &str, space_key: &str, max_results: i32) -> Result<Vec<RemoteLabel>> {
+        let response = self.call(
+            Method::new("getRelatedLabelsInSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name))
+                .with(Element::node("in2").with_text(space_key))
+                .with(max_results.to_soap("in3")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getRelatedLabelsInSpaceReturn"])?)
+    }
+
+    fn soap_get_server_info(&self) -> Result<RemoteServerInfo> {
+        let response = self.call(
+            Method::new("getServerInfo")
+                .with(Element::node("token").with_text(self.token())),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getServerInfoReturn"])?)
+    }
+
+    fn soap_get_space(&self, space_key: &str) -> Result<RemoteSpace> {
+        let response = self.call(
+            Method::new("getSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpaceReturn"])?)
+    }
+
+    fn soap_get_space_level_permissions(&self) -> Result<Vec<String>> {
+        let response = self.call(
+            Method::new("getSpaceLevelPermissions")
+                .with(Element::node("token").with_text(self.token())),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpaceLevelPermissionsReturn"])?)
+    }
+
+    fn soap_get_space_permission_set(&self, space_key: &str, permission_type: &str) -> Result<RemoteSpacePermissionSet> {
+        let response = self.call(
+            Method::new("getSpacePermissionSet")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(Element::node("in2").with_text(permission_type)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpacePermissionSetReturn"])?)
+    }
+
+    fn soap_get_space_permission_sets(&self, space_key: &str) -> Result<Vec<RemoteSpacePermissionSet>> {
+        let response = self.call(
+            Method::new("getSpacePermissionSets")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpacePermissionSetsReturn"])?)
+    }
+
+    fn soap_get_space_status(&self, space_key: &str) -> Result<String> {
+        let response = self.call(
+            Method::new("getSpaceStatus")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpaceStatusReturn"])?)
+    }
+
+    fn soap_get_spaces(&self) -> Result<Vec<RemoteSpaceSummary>> {
+        let response = self.call(
+            Method::new("getSpaces")
+                .with(Element::node("token").with_text(self.token())),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpacesReturn"])?)
+    }
+
+    fn soap_get_spaces_containing_content_with_label(&self, label_name: &str) -> Result<Vec<RemoteSpace>> {
+        let response = self.call(
+            Method::new("getSpacesContainingContentWithLabel")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpacesContainingContentWithLabelReturn"])?)
+    }
+
+    fn soap_get_spaces_in_group(&self, space_group_key: &str) -> Result<Vec<RemoteSpaceSummary>> {
+        let response = self.call(
+            Method::new("getSpacesInGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_group_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpacesInGroupReturn"])?)
+    }
+
+    fn soap_get_spaces_with_label(&self, label_name: &str) -> Result<Vec<RemoteSpace>> {
+        let response = self.call(
+            Method::new("getSpacesWithLabel")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getSpacesWithLabelReturn"])?)
+    }
+
+    fn soap_get_trash_contents(&self, space_key: &str, offset: i32, count: i32) -> Result<RemoteContentSummaries> {
+        let response = self.call(
+            Method::new("getTrashContents")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(offset.to_soap("in2"))
+                .with(count.to_soap("in3")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getTrashContentsReturn"])?)
+    }
+
+    fn soap_get_user(&self, username: &str) -> Result<RemoteUser> {
+        let response = self.call(
+            Method::new("getUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getUserReturn"])?)
+    }
+
+    fn soap_get_user_groups(&self, username: &str) -> Result<Vec<String>> {
+        let response = self.call(
+            Method::new("getUserGroups")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getUserGroupsReturn"])?)
+    }
+
+    fn soap_get_user_information(&self, username: &str) -> Result<RemoteUserInformation> {
+        let response = self.call(
+            Method::new("getUserInformation")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getUserInformationReturn"])?)
+    }
+
+    fn soap_get_user_preference_boolean(&self, key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("getUserPreferenceBoolean")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getUserPreferenceBooleanReturn"])?)
+    }
+
+    fn soap_get_user_preference_long(&self, key: &str) -> Result<i64> {
+        let response = self.call(
+            Method::new("getUserPreferenceLong")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getUserPreferenceLongReturn"])?)
+    }
+
+    fn soap_get_user_preference_string(&self, key: &str) -> Result<String> {
+        let response = self.call(
+            Method::new("getUserPreferenceString")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getUserPreferenceStringReturn"])?)
+    }
+
+    fn soap_get_watchers_for_page(&self, page_id: i64) -> Result<Vec<RemoteUser>> {
+        let response = self.call(
+            Method::new("getWatchersForPage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getWatchersForPageReturn"])?)
+    }
+
+    fn soap_get_watchers_for_space(&self, space_key: &str) -> Result<Vec<RemoteUser>> {
+        let response = self.call(
+            Method::new("getWatchersForSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["getWatchersForSpaceReturn"])?)
+    }
+
+    fn soap_has_group(&self, groupname: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("hasGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(groupname)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["hasGroupReturn"])?)
+    }
+
+    fn soap_has_user(&self, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("hasUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["hasUserReturn"])?)
+    }
+
+    fn soap_import_space(&self, import_data: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("importSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(import_data)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["importSpaceReturn"])?)
+    }
+
+    fn soap_is_active_user(&self, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("isActiveUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["isActiveUserReturn"])?)
+    }
+
+    fn soap_is_dark_feature_enabled(&self, key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("isDarkFeatureEnabled")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["isDarkFeatureEnabledReturn"])?)
+    }
+
+    fn soap_is_plugin_enabled(&self, plugin_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("isPluginEnabled")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(plugin_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["isPluginEnabledReturn"])?)
+    }
+
+    fn soap_is_plugin_installed(&self, plugin_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("isPluginInstalled")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(plugin_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["isPluginInstalledReturn"])?)
+    }
+
+    fn soap_is_watching_page(&self, page_id: i64, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("isWatchingPage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["isWatchingPageReturn"])?)
+    }
+
+    fn soap_is_watching_space(&self, space_key: &str, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("isWatchingSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(Element::node("in2").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["isWatchingSpaceReturn"])?)
+    }
+
+    fn soap_move_attachment(&self, original_content_id: i64, original_name: &str, new_content_id: i64, new_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("moveAttachment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(original_content_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(original_name))
+                .with(new_content_id.to_soap("in3"))
+                .with(Element::node("in4").with_text(new_name)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["moveAttachmentReturn"])?)
+    }
+
+    fn soap_move_page(&self, source_page_id: i64, target_page_id: i64, position: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("movePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(source_page_id.to_soap("in1"))
+                .with(target_page_id.to_soap("in2"))
+                .with(Element::node("in3").with_text(position)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["movePageReturn"])?)
+    }
+
+    fn soap_move_page_to_top_level(&self, page_id: i64, target_space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("movePageToTopLevel")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(target_space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["movePageToTopLevelReturn"])?)
+    }
+
+    fn soap_purge_from_trash(&self, space_key: &str, content_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("purgeFromTrash")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(content_id.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["purgeFromTrashReturn"])?)
+    }
+
+    fn soap_reactivate_user(&self, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("reactivateUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["reactivateUserReturn"])?)
+    }
+
+    fn soap_remove_all_permissions_for_group(&self, groupname: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeAllPermissionsForGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(groupname)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeAllPermissionsForGroupReturn"])?)
+    }
+
+    fn soap_remove_anonymous_permission_from_space(&self, permission: &str, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeAnonymousPermissionFromSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(permission))
+                .with(Element::node("in2").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeAnonymousPermissionFromSpaceReturn"])?)
+    }
+
+    fn soap_remove_attachment(&self, content_id: i64, file_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeAttachment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(content_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(file_name)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeAttachmentReturn"])?)
+    }
+
+    fn soap_remove_comment(&self, comment_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeComment")
+                .with(Element::node("token").with_text(self.token()))
+                .with(comment_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeCommentReturn"])?)
+    }
+
+    fn soap_remove_group(&self, groupname: &str, default_group_name: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(groupname))
+                .with(Element::node("in2").with_text(default_group_name)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeGroupReturn"])?)
+    }
+
+    fn soap_remove_label_by_id(&self, label_id: i64, object_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeLabelById")
+                .with(Element::node("token").with_text(self.token()))
+                .with(label_id.to_soap("in1"))
+                .with(object_id.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeLabelByIdReturn"])?)
+    }
+
+    fn soap_remove_label_by_name(&self, label_name: &str, object_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeLabelByName")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name))
+                .with(object_id.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeLabelByNameReturn"])?)
+    }
+
+    fn soap_remove_label_by_name_from_space(&self, label_name: &str, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeLabelByNameFromSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(label_name))
+                .with(Element::node("in2").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeLabelByNameFromSpaceReturn"])?)
+    }
+
+    fn soap_remove_label_by_object(&self, label_object: &RemoteLabel, object_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeLabelByObject")
+                .with(Element::node("token").with_text(self.token()))
+                .with(label_object.to_soap("in1"))
+                .with(object_id.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeLabelByObjectReturn"])?)
+    }
+
+    fn soap_remove_page(&self, page_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removePageReturn"])?)
+    }
+
+    fn soap_remove_page_version_by_id(&self, historical_page_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePageVersionById")
+                .with(Element::node("token").with_text(self.token()))
+                .with(historical_page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removePageVersionByIdReturn"])?)
+    }
+
+    fn soap_remove_page_version_by_version(&self, page_id: i64, version: i32) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePageVersionByVersion")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1"))
+                .with(version.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removePageVersionByVersionReturn"])?)
+    }
+
+    fn soap_remove_page_watch(&self, page_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePageWatch")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removePageWatchReturn"])?)
+    }
+
+    fn soap_remove_page_watch_for_user(&self, page_id: i64, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePageWatchForUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removePageWatchForUserReturn"])?)
+    }
+
+    fn soap_remove_permission_from_space(&self, permission: &str, remote_entity_name: &str, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removePermissionFromSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(permission))
+                .with(Element::node("in2").with_text(remote_entity_name))
+                .with(Element::node("in3").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removePermissionFromSpaceReturn"])?)
+    }
+
+    fn soap_remove_space(&self, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeSpaceReturn"])?)
+    }
+
+    fn soap_remove_space_watch(&self, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeSpaceWatch")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeSpaceWatchReturn"])?)
+    }
+
+    fn soap_remove_user(&self, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeUserReturn"])?)
+    }
+
+    fn soap_remove_user_from_group(&self, username: &str, groupname: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("removeUserFromGroup")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(username))
+                .with(Element::node("in2").with_text(groupname)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["removeUserFromGroupReturn"])?)
+    }
+
+    fn soap_rename_user(&self, old_username: &str, new_username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("renameUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(old_username))
+                .with(Element::node("in2").with_text(new_username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["renameUserReturn"])?)
+    }
+
+    fn soap_rename_users(&self, old_usernames_to_new_usernames: &Element) -> Result<Vec<String>> {
+        let response = self.call(
+            Method::new("renameUsers")
+                .with(Element::node("token").with_text(self.token()))
+                .with(old_usernames_to_new_usernames.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["renameUsersReturn"])?)
+    }
+
+    fn soap_render_content(&self, space_key: &str, page_id: i64, content: &str) -> Result<String> {
+        let response = self.call(
+            Method::new("renderContent")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(page_id.to_soap("in2"))
+                .with(Element::node("in3").with_text(content)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["renderContentReturn"])?)
+    }
+
+    fn soap_render_content_2(&self, space_key: &str, page_id: i64, content: &str, parameters: &Element) -> Result<String> {
+        let response = self.call(
+            Method::new("renderContent")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(page_id.to_soap("in2"))
+                .with(Element::node("in3").with_text(content))
+                .with(parameters.to_soap("in4")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["renderContentReturn"])?)
+    }
+
+    fn soap_search(&self, query: &str, max_results: i32) -> Result<Vec<RemoteSearchResult>> {
+        let response = self.call(
+            Method::new("search")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(query))
+                .with(max_results.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["searchReturn"])?)
+    }
+
+    fn soap_search_2(&self, query: &str, parameters: &Element, max_results: i32) -> Result<Vec<RemoteSearchResult>> {
+        let response = self.call(
+            Method::new("search")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(query))
+                .with(parameters.to_soap("in2"))
+                .with(max_results.to_soap("in3")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["searchReturn"])?)
+    }
+
+    fn soap_set_content_permissions(&self, content_id: i64, permission_type: &str, permissions: &[RemoteContentPermission]) -> Result<bool> {
+        let response = self.call(
+            Method::new("setContentPermissions")
+                .with(Element::node("token").with_text(self.token()))
+                .with(content_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(permission_type))
+                .with(array("in3", permissions)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["setContentPermissionsReturn"])?)
+    }
+
+    fn soap_set_enable_anonymous_access(&self, value: bool) -> Result<bool> {
+        let response = self.call(
+            Method::new("setEnableAnonymousAccess")
+                .with(Element::node("token").with_text(self.token()))
+                .with(value.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["setEnableAnonymousAccessReturn"])?)
+    }
+
+    fn soap_set_enable_wysiwyg(&self, value: bool) -> Result<bool> {
+        let response = self.call(
+            Method::new("setEnableWysiwyg")
+                .with(Element::node("token").with_text(self.token()))
+                .with(value.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["setEnableWysiwygReturn"])?)
+    }
+
+    fn soap_set_space_status(&self, space_key: &str, status: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("setSpaceStatus")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key))
+                .with(Element::node("in2").with_text(status)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["setSpaceStatusReturn"])?)
+    }
+
+    fn soap_set_user_information(&self, user_info: &RemoteUserInformation) -> Result<bool> {
+        let response = self.call(
+            Method::new("setUserInformation")
+                .with(Element::node("token").with_text(self.token()))
+                .with(user_info.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["setUserInformationReturn"])?)
+    }
+
+    fn soap_set_user_preference_boolean(&self, key: &str, value: bool) -> Result<bool> {
+        let response = self.call(
+            Method::new("setUserPreferenceBoolean")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key))
+                .with(value.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["setUserPreferenceBooleanReturn"])?)
+    }
+
+    fn soap_set_user_preference_long(&self, key: &str, value: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("setUserPreferenceLong")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key))
+                .with(value.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["setUserPreferenceLongReturn"])?)
+    }
+
+    fn soap_set_user_preference_string(&self, key: &str, value: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("setUserPreferenceString")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key))
+                .with(Element::node("in2").with_text(value)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["setUserPreferenceStringReturn"])?)
+    }
+
+    fn soap_start_activity(&self, key: &str, user: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("startActivity")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key))
+                .with(Element::node("in2").with_text(user)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["startActivityReturn"])?)
+    }
+
+    fn soap_stop_activity(&self, key: &str, user: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("stopActivity")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(key))
+                .with(Element::node("in2").with_text(user)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["stopActivityReturn"])?)
+    }
+
+    fn soap_store_blog_entry(&self, entry: &RemoteBlogEntry) -> Result<RemoteBlogEntry> {
+        let response = self.call(
+            Method::new("storeBlogEntry")
+                .with(Element::node("token").with_text(self.token()))
+                .with(entry.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["storeBlogEntryReturn"])?)
+    }
+
+    fn soap_store_page(&self, page: &RemotePage) -> Result<RemotePage> {
+        let response = self.call(
+            Method::new("storePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["storePageReturn"])?)
+    }
+
+    fn soap_store_space(&self, space: &RemoteSpace) -> Result<RemoteSpace> {
+        let response = self.call(
+            Method::new("storeSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(space.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["storeSpaceReturn"])?)
+    }
+
+    fn soap_update_page(&self, page: &RemotePage, page_update_options: &RemotePageUpdateOptions) -> Result<RemotePage> {
+        let response = self.call(
+            Method::new("updatePage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page.to_soap("in1"))
+                .with(page_update_options.to_soap("in2")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["updatePageReturn"])?)
+    }
+
+    fn soap_watch_page(&self, page_id: i64) -> Result<bool> {
+        let response = self.call(
+            Method::new("watchPage")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1")),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["watchPageReturn"])?)
+    }
+
+    fn soap_watch_page_for_user(&self, page_id: i64, username: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("watchPageForUser")
+                .with(Element::node("token").with_text(self.token()))
+                .with(page_id.to_soap("in1"))
+                .with(Element::node("in2").with_text(username)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["watchPageForUserReturn"])?)
+    }
+
+    fn soap_watch_space(&self, space_key: &str) -> Result<bool> {
+        let response = self.call(
+            Method::new("watchSpace")
+                .with(Element::node("token").with_text(self.token()))
+                .with(Element::node("in1").with_text(space_key)),
+        )?;
+
+        SoapValue::from_soap(&response.body.descend(&["watchSpaceReturn"])?)
+    }
+}
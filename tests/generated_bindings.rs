@@ -0,0 +1,47 @@
+//! Compiles the bindings generated from `fixtures/confluenceservice-v2-full.wsdl`,
+//! which describes all operations of `confluenceservice-v2`.
+//!
+//! Regenerate them after changing `wsdl::codegen` with
+//! `cargo run --example generate_bindings -- tests/fixtures/confluenceservice-v2-full.wsdl tests/fixtures/confluence_api.rs`.
+
+extern crate chrono;
+extern crate confluence;
+extern crate xmltree;
+
+#[allow(dead_code)]
+mod confluence_api {
+    include!("fixtures/confluence_api.rs");
+}
+
+#[cfg(feature = "testing")]
+mod stub {
+    use confluence::testing::{FakeConfluence, StubServer, FAKE_USER, STUB_PASSWORD};
+    use confluence::Session;
+
+    use confluence_api::{ConfluenceApi, RemotePage};
+
+    #[test]
+    fn generated_methods_call_the_service() {
+        let confluence = FakeConfluence::new();
+        confluence.seed_space("DOC", "Documentation");
+        let server = StubServer::start(confluence).unwrap();
+        let session = Session::login(&server.url(), FAKE_USER, STUB_PASSWORD).unwrap();
+
+        let stored = session
+            .soap_store_page(&RemotePage {
+                space: Some("DOC".into()),
+                title: Some("Guide".into()),
+                content: Some("<p>v1</p>".into()),
+                ..RemotePage::default()
+            })
+            .unwrap();
+        assert_eq!(stored.version, Some(1));
+
+        let page = session.soap_get_page(stored.id.unwrap()).unwrap();
+        assert_eq!(page.title, Some("Guide".into()));
+        assert_eq!(page.content, Some("<p>v1</p>".into()));
+
+        let by_title = session.soap_get_page_2("DOC", "Guide").unwrap();
+        assert_eq!(by_title.id, stored.id);
+    }
+}